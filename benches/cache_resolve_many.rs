@@ -0,0 +1,71 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Compares resolving sources one at a time with [`Cache::resolve_source`] against resolving
+//! them in a single call to [`Cache::resolve_many`], which resolves duplicate sources only once.
+//!
+//! Run with `cargo bench --bench cache_resolve_many`.
+
+// From dependency library
+use rsmount::cache::Cache;
+use rsmount::device::Source;
+
+// From standard library
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// From this library
+
+const SOURCES: usize = 64;
+const ROUNDS: u32 = 200;
+
+fn sources() -> Vec<Source> {
+    let templates = [
+        "/dev/null",
+        "/dev/zero",
+        "UUID=00000000-0000-0000-0000-000000000000",
+        "LABEL=rsmount-bench",
+    ];
+
+    templates
+        .iter()
+        .cycle()
+        .take(SOURCES)
+        .map(|source| source.parse().unwrap())
+        .collect()
+}
+
+fn bench<F>(name: &str, mut run: F)
+where
+    F: FnMut(&mut Cache),
+{
+    let mut total = Duration::ZERO;
+
+    for _ in 0..ROUNDS {
+        // Start each round with an empty cache, to measure resolution from scratch.
+        let mut cache = Cache::new().unwrap();
+
+        let start = Instant::now();
+        run(&mut cache);
+        total += start.elapsed();
+    }
+
+    println!(
+        "{name:<24} {SOURCES} sources: {:>10.2?} per round",
+        total / ROUNDS
+    );
+}
+
+fn main() {
+    let sources = sources();
+
+    bench("individual resolve", |cache| {
+        for source in sources.iter() {
+            let _ = black_box(cache.resolve_source(source));
+        }
+    });
+
+    bench("resolve_many", |cache| {
+        let _ = black_box(cache.resolve_many(&sources));
+    });
+}
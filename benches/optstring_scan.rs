@@ -0,0 +1,74 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Compares scanning a list of mount options with [`optstring::scan`], which yields slices of the
+//! list, against iterating over it with [`optstring::iter_options`], which allocates a
+//! [`MountOption`](rsmount::tables::MountOption) per option. Both parse the list with
+//! `libmount`'s `mnt_optstr_next_option`.
+//!
+//! Run with `cargo bench --bench optstring_scan`.
+
+// From dependency library
+use rsmount::optstring;
+
+// From standard library
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// From this library
+
+const OPTIONS: usize = 256;
+const ROUNDS: u32 = 2_000;
+
+fn options_list() -> String {
+    let templates = [
+        "ro",
+        "noatime",
+        "mode=1777",
+        "uid=1000",
+        "context=\"system_u:object_r:tmp_t:s0:c127,c456\"",
+        "x-systemd.automount",
+    ];
+
+    templates
+        .iter()
+        .cycle()
+        .take(OPTIONS)
+        .copied()
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn bench<F>(name: &str, mut run: F)
+where
+    F: FnMut(),
+{
+    let mut total = Duration::ZERO;
+
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        run();
+        total += start.elapsed();
+    }
+
+    println!(
+        "{name:<16} {OPTIONS} options: {:>10.2?} per round",
+        total / ROUNDS
+    );
+}
+
+fn main() {
+    let options_list = options_list();
+
+    bench("iter_options", || {
+        for option in optstring::iter_options(&options_list).unwrap() {
+            black_box(option);
+        }
+    });
+
+    bench("scan", || {
+        for pair in optstring::scan(&options_list).unwrap() {
+            black_box(pair);
+        }
+    });
+}
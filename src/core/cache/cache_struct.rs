@@ -6,18 +6,29 @@
 use rsblkid::probe::FsProperty;
 
 // From standard library
+use std::collections::{HashMap, HashSet};
+use std::fs;
 use std::mem::MaybeUninit;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 // From this library
-use crate::core::device::{Tag, TagName};
+use crate::core::device::{BlockDevice, Source, Tag, TagName};
 use crate::core::errors::CacheError;
 use crate::core::fs::{FileSystem, FsType};
 use crate::ffi_utils;
 use crate::tables::MountInfo;
 
+/// Number of distinct tags from which [`Cache::resolve_many`] probes every block device in
+/// `/proc/partitions` up front, rather than evaluating tags one by one.
+const EAGER_PROBING_THRESHOLD: usize = 8;
+
 /// A cache of device paths, and tags.
+///
+/// A `Cache` never invalidates its entries on its own. Devices removed, reinserted, or
+/// reformatted while it is alive (e.g. a USB stick plugged back in with a new `UUID`) keep
+/// resolving to their stale values; call [`Cache::clear`] after a hotplug event before reusing
+/// a long-lived `Cache`.
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct Cache {
@@ -103,6 +114,57 @@ pub fn new() -> Result<Cache, CacheError> {
         }
     }
 
+    /// Drops all the paths, and tags saved in this `Cache`, forcing the next lookups to probe
+    /// devices anew.
+    ///
+    /// **Note:** the file system properties set with `Cache::collect_fs_properties` are reset to
+    /// their default values, and tables previously given a copy of this `Cache` keep using the
+    /// old, uncleared, data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsmount::cache::Cache;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let mut cache = Cache::new()?;
+    ///     let _ = cache.resolve_and_cache("/dev/disk/by-label/backup");
+    ///
+    ///     // The backup drive was unplugged, and a new one inserted.
+    ///     cache.clear()?;
+    ///     let _ = cache.resolve_and_cache("/dev/disk/by-label/backup");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn clear(&mut self) -> Result<(), CacheError> {
+        log::debug!("Cache::clear dropping cached paths, and tags");
+
+        // libmount does not provide a way to empty a `libmnt_cache`, we replace it with a new one.
+        let mut inner = MaybeUninit::<*mut libmount::libmnt_cache>::zeroed();
+
+        unsafe { inner.write(libmount::mnt_new_cache()) };
+
+        match unsafe { inner.assume_init() } {
+            inner if inner.is_null() => {
+                let err_msg = "failed to clear `Cache`".to_owned();
+                log::debug!(
+                    "Cache::clear {}. libmount::mnt_new_cache returned a NULL pointer",
+                    err_msg
+                );
+
+                Err(CacheError::Creation(err_msg))
+            }
+            inner => {
+                unsafe { libmount::mnt_unref_cache(self.inner) };
+                self.inner = inner;
+                log::debug!("Cache::clear dropped cached paths, and tags");
+
+                Ok(())
+            }
+        }
+    }
+
     /// Returns `true` if the device matching `device_name` has a corresponding `tag` in `Cache`.
     pub fn device_has_tag<P, T>(&self, device_name: P, tag: T) -> bool
     where
@@ -588,6 +650,147 @@ pub fn find_and_cache_first_device_with_tag(&mut self, tag: &Tag) -> Option<Path
         Self::resolve_tag(self.inner, tag)
     }
 
+    /// Resolves a [`Source`] to the [`BlockDevice`] backing it, saving the result in this `Cache`.
+    ///
+    /// A [`Tag`] (e.g. `UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f`) resolves to the first device
+    /// with a matching tag, a block device path resolves to its canonical form.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CacheError::UnresolvedTag`] if no device on the system has the source's tag, or
+    /// [`CacheError::Resolve`] if the source is neither a tag nor a block device (e.g. a network
+    /// share).
+    pub fn resolve_source(&mut self, source: &Source) -> Result<BlockDevice, CacheError> {
+        log::debug!("Cache::resolve_source resolving source: {:?}", source);
+
+        match source {
+            Source::Tag(tag) => Self::resolve_tag(self.inner, tag)
+                .map(BlockDevice::from)
+                .ok_or_else(|| {
+                    let err_msg = format!("found no device with tag: {}", tag);
+                    log::debug!("Cache::resolve_source {err_msg}");
+
+                    CacheError::UnresolvedTag(err_msg)
+                }),
+            Source::BlockDevice(device) => {
+                let path = Self::canonicalize_path(self.inner, device.path())
+                    .unwrap_or_else(|| device.path().to_path_buf());
+
+                Ok(BlockDevice::from(path))
+            }
+            _otherwise => {
+                let err_msg = format!("source is not a block device: {}", source);
+                log::debug!("Cache::resolve_source {err_msg}");
+
+                Err(CacheError::Resolve(err_msg))
+            }
+        }
+    }
+
+    /// Resolves each [`Source`] in `sources` to the path of the block device backing it, saving
+    /// the results in this `Cache`.
+    ///
+    /// Every source gets a result, in the same order as `sources`; a failure to resolve one
+    /// source does not interrupt the resolution of the others (see [`Cache::resolve_source`] for
+    /// the possible errors).
+    ///
+    /// Unlike calling [`Cache::resolve_source`] in a loop, this method resolves each distinct
+    /// source only once, sharing the result between its duplicates.
+    ///
+    /// **Note:** when `sources` holds at least 8 distinct [`Tag`]s, this method first probes
+    /// every block device listed in `/proc/partitions`, importing all their tags in a single
+    /// pass, instead of running one `blkid` evaluation per tag. Probing reads each device, which
+    /// may be slow (e.g. with many disks, or disks that need to spin up); below that number,
+    /// tags are evaluated one by one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsmount::cache::Cache;
+    /// use rsmount::device::Source;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let mut cache = Cache::new()?;
+    ///     let sources: Vec<Source> = vec![
+    ///         "/dev/sda1".parse()?,
+    ///         "smb://localhost/share".parse()?,
+    ///     ];
+    ///
+    ///     let results = cache.resolve_many(&sources);
+    ///
+    ///     assert_eq!(results.len(), 2);
+    ///     assert!(results[1].is_err());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn resolve_many(&mut self, sources: &[Source]) -> Vec<Result<PathBuf, CacheError>> {
+        log::debug!("Cache::resolve_many resolving {} sources", sources.len());
+
+        let tags: HashSet<String> = sources
+            .iter()
+            .filter(|source| source.is_tag())
+            .map(|source| source.to_string())
+            .collect();
+
+        if tags.len() >= EAGER_PROBING_THRESHOLD {
+            log::debug!(
+                "Cache::resolve_many probing block devices for {} distinct tags",
+                tags.len()
+            );
+
+            self.import_tags_from_partitions();
+        }
+
+        let mut resolved: HashMap<String, Result<PathBuf, CacheError>> = HashMap::new();
+
+        sources
+            .iter()
+            .map(|source| {
+                resolved
+                    .entry(source.to_string())
+                    .or_insert_with(|| {
+                        self.resolve_source(source)
+                            .map(|device| device.path().to_path_buf())
+                    })
+                    .clone()
+            })
+            .collect()
+    }
+
+    #[doc(hidden)]
+    /// Imports the tags of every block device listed in `/proc/partitions`, probing each device
+    /// once.
+    fn import_tags_from_partitions(&mut self) {
+        let partitions = match fs::read_to_string("/proc/partitions") {
+            Ok(partitions) => partitions,
+            Err(e) => {
+                log::debug!(
+                    "Cache::import_tags_from_partitions failed to read /proc/partitions. {e}"
+                );
+
+                return;
+            }
+        };
+
+        // Skip the header and the blank line following it.
+        for name in partitions
+            .lines()
+            .skip(2)
+            .filter_map(|line| line.split_whitespace().nth(3))
+        {
+            let device = Path::new("/dev").join(name);
+
+            // An unreadable device is not fatal, its tags will be evaluated one by one.
+            if let Err(e) = self.import_tags(&device) {
+                log::debug!(
+                    "Cache::import_tags_from_partitions skipping device {:?}. {e}",
+                    device
+                );
+            }
+        }
+    }
+
     #[doc(hidden)]
     /// Finds the name of the device associated with the given path, and saves the result in a
     /// `Cahce` if `cache_ptr` is not NULL.
@@ -667,6 +870,144 @@ impl Drop for Cache {
     fn drop(&mut self) {
         log::debug!("Cache::drop deallocation `Cache` instance");
 
-        unsafe { libmount::mnt_free_cache(self.inner) }
+        // `mnt_free_cache` ignores the reference counter, decrementing it instead keeps the
+        // cache alive while it is still shared with a table or a `Mount`.
+        unsafe { libmount::mnt_unref_cache(self.inner) }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use crate::tables::FsTab;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn cache_can_not_resolve_a_network_share() -> crate::Result<()> {
+        let mut cache = Cache::new()?;
+        let source: Source = "smb://localhost/share".parse()?;
+
+        let actual = cache.resolve_source(&source);
+        assert!(matches!(actual, Err(CacheError::Resolve(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cache_can_not_resolve_an_unknown_tag() -> crate::Result<()> {
+        let mut cache = Cache::new()?;
+        let source: Source = "UUID=00000000-0000-0000-0000-000000000000".parse()?;
+
+        let actual = cache.resolve_source(&source);
+        assert!(matches!(actual, Err(CacheError::UnresolvedTag(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cache_can_resolve_many_sources_without_short_circuiting() -> crate::Result<()> {
+        let mut cache = Cache::new()?;
+        let sources: Vec<Source> = vec![
+            "UUID=00000000-0000-0000-0000-000000000000".parse()?,
+            "/dev/null".parse()?,
+            "smb://localhost/share".parse()?,
+        ];
+
+        let results = cache.resolve_many(&sources);
+
+        let actual = results.len();
+        let expected = 3;
+        assert_eq!(actual, expected);
+
+        assert!(matches!(results[0], Err(CacheError::UnresolvedTag(_))));
+
+        let actual = results[1].as_ref().ok();
+        let expected = Some(Path::new("/dev/null").to_path_buf());
+        assert_eq!(actual, expected.as_ref());
+
+        assert!(matches!(results[2], Err(CacheError::Resolve(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cache_resolves_duplicate_sources_to_the_same_result() -> crate::Result<()> {
+        let mut cache = Cache::new()?;
+        let sources: Vec<Source> = vec![
+            "/dev/null".parse()?,
+            "UUID=00000000-0000-0000-0000-000000000000".parse()?,
+            "/dev/null".parse()?,
+            "UUID=00000000-0000-0000-0000-000000000000".parse()?,
+        ];
+
+        let results = cache.resolve_many(&sources);
+
+        let actual = results[2].as_ref().ok();
+        let expected = results[0].as_ref().ok();
+        assert_eq!(actual, expected);
+
+        let actual = results[2].as_ref().ok();
+        let expected = Some(Path::new("/dev/null").to_path_buf());
+        assert_eq!(actual, expected.as_ref());
+
+        assert!(matches!(results[1], Err(CacheError::UnresolvedTag(_))));
+        assert!(matches!(results[3], Err(CacheError::UnresolvedTag(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cache_probes_paths_anew_after_being_cleared() -> crate::Result<()> {
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let first = tmp_dir.path().join("first");
+        let second = tmp_dir.path().join("second");
+        let link = tmp_dir.path().join("link");
+        std::fs::create_dir(&first).unwrap();
+        std::fs::create_dir(&second).unwrap();
+        std::os::unix::fs::symlink(&first, &link).unwrap();
+
+        let mut cache = Cache::new()?;
+
+        let actual = cache.resolve_and_cache(&link);
+        let expected = Some(std::fs::canonicalize(&first).unwrap());
+        assert_eq!(actual, expected);
+
+        // Point the link to another directory.
+        std::fs::remove_file(&link).unwrap();
+        std::os::unix::fs::symlink(&second, &link).unwrap();
+
+        // Stale value served from the cache.
+        let actual = cache.resolve_and_cache(&link);
+        let expected = Some(std::fs::canonicalize(&first).unwrap());
+        assert_eq!(actual, expected);
+
+        cache.clear()?;
+
+        let actual = cache.resolve_and_cache(&link);
+        let expected = Some(std::fs::canonicalize(&second).unwrap());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cache_can_resolve_the_tags_in_fstab() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let mut fs_tab = FsTab::new()?;
+            fs_tab.import_etc_fstab()?;
+
+            let mut cache = Cache::new()?;
+
+            for entry in fs_tab.iter() {
+                if let Some(source @ Source::Tag(_)) = entry.source() {
+                    let device = cache.resolve_source(&source)?;
+
+                    assert!(device.path().starts_with("/dev"));
+                }
+            }
+        }
+
+        Ok(())
     }
 }
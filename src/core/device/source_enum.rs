@@ -14,6 +14,7 @@
 use crate::core::device::SmbFs;
 use crate::core::device::SshFs;
 use crate::core::device::Tag;
+use crate::core::device::TagName;
 use crate::core::device::NFS;
 use crate::core::errors::ParserError;
 
@@ -166,6 +167,17 @@ impl TryFrom<&str> for Source {
     type Error = ParserError;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
+        // A string starting with a tag name (e.g. `UUID=`) can only be a tag, report parsing
+        // errors instead of falling back on other types.
+        let is_tag = s
+            .split_once('=')
+            .map(|(name, _)| TagName::from_str(name).is_ok())
+            .unwrap_or(false);
+
+        if is_tag {
+            return Tag::from_str(s).map(Self::from);
+        }
+
         // Parse string into matching type...
         Tag::from_str(s)
             .map(Self::from)
@@ -343,6 +355,39 @@ fn source_parses_a_uuid_tag() -> crate::Result<()> {
         Ok(())
     }
 
+    #[test]
+    fn source_parses_tags() -> crate::Result<()> {
+        let source = "LABEL=root";
+        let actual: Source = source.parse()?;
+        assert!(actual.is_tag_label());
+
+        let source = "PARTUUID=3a8e4f0b-01";
+        let actual: Source = source.parse()?;
+        assert!(actual.is_tag_partition_uuid());
+
+        let source = "PARTLABEL=EFI";
+        let actual: Source = source.parse()?;
+        assert!(actual.is_tag_partition_label());
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing value after `=` sign")]
+    fn source_does_not_parse_a_tag_without_a_value() {
+        let _: Source = "UUID=".parse().unwrap();
+    }
+
+    #[test]
+    fn source_parses_a_device_path_with_an_equal_sign_as_a_block_device() -> crate::Result<()> {
+        let source = "/dev/disk/by-id/a=b";
+        let actual: Source = source.parse()?;
+
+        assert!(actual.is_block_device());
+
+        Ok(())
+    }
+
     #[test]
     fn source_parses_a_pseudo_fs() -> crate::Result<()> {
         let source = "none";
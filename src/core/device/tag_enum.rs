@@ -31,7 +31,28 @@ pub enum Tag {
 }
 
 impl Tag {
-    /// Returns a `Tag`'s name.
+    /// Returns a `Tag`'s name, identifying which kind of tag it is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsmount::device::{Tag, TagName};
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let tag: Tag = "PARTLABEL=EFI".parse()?;
+    ///
+    ///     let description = match tag.name() {
+    ///         TagName::Label | TagName::PartLabel => format!("labelled {}", tag.value()),
+    ///         TagName::Uuid | TagName::PartUuid => format!("identified by {}", tag.value()),
+    ///         _ => tag.to_string(),
+    ///     };
+    ///
+    ///     assert_eq!(description, "labelled EFI");
+    ///     assert_eq!(tag.name().as_str(), "PARTLABEL");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
     pub fn name(&self) -> TagName {
         TagName::from(self)
     }
@@ -102,13 +123,21 @@ fn try_from(s: &str) -> Result<Self, Self::Error> {
         let (tag_name, value) = s.split_once('=').ok_or(ParserError::Tag(err_msg))?;
 
         let tag_name = TagName::from_str(tag_name)?;
-        match tag_name {
+        let tag = match tag_name {
             TagName::Label => Label::from_str(value).map(Self::Label),
             TagName::PartLabel => Label::from_str(value).map(Self::PartLabel),
             TagName::Uuid => Uuid::from_str(value).map(Self::Uuid),
             TagName::PartUuid => Uuid::from_str(value).map(Self::PartUuid),
             TagName::Id => Id::from_str(value).map(Self::Id),
+        }?;
+
+        if tag.value().is_empty() {
+            let err_msg = format!("invalid tag: {:?}. Missing value after `=` sign", s);
+
+            return Err(ParserError::Tag(err_msg));
         }
+
+        Ok(tag)
     }
 }
 
@@ -135,3 +164,114 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
         Self::try_from(s)
     }
 }
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    #[should_panic(expected = "invalid tag name")]
+    fn tag_can_not_parse_an_unknown_tag_name() {
+        let _: Tag = "FOO=bar".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing `=` sign")]
+    fn tag_can_not_parse_a_string_without_an_equal_sign() {
+        let _: Tag = "UUID".parse().unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing value after `=` sign")]
+    fn tag_can_not_parse_a_tag_without_a_value() {
+        let _: Tag = "LABEL=".parse().unwrap();
+    }
+
+    #[test]
+    fn tag_can_parse_a_label() -> crate::Result<()> {
+        let tag: Tag = "LABEL=root".parse()?;
+
+        assert!(tag.is_label());
+
+        let actual = tag.name();
+        let expected = TagName::Label;
+        assert_eq!(actual, expected);
+
+        let actual = tag.value();
+        let expected = "root";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tag_can_parse_a_uuid() -> crate::Result<()> {
+        let tag: Tag = "UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f".parse()?;
+
+        assert!(tag.is_uuid());
+
+        let actual = tag.name();
+        let expected = TagName::Uuid;
+        assert_eq!(actual, expected);
+
+        let actual = tag.value();
+        let expected = "dd476616-1ce4-415e-9dbd-8c2fa8f42f0f";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tag_can_parse_a_partition_label() -> crate::Result<()> {
+        let tag: Tag = "PARTLABEL=EFI".parse()?;
+
+        assert!(tag.is_partition_label());
+
+        let actual = tag.name();
+        let expected = TagName::PartLabel;
+        assert_eq!(actual, expected);
+
+        let actual = tag.value();
+        let expected = "EFI";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tag_can_parse_a_partition_uuid() -> crate::Result<()> {
+        let tag: Tag = "PARTUUID=3a8e4f0b-01".parse()?;
+
+        assert!(tag.is_partition_uuid());
+
+        let actual = tag.name();
+        let expected = TagName::PartUuid;
+        assert_eq!(actual, expected);
+
+        let actual = tag.value();
+        let expected = "3a8e4f0b-01";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tag_display_round_trips() -> crate::Result<()> {
+        for tag_str in [
+            "LABEL=root",
+            "UUID=1234-ABCD",
+            "PARTLABEL=EFI",
+            "PARTUUID=3a8e4f0b-01",
+        ] {
+            let tag: Tag = tag_str.parse()?;
+
+            let actual = tag.to_string();
+            let expected = tag_str;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+}
@@ -25,17 +25,17 @@ pub(crate) struct FsTbEntBuilder {
     #[builder(default, setter(transform = |line: impl AsRef<str>| Some(line.as_ref().to_owned()),
     doc = "Sets a comment line.\n **Note:** this method adds a newline character at the end of the
 parameter `line` if it is not present."))]
-    comment_line: Option<String>,
+    pub(crate) comment_line: Option<String>,
 
     #[builder(setter(transform = |source: impl Into<Source>| Into::<Source>::into(source), doc = "Sets the device to mount."))]
-    source: Source,
+    pub(crate) source: Source,
 
     #[builder(
         default,
         setter(transform = |target: impl AsRef<Path>| target.as_ref().to_path_buf(),
             doc = "Sets the location of the device mount point.")
     )]
-    target: PathBuf,
+    pub(crate) target: PathBuf,
 
     #[builder(
         default,
@@ -44,11 +44,11 @@ pub(crate) struct FsTbEntBuilder {
             doc = "Sets the file system type of the device to mount."
         )
     )]
-    file_system_type: Option<FileSystem>,
+    pub(crate) file_system_type: Option<FileSystem>,
 
     #[builder(default, setter(transform = |opts: impl AsRef<str>| Some(opts.as_ref().to_owned()),
     doc = "Sets a list of comma-separated options."))]
-    mount_options: Option<String>,
+    pub(crate) mount_options: Option<String>,
     #[builder(
         default,
         setter(
@@ -57,7 +57,7 @@ pub(crate) struct FsTbEntBuilder {
 ext2/3/4 file systems. (see the [`dump` command's manpage](https://manpages.org/dump/8))"
         )
     )]
-    backup_frequency: Option<i32>,
+    pub(crate) backup_frequency: Option<i32>,
 
     #[builder(
         default,
@@ -67,7 +67,7 @@ pub(crate) struct FsTbEntBuilder {
 value to `0` will direct `fsck` to skip the device referenced in this [`FsTabEntry`]."
         )
     )]
-    fsck_checking_order: Option<i32>,
+    pub(crate) fsck_checking_order: Option<i32>,
 }
 
 #[allow(non_camel_case_types)]
@@ -92,37 +92,44 @@ impl<
     pub fn build(self) -> Result<FsTabEntry, FsTabEntryBuilderError> {
         log::debug!("FsTabEntryBuilder::build building a new `FsTabEntry` instance");
 
-        let builder = self.__make();
+        self.__make().into_entry()
+    }
+}
+
+impl FsTbEntBuilder {
+    #[doc(hidden)]
+    /// Creates a new [`FsTabEntry`] from this configuration.
+    pub(crate) fn into_entry(self) -> Result<FsTabEntry, FsTabEntryBuilderError> {
         let mut entry = FsTabEntry::new()?;
 
         // Setting the device/path to mount.
-        entry.set_mount_source(builder.source.to_string())?;
+        entry.set_mount_source(self.source.to_string())?;
 
         // Setting a device's mount point.
-        entry.set_mount_target(builder.target)?;
+        entry.set_mount_target(self.target)?;
 
         // Setting comment line.
-        if let Some(line) = builder.comment_line {
+        if let Some(line) = self.comment_line {
             entry.set_comment(line)?;
         }
 
         // Setting interval between file system backups.
-        if let Some(backup_frequency) = builder.backup_frequency {
+        if let Some(backup_frequency) = self.backup_frequency {
             entry.set_backup_frequency(backup_frequency)?;
         }
 
         // Setting file_system_type.
-        if let Some(file_system_type) = builder.file_system_type {
+        if let Some(file_system_type) = self.file_system_type {
             entry.set_file_system_type(file_system_type)?;
         }
 
         // Setting mount options string.
-        if let Some(mount_options) = builder.mount_options {
+        if let Some(mount_options) = self.mount_options {
             entry.set_mount_options(mount_options)?;
         }
 
         // Setting the order in which file systems are checked by the `fsck` command.
-        if let Some(fsck_checking_order) = builder.fsck_checking_order {
+        if let Some(fsck_checking_order) = self.fsck_checking_order {
             entry.set_fsck_checking_order(fsck_checking_order)?;
         }
 
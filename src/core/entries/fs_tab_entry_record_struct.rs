@@ -0,0 +1,78 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use serde::{Deserialize, Serialize};
+
+// From standard library
+use std::path::PathBuf;
+use std::str::FromStr;
+
+// From this library
+use crate::core::device::Source;
+use crate::core::entries::FsTabEntry;
+use crate::core::entries::FsTbEntBuilder;
+use crate::core::fs::FileSystem;
+
+#[doc(hidden)]
+/// Serialized form of a [`FsTabEntry`].
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct FsTabEntryRecord {
+    source: String,
+    #[serde(default)]
+    target: Option<PathBuf>,
+    #[serde(default)]
+    file_system_type: Option<String>,
+    #[serde(default)]
+    mount_options: Option<String>,
+    #[serde(default)]
+    backup_frequency: i32,
+    #[serde(default)]
+    fsck_checking_order: Option<i32>,
+}
+
+impl From<&FsTabEntry> for FsTabEntryRecord {
+    fn from(entry: &FsTabEntry) -> FsTabEntryRecord {
+        Self {
+            source: entry
+                .source()
+                .map(|source| source.to_string())
+                .unwrap_or_else(|| "none".to_owned()),
+            target: entry.target().map(|target| target.to_path_buf()),
+            file_system_type: entry.file_system_type().map(|fs| fs.to_string()),
+            mount_options: entry.mount_options().map(|options| options.to_owned()),
+            backup_frequency: entry.backup_frequency(),
+            fsck_checking_order: entry.fsck_checking_order().map(|order| order as i32),
+        }
+    }
+}
+
+impl FsTabEntryRecord {
+    #[doc(hidden)]
+    /// Converts this record into a [`FsTabEntry`], validating its fields through the
+    /// [`FsTabEntryBuilder`](crate::core::entries::FsTabEntryBuilder).
+    pub(crate) fn into_entry<E>(self) -> Result<FsTabEntry, E>
+    where
+        E: serde::de::Error,
+    {
+        let source = Source::from_str(&self.source).map_err(E::custom)?;
+        let file_system_type = self
+            .file_system_type
+            .as_deref()
+            .map(FileSystem::from_str)
+            .transpose()
+            .map_err(E::custom)?;
+
+        let builder = FsTbEntBuilder {
+            comment_line: None,
+            source,
+            target: self.target.unwrap_or_default(),
+            file_system_type,
+            mount_options: self.mount_options,
+            backup_frequency: Some(self.backup_frequency),
+            fsck_checking_order: self.fsck_checking_order,
+        };
+
+        builder.into_entry().map_err(E::custom)
+    }
+}
@@ -4,8 +4,11 @@
 // From dependency library
 
 // From standard library
+use std::collections::BTreeSet;
 use std::fmt;
 use std::fs::File;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::mem::MaybeUninit;
 use std::path::Path;
 use std::str::FromStr;
@@ -17,15 +20,21 @@
 use crate::core::device::Tag;
 
 use crate::core::entries::FsTabEntryBuilder;
+#[cfg(feature = "serde")]
+use crate::core::entries::FsTabEntryRecord;
 use crate::core::entries::FsTbEntBuilder;
 use crate::core::entries::MntEnt;
+use crate::core::entries::ValidationIssue;
 
 use crate::core::errors::FsTabEntryError;
 use crate::core::fs::FileSystem;
+use crate::core::optstring;
+use crate::core::utils;
 use crate::ffi_utils;
+use crate::tables::MountOption;
 
 /// A configuration line in `/etc/fstab`.
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 #[repr(transparent)]
 pub struct FsTabEntry {
     pub(crate) inner: *mut libmount::libmnt_fs,
@@ -161,6 +170,117 @@ pub fn builder() -> FsTabEntryBuilder {
         FsTbEntBuilder::builder()
     }
 
+    /// Parses a single line of an `fstab` file into a new `FsTabEntry`.
+    ///
+    /// A line is made of whitespace-separated fields: source, target, file system type, mount
+    /// options, backup frequency, and `fsck` checking order. The last three fields are optional;
+    /// missing backup frequency and `fsck` checking order default to `0`. Escaped characters
+    /// (e.g. `\040` for a space) are decoded.
+    ///
+    /// Returns `Ok(None)` if the line is empty, or a comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use std::path::Path;
+    /// use rsmount::entries::FsTabEntry;
+    /// use rsmount::fs::FileSystem;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let line = r"/dev/sdb1 /media/USB\040Stick vfat noauto";
+    ///     let entry = FsTabEntry::from_line(line)?.unwrap();
+    ///
+    ///     let actual = entry.target();
+    ///     let expected = Some(Path::new("/media/USB Stick"));
+    ///     assert_eq!(actual, expected);
+    ///
+    ///     let actual = entry.file_system_type();
+    ///     let expected = Some(FileSystem::VFAT);
+    ///     assert_eq!(actual, expected);
+    ///
+    ///     // Comments are not entries.
+    ///     let actual = FsTabEntry::from_line("# /etc/fstab")?;
+    ///     assert!(actual.is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_line<T>(line: T) -> Result<Option<FsTabEntry>, FsTabEntryError>
+    where
+        T: AsRef<str>,
+    {
+        let line = line.as_ref();
+        log::debug!("FsTabEntry::from_line parsing line: {:?}", line);
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            log::debug!("FsTabEntry::from_line line is empty or a comment");
+
+            return Ok(None);
+        }
+
+        let fields: Vec<_> = trimmed.split_whitespace().collect();
+        if !(3..=6).contains(&fields.len()) {
+            let err_msg = format!(
+                "expected 3 to 6 fields, found {} in line: {:?}",
+                fields.len(),
+                line
+            );
+            log::debug!("FsTabEntry::from_line {}", err_msg);
+
+            return Err(FsTabEntryError::Parse(err_msg));
+        }
+
+        let decode = |field: &str| {
+            utils::fstab_decode(field).ok_or_else(|| {
+                let err_msg = format!("failed to decode field {:?} in line: {:?}", field, line);
+                log::debug!("FsTabEntry::from_line {}", err_msg);
+
+                FsTabEntryError::Parse(err_msg)
+            })
+        };
+
+        let parse_number = |field: Option<&&str>| match field {
+            Some(field) => field.parse::<i32>().map_err(|_| {
+                let err_msg = format!("invalid number {:?} in line: {:?}", field, line);
+                log::debug!("FsTabEntry::from_line {}", err_msg);
+
+                FsTabEntryError::Parse(err_msg)
+            }),
+            None => Ok(0),
+        };
+
+        let source = decode(fields[0])?;
+        let target = decode(fields[1])?;
+        let fs_type = decode(fields[2])?;
+        let backup_frequency = parse_number(fields.get(4))?;
+        let fsck_checking_order = parse_number(fields.get(5))?;
+
+        let fs_type = FileSystem::from_str(&fs_type).map_err(|e| {
+            let err_msg = format!("invalid file system type in line: {:?}. {}", line, e);
+            log::debug!("FsTabEntry::from_line {}", err_msg);
+
+            FsTabEntryError::Parse(err_msg)
+        })?;
+
+        let mut entry = FsTabEntry::new()?;
+        entry.set_mount_source(source)?;
+        entry.set_mount_target(target)?;
+        entry.set_file_system_type(fs_type)?;
+
+        if let Some(options) = fields.get(3) {
+            entry.set_mount_options(decode(options)?)?;
+        }
+
+        entry.set_backup_frequency(backup_frequency)?;
+        entry.set_fsck_checking_order(fsck_checking_order)?;
+
+        log::debug!("FsTabEntry::from_line parsed line: {:?}", line);
+
+        Ok(Some(entry))
+    }
+
     //---- BEGIN getters
 
     /// Allocates a new `FsTabEntry`, and a copies all the source's fields to the new
@@ -440,6 +560,25 @@ pub fn mount_options(&self) -> Option<&str> {
         }
     }
 
+    /// Returns the list of mount options of this entry, parsed into [`MountOption`]s.
+    ///
+    /// Returns an empty `Vec` if the entry has no mount options. Quoted values are returned without
+    /// their surrounding double quotes.
+    pub fn options(&self) -> Vec<MountOption> {
+        log::debug!("FsTabEntry::options parsing mount options");
+
+        self.mount_options()
+            .map(optstring::parse_options)
+            .unwrap_or_default()
+    }
+
+    #[doc(hidden)]
+    /// Returns the set of mount options of this entry, to compare entries regardless of the order
+    /// in which their options are listed.
+    fn option_set(&self) -> BTreeSet<MountOption> {
+        self.options().into_iter().collect()
+    }
+
     /// Returns the interval in days between file system backups by the `dump` command on ext2/3/4 filesystems.
     pub fn backup_frequency(&self) -> i32 {
         let freq = unsafe { libmount::mnt_fs_get_freq(self.inner) };
@@ -560,6 +699,28 @@ pub fn set_comment(&mut self, mut comment: String) -> Result<(), FsTabEntryError
         }
     }
 
+    #[doc(hidden)]
+    /// Removes the comment line associated with this entry.
+    pub(crate) fn remove_comment(&mut self) -> Result<(), FsTabEntryError> {
+        log::debug!("FsTabEntry::remove_comment removing comment line");
+
+        let result = unsafe { libmount::mnt_fs_set_comment(self.inner, std::ptr::null()) };
+
+        match result {
+            0 => {
+                log::debug!("FsTabEntry::remove_comment removed comment line");
+
+                Ok(())
+            }
+            code => {
+                let err_msg = "failed to remove comment line".to_owned();
+                log::debug!( "FsTabEntry::remove_comment {}. libmount::mnt_fs_set_comment returned error code: {:?}", err_msg, code);
+
+                Err(FsTabEntryError::Config(err_msg))
+            }
+        }
+    }
+
     /// Sets the file system associated with the device to mount.
     pub fn set_file_system_type(&mut self, fs_type: FileSystem) -> Result<(), FsTabEntryError> {
         log::debug!(
@@ -679,6 +840,114 @@ pub fn set_mount_options<T>(&mut self, options: T) -> Result<(), FsTabEntryError
         }
     }
 
+    /// Sets the mount options of this entry from a list of [`MountOption`]s.
+    pub fn set_options_from(&mut self, options: &[MountOption]) -> Result<(), FsTabEntryError> {
+        let options = options
+            .iter()
+            .map(|option| option.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        log::debug!(
+            "FsTabEntry::set_options_from setting mount options to: {:?}",
+            options
+        );
+
+        self.set_mount_options(options)
+    }
+
+    /// Sets the value of the mount option `name`, leaving all other options untouched.
+    ///
+    /// If the entry does not have an option named `name`, it is appended at the end of its mount
+    /// options. Otherwise, its value is updated in place; a value of `None` turns the option into
+    /// a flag (e.g. `ro=recursive` becomes `ro`).
+    ///
+    /// **Note:** a value containing commas should be enclosed in double-quotes (e.g.
+    /// `"\"system_u:object_r:tmp_t:s0:c127,c456\""`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use rsmount::device::Tag;
+    /// use rsmount::entries::FsTabEntry;
+    /// use rsmount::fs::FileSystem;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let label: Tag = "LABEL=data".parse()?;
+    ///     let mut entry = FsTabEntry::builder()
+    ///         .source(label)
+    ///         .target("/data")
+    ///         .file_system_type(FileSystem::Ext4)
+    ///         .mount_options("rw,noatime")
+    ///         .build()?;
+    ///
+    ///     entry.set_option("ro", None)?;
+    ///
+    ///     let actual = entry.mount_options();
+    ///     let expected = Some("rw,noatime,ro");
+    ///     assert_eq!(actual, expected);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_option<T>(&mut self, name: T, value: Option<&str>) -> Result<(), FsTabEntryError>
+    where
+        T: AsRef<str>,
+    {
+        let name = name.as_ref();
+        log::debug!(
+            "FsTabEntry::set_option setting option {:?} to value {:?}",
+            name,
+            value
+        );
+
+        let options = self.mount_options().unwrap_or_default();
+
+        let new_options = match (self.has_option(name), value) {
+            (true, Some(value)) => optstring::set_option_value(options, name, value),
+            (true, None) => optstring::unset_option_value(options, name),
+            (false, Some(value)) => optstring::append_option(options, name, value),
+            (false, None) if options.is_empty() => Some(name.to_owned()),
+            (false, None) => Some(format!("{},{}", options, name)),
+        };
+
+        match new_options {
+            Some(options) => self.set_mount_options(options),
+            None => {
+                let err_msg = format!("failed to set option {:?} to value {:?}", name, value);
+                log::debug!("FsTabEntry::set_option {}", err_msg);
+
+                Err(FsTabEntryError::Config(err_msg))
+            }
+        }
+    }
+
+    /// Removes all instances of the mount option `name` from this entry, leaving all other
+    /// options untouched. Does nothing if the entry does not have a matching option.
+    pub fn remove_option<T>(&mut self, name: T) -> Result<(), FsTabEntryError>
+    where
+        T: AsRef<str>,
+    {
+        let name = name.as_ref();
+        log::debug!("FsTabEntry::remove_option removing option {:?}", name);
+
+        if !self.has_option(name) {
+            log::debug!(
+                "FsTabEntry::remove_option found no option {:?} to remove",
+                name
+            );
+
+            return Ok(());
+        }
+
+        let mut options = self.mount_options().unwrap_or_default().to_owned();
+        while let Some(new_options) = optstring::remove_option(&options, name) {
+            options = new_options;
+        }
+
+        self.set_mount_options(options)
+    }
+
     #[doc(hidden)]
     /// Sets the source of the device to mount.
     ///
@@ -936,10 +1205,82 @@ pub fn to_mnt_ent(&self) -> Result<MntEnt, FsTabEntryError> {
         }
     }
 
+    /// Renders this `FsTabEntry` as a single `fstab` line, without a trailing newline.
+    ///
+    /// Fields are separated by a single space, and escaped with [`utils::fstab_encode`] (e.g. a
+    /// space in a mount point is written as `\040`), so that the line can be parsed back by
+    /// [`FsTabEntry::from_line`], or appended to an existing `fstab` file. Missing fields are
+    /// replaced by their usual placeholders: `none` for the source and target, `auto` for the file
+    /// system type, and `defaults` for the mount options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use rsmount::entries::FsTabEntry;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let line = r"/dev/sdb1   /media/USB\040Stick  vfat";
+    ///     let entry = FsTabEntry::from_line(line)?.unwrap();
+    ///
+    ///     let actual = entry.to_line();
+    ///     let expected = r"/dev/sdb1 /media/USB\040Stick vfat defaults 0 0";
+    ///     assert_eq!(actual, expected);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_line(&self) -> String {
+        log::debug!("FsTabEntry::to_line rendering `FsTabEntry` as an `fstab` line");
+
+        let read_field = |ptr: *const libc::c_char| {
+            (!ptr.is_null())
+                .then(|| ffi_utils::const_char_array_to_str_ref(ptr).ok())
+                .flatten()
+                .filter(|field| !field.is_empty())
+        };
+        let encode = |field: &str| utils::fstab_encode(field).unwrap_or_else(|| field.to_owned());
+
+        let source = read_field(unsafe { libmount::mnt_fs_get_source(self.inner) });
+        let target = read_field(unsafe { libmount::mnt_fs_get_target(self.inner) });
+        let fs_type = read_field(unsafe { libmount::mnt_fs_get_fstype(self.inner) });
+        let options = self.mount_options().filter(|options| !options.is_empty());
+
+        let line = format!(
+            "{} {} {} {} {} {}",
+            encode(source.unwrap_or("none")),
+            encode(target.unwrap_or("none")),
+            encode(fs_type.unwrap_or("auto")),
+            encode(options.unwrap_or("defaults")),
+            self.backup_frequency(),
+            self.fsck_checking_order().unwrap_or(0),
+        );
+        log::debug!("FsTabEntry::to_line rendered line: {:?}", line);
+
+        line
+    }
+
     //---- END mutators
 
     //---- BEGIN predicates
 
+    /// Returns `true` if this entry has a mount option named `name`, with or without a value.
+    pub fn has_option<T>(&self, name: T) -> bool
+    where
+        T: AsRef<str>,
+    {
+        let name = name.as_ref();
+
+        let state = self
+            .mount_options()
+            .and_then(|options| optstring::scan(options).ok())
+            .map(|mut scanner| scanner.any(|(option_name, _)| option_name == name))
+            .unwrap_or(false);
+        log::debug!("FsTabEntry::has_option has option {:?}? {:?}", name, state);
+
+        state
+    }
+
     /// Returns `true` if mount options do (or do not) contain an element of the `pattern`
     /// parameter (a comma-separated list of values). See the [`mount` command's
     /// manpage](https://www.man7.org/linux/man-pages/man8/mount.8.html#FILESYSTEM-INDEPENDENT_MOUNT_OPTIONS)
@@ -1197,6 +1538,128 @@ pub fn is_exact_target<T>(&self, path: T) -> bool
     }
 
     //---- END predicates
+
+    /// Checks this entry for common semantic mistakes, returning every issue found.
+    ///
+    /// The following rules are enforced:
+    /// - the mount point is an absolute path,
+    /// - pseudo, and network file systems not mounted at `/` have a `fsck` checking order of `0`,
+    /// - swap entries have `none` as their mount point,
+    /// - mount options do not contain mutually exclusive flags (e.g. `ro`, and `rw`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsmount::device::Tag;
+    /// use rsmount::entries::{FsTabEntry, ValidationIssue};
+    /// use rsmount::fs::FileSystem;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let label: Tag = "LABEL=data".parse()?;
+    ///     let entry = FsTabEntry::builder()
+    ///         .source(label)
+    ///         .target("/data")
+    ///         .file_system_type(FileSystem::Ext4)
+    ///         .mount_options("ro,noatime,rw")
+    ///         .build()?;
+    ///
+    ///     let actual = entry.validate();
+    ///     let expected = Err(vec![ValidationIssue::ContradictoryOptions(
+    ///         "ro".to_owned(),
+    ///         "rw".to_owned(),
+    ///     )]);
+    ///     assert_eq!(actual, expected);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        const CONTRADICTORY_OPTIONS: [(&str, &str); 6] = [
+            ("ro", "rw"),
+            ("sync", "async"),
+            ("dev", "nodev"),
+            ("exec", "noexec"),
+            ("suid", "nosuid"),
+            ("auto", "noauto"),
+        ];
+
+        log::debug!("FsTabEntry::validate checking entry for semantic mistakes");
+
+        let mut issues = Vec::new();
+
+        match self.target() {
+            None => issues.push(ValidationIssue::MissingTarget),
+            Some(target) if self.is_swap() => {
+                if target != Path::new("none") {
+                    issues.push(ValidationIssue::SwapTargetNotNone(target.to_path_buf()));
+                }
+            }
+            Some(target) => {
+                if !target.is_absolute() {
+                    issues.push(ValidationIssue::RelativeTarget(target.to_path_buf()));
+                }
+
+                if (self.is_pseudo_fs() || self.is_net_fs()) && target != Path::new("/") {
+                    if let Some(order) = self.fsck_checking_order().filter(|&order| order != 0) {
+                        issues.push(ValidationIssue::NonZeroFsckOrder {
+                            target: target.to_path_buf(),
+                            order,
+                        });
+                    }
+                }
+            }
+        }
+
+        for (first, second) in CONTRADICTORY_OPTIONS {
+            if self.has_option(first) && self.has_option(second) {
+                issues.push(ValidationIssue::ContradictoryOptions(
+                    first.to_owned(),
+                    second.to_owned(),
+                ));
+            }
+        }
+
+        if issues.is_empty() {
+            log::debug!("FsTabEntry::validate found no issue");
+
+            Ok(())
+        } else {
+            log::debug!("FsTabEntry::validate found issues: {:?}", issues);
+
+            Err(issues)
+        }
+    }
+}
+
+impl PartialEq for FsTabEntry {
+    /// Returns `true` if both entries have the same source, target, file system type, mount
+    /// options, backup frequency, and fsck checking order.
+    ///
+    /// Mount options are compared as a set, i.e. regardless of the order in which they are
+    /// listed (`rw,relatime` is equal to `relatime,rw`).
+    fn eq(&self, other: &Self) -> bool {
+        self.source() == other.source()
+            && self.target() == other.target()
+            && self.file_system_type() == other.file_system_type()
+            && self.option_set() == other.option_set()
+            && self.backup_frequency() == other.backup_frequency()
+            && self.fsck_checking_order() == other.fsck_checking_order()
+    }
+}
+
+impl Eq for FsTabEntry {}
+
+impl Hash for FsTabEntry {
+    /// Hashes the same fields as those compared for equality, so that two entries with the same
+    /// mount options listed in a different order have the same hash.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.source().map(|source| source.to_string()).hash(state);
+        self.target().hash(state);
+        self.file_system_type().hash(state);
+        self.option_set().hash(state);
+        self.backup_frequency().hash(state);
+        self.fsck_checking_order().hash(state);
+    }
 }
 
 impl fmt::Display for FsTabEntry {
@@ -1229,14 +1692,159 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for FsTabEntry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&FsTabEntryRecord::from(self), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FsTabEntry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        <FsTabEntryRecord as serde::Deserialize>::deserialize(deserializer)?.into_entry()
+    }
+}
+
 #[cfg(test)]
 #[allow(unused_imports)]
 mod tests {
     use super::*;
+    use crate::core::device::Pseudo;
     use crate::core::fs::FileSystem;
     use pretty_assertions::{assert_eq, assert_ne};
+    use std::collections::HashSet;
     use std::path::Path;
 
+    #[test]
+    fn fs_tab_entry_can_parse_a_line_with_six_fields() -> crate::Result<()> {
+        let line = "UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f / ext4 rw,relatime 0 1";
+        let entry = FsTabEntry::from_line(line)?.unwrap();
+
+        let actual = entry.tag();
+        let tag: Tag = "UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f".parse()?;
+        let expected = Some(tag);
+        assert_eq!(actual, expected);
+
+        let actual = entry.target();
+        let expected = Some(Path::new("/"));
+        assert_eq!(actual, expected);
+
+        let actual = entry.file_system_type();
+        let expected = Some(FileSystem::Ext4);
+        assert_eq!(actual, expected);
+
+        let actual = entry.mount_options();
+        let expected = Some("rw,relatime");
+        assert_eq!(actual, expected);
+
+        let actual = entry.backup_frequency();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        let actual = entry.fsck_checking_order();
+        let expected = Some(1);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_can_parse_a_line_with_four_fields() -> crate::Result<()> {
+        let line = "tmpfs\t/tmp\ttmpfs   nosuid,nodev";
+        let entry = FsTabEntry::from_line(line)?.unwrap();
+
+        let actual = entry.mount_options();
+        let expected = Some("nosuid,nodev");
+        assert_eq!(actual, expected);
+
+        let actual = entry.backup_frequency();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        let actual = entry.fsck_checking_order();
+        let expected = Some(0);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_can_parse_a_line_with_an_escaped_space_in_the_target() -> crate::Result<()> {
+        let line = r"/dev/sdb1 /media/USB\040Stick vfat noauto,user 0 0";
+        let entry = FsTabEntry::from_line(line)?.unwrap();
+
+        let actual = entry.target();
+        let expected = Some(Path::new("/media/USB Stick"));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_can_round_trip_a_line_with_a_tricky_path() -> crate::Result<()> {
+        let line = r"/dev/sdb1 /media/My\040USB\011Stick\134backup vfat noauto,user 0 2";
+        let entry = FsTabEntry::from_line(line)?.unwrap();
+
+        let actual = entry.target();
+        let expected = Some(Path::new("/media/My USB\tStick\\backup"));
+        assert_eq!(actual, expected);
+
+        let actual = entry.to_line();
+        let expected = line;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_escapes_a_space_in_the_target_of_a_line() -> crate::Result<()> {
+        let entry = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target("/mnt/my disk")
+            .file_system_type(FileSystem::Tmpfs)
+            .build()?;
+
+        let actual = entry.to_line();
+        let expected = r"none /mnt/my\040disk tmpfs defaults 0 0";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_does_not_parse_empty_or_comment_lines() -> crate::Result<()> {
+        for line in [
+            "",
+            "   \t",
+            "# /etc/fstab",
+            "  # tmpfs /tmp tmpfs defaults 0 0",
+        ] {
+            let actual = FsTabEntry::from_line(line)?;
+            assert!(actual.is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_can_not_parse_a_malformed_line() {
+        for line in [
+            "/dev/sda1 /",
+            "/dev/sda1 / ext4 rw 0 1 extra",
+            "/dev/sda1 / ext4 rw zero 1",
+        ] {
+            let actual = FsTabEntry::from_line(line);
+            assert!(matches!(actual, Err(FsTabEntryError::Parse(_))));
+        }
+    }
+
     #[test]
     fn fs_tab_entry_can_build_an_instance_with_a_uuid_source() -> crate::Result<()> {
         // Root mount
@@ -1296,4 +1904,414 @@ fn fs_tab_entry_can_build_an_instance_with_a_uuid_source() -> crate::Result<()>
 
         Ok(())
     }
+    #[test]
+    #[cfg(feature = "serde")]
+    fn fs_tab_entry_can_be_serialized() -> crate::Result<()> {
+        let uuid: Tag = "UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f".parse()?;
+        let entry = FsTabEntry::builder()
+            .source(uuid)
+            .target("/")
+            .file_system_type(FileSystem::Ext4)
+            .mount_options("rw,relatime")
+            .backup_frequency(0)
+            .fsck_checking_order(1)
+            .build()?;
+
+        let actual = serde_json::to_value(&entry).unwrap();
+        let expected = serde_json::json!({
+            "source": "UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f",
+            "target": "/",
+            "file_system_type": "ext4",
+            "mount_options": "rw,relatime",
+            "backup_frequency": 0,
+            "fsck_checking_order": 1,
+        });
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn fs_tab_entry_can_be_deserialized_and_pushed_into_a_table() -> crate::Result<()> {
+        let json = r#"[
+            {
+                "source": "UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f",
+                "target": "/",
+                "file_system_type": "ext4",
+                "mount_options": "rw,relatime",
+                "backup_frequency": 0,
+                "fsck_checking_order": 1
+            },
+            {
+                "source": "none",
+                "target": "/tmp",
+                "file_system_type": "tmpfs",
+                "mount_options": "nosuid,nodev"
+            }
+        ]"#;
+
+        let entries: Vec<FsTabEntry> = serde_json::from_str(json).unwrap();
+        let mut fs_tab = crate::tables::FsTab::new()?;
+
+        for entry in entries {
+            fs_tab.push(entry);
+        }
+
+        assert_eq!(fs_tab.len(), 2);
+
+        let actual: Vec<_> = fs_tab.iter().map(|e| e.target().unwrap()).collect();
+        let expected = vec![Path::new("/"), Path::new("/tmp")];
+        assert_eq!(actual, expected);
+
+        let actual = fs_tab[0].file_system_type();
+        let expected = Some(FileSystem::Ext4);
+        assert_eq!(actual, expected);
+
+        let actual = fs_tab[1].mount_options();
+        let expected = Some("nosuid,nodev");
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_can_parse_its_mount_options() -> crate::Result<()> {
+        let label: Tag = "LABEL=scratch".parse()?;
+        let entry = FsTabEntry::builder()
+            .source(label)
+            .target("/tmp")
+            .file_system_type(FileSystem::Ext4)
+            .mount_options("nosuid,nodev,noatime")
+            .build()?;
+
+        let actual = entry.options();
+        let expected: Vec<MountOption> =
+            vec!["nosuid".parse()?, "nodev".parse()?, "noatime".parse()?];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_can_set_its_mount_options_from_a_list_of_options() -> crate::Result<()> {
+        let label: Tag = "LABEL=scratch".parse()?;
+        let mut entry = FsTabEntry::builder()
+            .source(label)
+            .target("/tmp")
+            .file_system_type(FileSystem::Ext4)
+            .build()?;
+
+        let actual = entry.options();
+        assert!(actual.is_empty());
+
+        let options: Vec<MountOption> = vec![
+            "nodev".parse()?,
+            r#"context="system_u:object_r:tmp_t:s0:c127,c456""#.parse()?,
+            "ro".parse()?,
+        ];
+        entry.set_options_from(&options)?;
+
+        let actual = entry.mount_options();
+        let expected = Some(r#"nodev,context="system_u:object_r:tmp_t:s0:c127,c456",ro"#);
+        assert_eq!(actual, expected);
+
+        let actual = entry.options();
+        let expected = options;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_can_append_a_new_option() -> crate::Result<()> {
+        let label: Tag = "LABEL=scratch".parse()?;
+        let mut entry = FsTabEntry::builder()
+            .source(label)
+            .target("/scratch")
+            .file_system_type(FileSystem::Ext4)
+            .mount_options("rw,noatime")
+            .build()?;
+
+        assert!(!entry.has_option("ro"));
+        entry.set_option("ro", None)?;
+        assert!(entry.has_option("ro"));
+
+        entry.set_option("commit", Some("60"))?;
+
+        let actual = entry.to_string();
+        let expected = "LABEL=scratch /scratch ext4 rw,noatime,ro,commit=60 0 0";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_can_update_an_existing_option() -> crate::Result<()> {
+        let label: Tag = "LABEL=scratch".parse()?;
+        let mut entry = FsTabEntry::builder()
+            .source(label)
+            .target("/scratch")
+            .file_system_type(FileSystem::Ext4)
+            .mount_options("ro=recursive,commit=5,noatime")
+            .build()?;
+
+        entry.set_option("commit", Some("60"))?;
+        entry.set_option("ro", None)?;
+
+        let actual = entry.to_string();
+        let expected = "LABEL=scratch /scratch ext4 ro,commit=60,noatime 0 0";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_can_set_an_option_on_an_entry_without_options() -> crate::Result<()> {
+        let label: Tag = "LABEL=scratch".parse()?;
+        let mut entry = FsTabEntry::builder()
+            .source(label)
+            .target("/scratch")
+            .file_system_type(FileSystem::Ext4)
+            .build()?;
+
+        entry.set_option("ro", None)?;
+
+        let actual = entry.mount_options();
+        let expected = Some("ro");
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_can_remove_an_option() -> crate::Result<()> {
+        let label: Tag = "LABEL=scratch".parse()?;
+        let mut entry = FsTabEntry::builder()
+            .source(label)
+            .target("/scratch")
+            .file_system_type(FileSystem::Ext4)
+            .mount_options("noatime,ro,nodev,ro")
+            .build()?;
+
+        entry.remove_option("ro")?;
+        assert!(!entry.has_option("ro"));
+
+        // Removing a missing option is a no-op.
+        entry.remove_option("ro")?;
+
+        let actual = entry.to_string();
+        let expected = "LABEL=scratch /scratch ext4 noatime,nodev 0 0";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_equality_ignores_the_order_of_mount_options() -> crate::Result<()> {
+        let entry = |options: &str| -> crate::Result<FsTabEntry> {
+            let uuid: Tag = "UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f".parse()?;
+            let entry = FsTabEntry::builder()
+                .source(uuid)
+                .target("/")
+                .file_system_type(FileSystem::Ext4)
+                .mount_options(options)
+                .backup_frequency(0)
+                .fsck_checking_order(1)
+                .build()?;
+
+            Ok(entry)
+        };
+
+        assert_eq!(entry("rw,relatime")?, entry("rw,relatime")?);
+        assert_eq!(entry("rw,relatime")?, entry("relatime,rw")?);
+        assert_ne!(entry("rw,relatime")?, entry("ro,relatime")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_equality_compares_all_semantic_fields() -> crate::Result<()> {
+        let uuid: Tag = "UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f".parse()?;
+        let entry = FsTabEntry::builder()
+            .source(uuid.clone())
+            .target("/")
+            .file_system_type(FileSystem::Ext4)
+            .mount_options("rw,relatime")
+            .fsck_checking_order(1)
+            .build()?;
+
+        let other_target = FsTabEntry::builder()
+            .source(uuid.clone())
+            .target("/home")
+            .file_system_type(FileSystem::Ext4)
+            .mount_options("rw,relatime")
+            .fsck_checking_order(1)
+            .build()?;
+        assert_ne!(entry, other_target);
+
+        let other_order = FsTabEntry::builder()
+            .source(uuid)
+            .target("/")
+            .file_system_type(FileSystem::Ext4)
+            .mount_options("rw,relatime")
+            .fsck_checking_order(2)
+            .build()?;
+        assert_ne!(entry, other_order);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_hash_ignores_the_order_of_mount_options() -> crate::Result<()> {
+        let entry = |options: &str| -> crate::Result<FsTabEntry> {
+            let uuid: Tag = "UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f".parse()?;
+            let entry = FsTabEntry::builder()
+                .source(uuid)
+                .target("/")
+                .file_system_type(FileSystem::Ext4)
+                .mount_options(options)
+                .build()?;
+
+            Ok(entry)
+        };
+
+        let mut entries = HashSet::new();
+        entries.insert(entry("rw,relatime")?);
+        entries.insert(entry("relatime,rw")?);
+
+        let actual = entries.len();
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        entries.insert(entry("ro,relatime")?);
+
+        let actual = entries.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    fn entry_to_validate(
+        target: &str,
+        fs_type: FileSystem,
+        options: &str,
+        fsck_order: i32,
+    ) -> crate::Result<FsTabEntry> {
+        let label: Tag = "LABEL=data".parse()?;
+        let entry = FsTabEntry::builder()
+            .source(label)
+            .target(target)
+            .file_system_type(fs_type)
+            .mount_options(options)
+            .fsck_checking_order(fsck_order)
+            .build()?;
+
+        Ok(entry)
+    }
+
+    #[test]
+    fn fs_tab_entry_validate_accepts_a_valid_entry() -> crate::Result<()> {
+        let entry = entry_to_validate("/", FileSystem::Ext4, "rw,relatime", 1)?;
+
+        let actual = entry.validate();
+        let expected = Ok(());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_validate_requires_an_absolute_target() -> crate::Result<()> {
+        let entry = entry_to_validate("/data", FileSystem::Ext4, "rw", 2)?;
+
+        let actual = entry.validate();
+        let expected = Ok(());
+        assert_eq!(actual, expected);
+
+        let entry = entry_to_validate("data", FileSystem::Ext4, "rw", 2)?;
+
+        let actual = entry.validate();
+        let expected = Err(vec![ValidationIssue::RelativeTarget("data".into())]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_validate_requires_a_zero_fsck_order_for_pseudo_and_network_fs(
+    ) -> crate::Result<()> {
+        let entry = entry_to_validate("/proc", FileSystem::Proc, "defaults", 0)?;
+
+        let actual = entry.validate();
+        let expected = Ok(());
+        assert_eq!(actual, expected);
+
+        let entry = entry_to_validate("/proc", FileSystem::Proc, "defaults", 2)?;
+
+        let actual = entry.validate();
+        let expected = Err(vec![ValidationIssue::NonZeroFsckOrder {
+            target: "/proc".into(),
+            order: 2,
+        }]);
+        assert_eq!(actual, expected);
+
+        let entry = entry_to_validate("/mnt/share", FileSystem::NFS, "defaults", 1)?;
+
+        let actual = entry.validate();
+        let expected = Err(vec![ValidationIssue::NonZeroFsckOrder {
+            target: "/mnt/share".into(),
+            order: 1,
+        }]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_validate_requires_swap_entries_to_target_none() -> crate::Result<()> {
+        let entry = entry_to_validate("none", FileSystem::Swap, "sw", 0)?;
+
+        let actual = entry.validate();
+        let expected = Ok(());
+        assert_eq!(actual, expected);
+
+        let entry = entry_to_validate("/swap", FileSystem::Swap, "sw", 0)?;
+
+        let actual = entry.validate();
+        let expected = Err(vec![ValidationIssue::SwapTargetNotNone("/swap".into())]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_entry_validate_rejects_contradictory_options() -> crate::Result<()> {
+        let entry = entry_to_validate("/data", FileSystem::Ext4, "ro,noexec", 2)?;
+
+        let actual = entry.validate();
+        let expected = Ok(());
+        assert_eq!(actual, expected);
+
+        let entry = entry_to_validate("/data", FileSystem::Ext4, "ro,exec,rw,noexec", 2)?;
+
+        let actual = entry.validate();
+        let expected = Err(vec![
+            ValidationIssue::ContradictoryOptions("ro".to_owned(), "rw".to_owned()),
+            ValidationIssue::ContradictoryOptions("exec".to_owned(), "noexec".to_owned()),
+        ]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn fs_tab_entry_deserialization_rejects_invalid_mount_options() {
+        let json = r#"{ "source": "none", "target": "/tmp", "mount_options": "ro\u0000exec" }"#;
+
+        let actual = serde_json::from_str::<FsTabEntry>(json);
+        assert!(actual.is_err());
+    }
 }
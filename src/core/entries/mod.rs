@@ -10,25 +10,39 @@
 pub use fs_tab_entry_builder_struct::FsTabEntryBuilder;
 pub(crate) use fs_tab_entry_builder_struct::FsTbEntBuilder;
 pub use fs_tab_entry_diff_struct::FsTabEntryDiff;
+#[cfg(feature = "serde")]
+pub(crate) use fs_tab_entry_record_struct::FsTabEntryRecord;
 pub use fs_tab_entry_struct::FsTabEntry;
 pub use mnt_ent_struct::MntEnt;
+pub use mount_info_change_struct::MountInfoChange;
 pub use mount_info_entry_diff_struct::MountInfoEntryDiff;
+#[cfg(feature = "serde")]
+pub(crate) use mount_info_entry_record_struct::MountInfoEntryRecord;
 pub use mount_info_entry_struct::MountInfoEntry;
+pub use swap_type_enum::SwapType;
 pub use swaps_entry_diff_struct::SwapsEntryDiff;
 pub use swaps_entry_struct::SwapsEntry;
 pub use utab_entry_builder_struct::UTabEntryBuilder;
 pub(crate) use utab_entry_builder_struct::UTbEntBuilder;
 pub use utab_entry_diff_struct::UTabEntryDiff;
 pub use utab_entry_struct::UTabEntry;
+pub use validation_issue_enum::ValidationIssue;
 
 mod fs_tab_entry_builder_struct;
 mod fs_tab_entry_diff_struct;
+#[cfg(feature = "serde")]
+mod fs_tab_entry_record_struct;
 mod fs_tab_entry_struct;
 mod mnt_ent_struct;
+mod mount_info_change_struct;
 mod mount_info_entry_diff_struct;
+#[cfg(feature = "serde")]
+mod mount_info_entry_record_struct;
 mod mount_info_entry_struct;
+mod swap_type_enum;
 mod swaps_entry_diff_struct;
 mod swaps_entry_struct;
 mod utab_entry_builder_struct;
 mod utab_entry_diff_struct;
 mod utab_entry_struct;
+mod validation_issue_enum;
@@ -0,0 +1,77 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::entries::MountInfoEntry;
+use crate::core::entries::MountInfoEntryDiff;
+use crate::tables::Comparison;
+
+/// Owned record of a change between two [`MountInfo`](crate::tables::MountInfo) tables.
+///
+/// Unlike a [`MountInfoEntryDiff`], a `MountInfoChange` does not borrow from the
+/// [`MountInfoDiff`](crate::tables::MountInfoDiff) that produced it, and can outlive the compared
+/// tables.
+#[derive(Debug)]
+pub struct MountInfoChange {
+    source: Option<MountInfoEntry>,
+    other: Option<MountInfoEntry>,
+    comparisons: Vec<Comparison>,
+}
+
+impl MountInfoChange {
+    #[doc(hidden)]
+    /// Creates a new `MountInfoChange` from a [`MountInfoEntryDiff`].
+    pub(crate) fn from_entry_diff(entry_diff: &MountInfoEntryDiff) -> MountInfoChange {
+        let source = entry_diff
+            .source()
+            .map(|entry| MountInfoEntry::borrow_ptr(entry.inner));
+        let other = entry_diff
+            .other()
+            .map(|entry| MountInfoEntry::borrow_ptr(entry.inner));
+        let comparisons = entry_diff.comparisons().to_vec();
+
+        Self {
+            source,
+            other,
+            comparisons,
+        }
+    }
+
+    /// Returns the entry used as the reference for the comparison, or `None` if it did not exist
+    /// (e.g. for a new mount).
+    pub fn source(&self) -> Option<&MountInfoEntry> {
+        self.source.as_ref()
+    }
+
+    /// Returns the entry the reference is compared to, or `None` if it does not exist anymore
+    /// (e.g. for an unmounted device).
+    pub fn other(&self) -> Option<&MountInfoEntry> {
+        self.other.as_ref()
+    }
+
+    /// Returns a list of the [`Comparison`]s performed.
+    pub fn comparisons(&self) -> &[Comparison] {
+        &self.comparisons
+    }
+}
+
+impl Clone for MountInfoChange {
+    /// Returns a new `MountInfoChange` sharing the same underlying entries.
+    fn clone(&self) -> Self {
+        Self {
+            source: self
+                .source
+                .as_ref()
+                .map(|entry| MountInfoEntry::borrow_ptr(entry.inner)),
+            other: self
+                .other
+                .as_ref()
+                .map(|entry| MountInfoEntry::borrow_ptr(entry.inner)),
+            comparisons: self.comparisons.clone(),
+        }
+    }
+}
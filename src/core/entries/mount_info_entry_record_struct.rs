@@ -0,0 +1,63 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use serde::Serialize;
+
+// From standard library
+use std::path::Path;
+
+// From this library
+use crate::core::entries::MountInfoEntry;
+use crate::core::flags::MountFlag;
+
+#[doc(hidden)]
+/// Serialized form of a [`MountInfoEntry`].
+#[derive(Debug, Serialize)]
+pub(crate) struct MountInfoEntryRecord<'a> {
+    mount_id: Option<u32>,
+    parent_id: Option<u32>,
+    device_id: Option<String>,
+    root: Option<&'a str>,
+    target: Option<&'a Path>,
+    source: Option<&'a Path>,
+    fs_options: Option<String>,
+    fs_independent_options: Option<&'a str>,
+    fs_specific_options: Option<&'a str>,
+    propagation_flags: Vec<&'static str>,
+    file_system_type: Option<String>,
+}
+
+impl<'a> From<&'a MountInfoEntry> for MountInfoEntryRecord<'a> {
+    fn from(entry: &'a MountInfoEntry) -> MountInfoEntryRecord<'a> {
+        let flags = entry.propagation_flags().unwrap_or_default();
+
+        // Listed in a fixed order to produce a stable output.
+        let propagation_flags = [
+            (MountFlag::Shared, "shared"),
+            (MountFlag::Private, "private"),
+            (MountFlag::Slave, "slave"),
+            (MountFlag::Unbindable, "unbindable"),
+        ]
+        .iter()
+        .filter(|(flag, _)| flags.contains(flag))
+        .map(|&(_, name)| name)
+        .collect();
+
+        Self {
+            mount_id: entry.mount_id(),
+            parent_id: entry.parent_id(),
+            device_id: entry
+                .device_id_major_minor()
+                .map(|(major, minor)| format!("{major}:{minor}")),
+            root: entry.root(),
+            target: entry.target(),
+            source: entry.source_path(),
+            fs_options: entry.fs_options(),
+            fs_independent_options: entry.fs_independent_options(),
+            fs_specific_options: entry.fs_specific_options(),
+            propagation_flags,
+            file_system_type: entry.file_system_type().map(|fs| fs.to_string()),
+        }
+    }
+}
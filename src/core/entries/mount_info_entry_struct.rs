@@ -4,20 +4,33 @@
 // From dependency library
 
 // From standard library
+use std::collections::BTreeSet;
 use std::collections::HashSet;
 use std::fmt;
 use std::fs::File;
 use std::mem::MaybeUninit;
+use std::os::unix::ffi::OsStrExt;
 use std::path::Path;
+use std::str;
 use std::str::FromStr;
 
 // From this library
 use crate::core::cache::Cache;
 use crate::core::device::Source;
+use crate::core::device::Tag;
+use crate::core::device::TagName;
+use crate::core::entries::FsTabEntry;
+use crate::core::entries::FsTbEntBuilder;
+#[cfg(feature = "serde")]
+use crate::core::entries::MountInfoEntryRecord;
 use crate::core::errors::MountInfoEntryError;
 use crate::core::flags::MountFlag;
+use crate::core::flags::PropagationMode;
 use crate::core::fs::FileSystem;
+use crate::core::optstring;
+use crate::core::utils;
 use crate::ffi_utils;
+use crate::tables::MountOption;
 
 /// A line in `/proc/<pid>/mountinfo` (where `<pid>` is the ID of a process).
 ///
@@ -26,7 +39,7 @@
 /// ```text
 /// 26 1 8:3 / / rw,relatime - ext4 /dev/sda3 rw
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 #[repr(transparent)]
 pub struct MountInfoEntry {
     pub(crate) inner: *mut libmount::libmnt_fs,
@@ -220,16 +233,42 @@ pub fn device_id(&self) -> Option<u64> {
 
     /// Returns the ID of the device containing files on a file system as a major:minor pair.
     pub fn device_id_major_minor(&self) -> Option<(u64, u64)> {
-        // From https://github.com/openbsd/src/blob/master/sys/sys/types.h#L211
-        fn major(x: u64) -> u64 {
-            (x >> 8) & 0xff
-        }
+        self.device_id()
+            .map(|id| (utils::major(id) as u64, utils::minor(id) as u64))
+    }
 
-        fn minor(x: u64) -> u64 {
-            (x & 0xff) | ((x & 0xffff0000) >> 8)
-        }
+    /// Returns the `(major, minor)` numbers of the device containing files on a file system
+    /// (e.g. to look up the device in `/sys/dev/block/<major>:<minor>`).
+    ///
+    /// Returns `(0, 0)` if the device number is unknown.
+    pub fn device_numbers(&self) -> (u32, u32) {
+        let dev_num = self.device_id().unwrap_or(0);
+
+        (utils::major(dev_num), utils::minor(dev_num))
+    }
+
+    /// Returns the source directory of a bind mount, or `None` if this entry was not merged with
+    /// a `/run/mount/utab` entry describing a bind mount (see
+    /// [`UTabEntry::merge_into`](crate::core::entries::UTabEntry::merge_into)).
+    pub fn bind_source(&self) -> Option<&str> {
+        log::debug!("MountInfoEntry::bind_source getting bind mount source");
+
+        let mut ptr = MaybeUninit::<*const libc::c_char>::zeroed();
+        ptr.write(unsafe { libmount::mnt_fs_get_bindsrc(self.inner) });
 
-        self.device_id().map(|id| (major(id), minor(id)))
+        match unsafe { ptr.assume_init() } {
+            ptr if ptr.is_null() => {
+                log::debug!("MountInfoEntry::bind_source no bind mount source set. libmount::mnt_fs_get_bindsrc returned a NULL pointer");
+
+                None
+            }
+            ptr => {
+                let bind_source = ffi_utils::const_char_array_to_str_ref(ptr);
+                log::debug!("MountInfoEntry::bind_source value: {:?}", bind_source);
+
+                bind_source.ok()
+            }
+        }
     }
 
     /// Returns the pathname of the directory a process sees as its root directory.
@@ -361,6 +400,53 @@ pub fn fs_options(&self) -> Option<String> {
         }
     }
 
+    /// Returns the list of file system options of this entry (see [`MountInfoEntry::fs_options`]),
+    /// parsed into [`MountOption`]s.
+    ///
+    /// Returns an empty `Vec` if the entry has no options. Quoted values are returned without
+    /// their surrounding double quotes.
+    pub fn options(&self) -> Vec<MountOption> {
+        log::debug!("MountInfoEntry::options parsing file system options");
+
+        self.fs_options()
+            .map(|options| optstring::parse_options(&options))
+            .unwrap_or_default()
+    }
+
+    #[doc(hidden)]
+    /// Returns the set of mount options of this entry, to compare entries regardless of the order
+    /// in which their options are listed.
+    fn option_set(&self) -> BTreeSet<MountOption> {
+        self.options().into_iter().collect()
+    }
+
+    #[doc(hidden)]
+    /// Returns the list of fs-independent options followed by the list of fs-specific options,
+    /// without the `ro`/`rw` flag the kernel repeats in the latter.
+    pub(crate) fn merged_options(&self) -> String {
+        let fs_specific = self
+            .fs_specific_options()
+            .and_then(|options| optstring::scan(options).ok())
+            .map(|scanner| {
+                scanner
+                    .filter(|(name, _)| *name != "ro" && *name != "rw")
+                    .map(|(name, value)| match value {
+                        Some(value) => format!("{}={}", name, value),
+                        None => name.to_owned(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        self.fs_independent_options()
+            .map(str::to_owned)
+            .into_iter()
+            .chain(fs_specific)
+            .filter(|option| !option.is_empty())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
     /// Returns `mountinfo`'s optional fields (zero or more fields of the form *tag\[:value]*, which describe a mount point’s propagation type).
     pub fn optional_fields(&self) -> Option<&str> {
         log::debug!("MountInfoEntry::optional_fields getting mountinfo optional fields");
@@ -430,6 +516,73 @@ pub fn propagation_flags(&self) -> Option<HashSet<MountFlag>> {
         }
     }
 
+    #[doc(hidden)]
+    /// Returns the value of the first optional field tagged `tag`, or `None` if there is no such
+    /// field.
+    fn optional_field_value(&self, tag: &str) -> Option<&str> {
+        self.optional_fields()?
+            .split_whitespace()
+            .find_map(|field| match field.split_once(':') {
+                Some((name, value)) if name == tag => Some(value),
+                _ => None,
+            })
+    }
+
+    /// Returns a mount point's propagation types, decoded from `mountinfo`'s optional fields the
+    /// way `findmnt -o PROPAGATION` presents them:
+    /// - `shared:N` maps to [`PropagationMode::Shared`],
+    /// - `master:N` maps to [`PropagationMode::Slave`],
+    /// - `unbindable` maps to [`PropagationMode::Unbindable`],
+    /// - a mount point without any of these tags is [`PropagationMode::Private`].
+    ///
+    /// A mount point can be both [`PropagationMode::Slave`] and [`PropagationMode::Shared`], i.e.
+    /// it receives events from its master peer group, and propagates them to its own peer group.
+    /// See [`MountInfoEntry::peer_group`] and [`MountInfoEntry::master_peer_group`] for the IDs of
+    /// these peer groups.
+    pub fn propagation(&self) -> HashSet<PropagationMode> {
+        let mut modes: HashSet<_> = self
+            .optional_fields()
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter_map(|field| match field.split(':').next() {
+                Some("shared") => Some(PropagationMode::Shared),
+                Some("master") => Some(PropagationMode::Slave),
+                Some("unbindable") => Some(PropagationMode::Unbindable),
+                _ => None,
+            })
+            .collect();
+
+        if modes.is_empty() {
+            modes.insert(PropagationMode::Private);
+        }
+
+        log::debug!("MountInfoEntry::propagation value: {:?}", modes);
+
+        modes
+    }
+
+    /// Returns the ID of the peer group a shared mount point belongs to (i.e. `N` in the optional
+    /// field `shared:N`), or `None` if the mount point is not shared.
+    pub fn peer_group(&self) -> Option<u32> {
+        let id = self
+            .optional_field_value("shared")
+            .and_then(|value| value.parse().ok());
+        log::debug!("MountInfoEntry::peer_group value: {:?}", id);
+
+        id
+    }
+
+    /// Returns the ID of the peer group a slave mount point receives mount and unmount events from
+    /// (i.e. `N` in the optional field `master:N`), or `None` if the mount point is not a slave.
+    pub fn master_peer_group(&self) -> Option<u32> {
+        let id = self
+            .optional_field_value("master")
+            .and_then(|value| value.parse().ok());
+        log::debug!("MountInfoEntry::master_peer_group value: {:?}", id);
+
+        id
+    }
+
     /// Returns the value of `<pid>` (the process ID) in `/proc/<pid>/mountinfo`.
     pub fn pid(&self) -> usize {
         let id = unsafe { libmount::mnt_fs_get_tid(self.inner) as usize };
@@ -612,6 +765,39 @@ pub(crate) fn set_mount_target<T>(&mut self, path: T) -> Result<(), MountInfoEnt
         }
     }
 
+    #[doc(hidden)]
+    /// Sets the source directory of a bind mount.
+    pub(crate) fn set_bind_source<T>(&mut self, path: T) -> Result<(), MountInfoEntryError>
+    where
+        T: AsRef<str>,
+    {
+        let path = path.as_ref();
+        log::debug!(
+            "MountInfoEntry::set_bind_source setting bind mount source directory as {:?}",
+            path
+        );
+
+        let path_cstr = ffi_utils::as_ref_str_to_c_string(path)?;
+
+        let result = unsafe { libmount::mnt_fs_set_bindsrc(self.inner, path_cstr.as_ptr()) };
+        match result {
+            0 => {
+                log::debug!(
+                    "MountInfoEntry::set_bind_source set bind mount source directory as {:?}",
+                    path
+                );
+
+                Ok(())
+            }
+            code => {
+                let err_msg = format!("failed to set bind mount source directory as {:?}", path);
+                log::debug!( "MountInfoEntry::set_bind_source {err_msg}. libmount::mnt_fs_set_bindsrc returned error code: {code:?}");
+
+                Err(MountInfoEntryError::Config(err_msg))
+            }
+        }
+    }
+
     //---- END setters
 
     //---- BEGIN mutators
@@ -645,6 +831,126 @@ pub fn copy(&self) -> Result<MountInfoEntry, MountInfoEntryError> {
         }
     }
 
+    #[doc(hidden)]
+    /// Appends the `options` parameter to the corresponding VFS, FS-specific, and userspace list
+    /// of options.
+    pub(crate) fn append_options<T>(&mut self, options: T) -> Result<(), MountInfoEntryError>
+    where
+        T: AsRef<str>,
+    {
+        let options = options.as_ref();
+        log::debug!(
+            "MountInfoEntry::append_options appending options {:?}",
+            options
+        );
+
+        let opts = ffi_utils::as_ref_str_to_c_string(options)?;
+
+        let result = unsafe { libmount::mnt_fs_append_options(self.inner, opts.as_ptr()) };
+        match result {
+            0 => {
+                log::debug!(
+                    "MountInfoEntry::append_options appended options {:?}",
+                    options
+                );
+
+                Ok(())
+            }
+            code => {
+                let err_msg = format!("failed to append options {:?}", options);
+                log::debug!( "MountInfoEntry::append_options {err_msg}. libmount::mnt_fs_append_options returned error code: {code:?}");
+
+                Err(MountInfoEntryError::Config(err_msg))
+            }
+        }
+    }
+
+    /// Converts this `MountInfoEntry` into a [`FsTabEntry`], describing the same mount in
+    /// `/etc/fstab`'s format.
+    ///
+    /// The new entry copies this entry's source, target, file system type, and its fs-independent
+    /// and fs-specific mount options. Its backup frequency and `fsck` checking order are both set
+    /// to `0`.
+    ///
+    /// When a [`Cache`] is provided, the device is identified by its `UUID`, or by its `LABEL` if
+    /// it has no `UUID`, instead of its path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsmount::tables::MountInfo;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let mut mount_info = MountInfo::new()?;
+    ///     mount_info.import_mountinfo()?;
+    ///
+    ///     if let Some(entry) = mount_info.find_target("/") {
+    ///         let fstab_entry = entry.to_fstab_entry(None)?;
+    ///         println!("{}", fstab_entry);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_fstab_entry(&self, cache: Option<&Cache>) -> Result<FsTabEntry, MountInfoEntryError> {
+        log::debug!("MountInfoEntry::to_fstab_entry converting `MountInfoEntry` to `FsTabEntry`");
+
+        let source_path = self.source_path().ok_or_else(|| {
+            let err_msg =
+                "failed to convert `MountInfoEntry` to `FsTabEntry`. Missing source".to_owned();
+            log::debug!("MountInfoEntry::to_fstab_entry {}", err_msg);
+
+            MountInfoEntryError::Action(err_msg)
+        })?;
+
+        let tag = cache.and_then(|cache| {
+            [TagName::Uuid, TagName::Label]
+                .into_iter()
+                .find_map(|tag_name| {
+                    cache
+                        .find_tag_value(source_path, tag_name)
+                        .and_then(|value| Tag::try_from(format!("{}={}", tag_name, value)).ok())
+                })
+        });
+
+        let source = match tag {
+            Some(tag) => Source::from(tag),
+            None => {
+                let source = str::from_utf8(source_path.as_os_str().as_bytes()).map_err(|e| {
+                    let err_msg = format!(
+                        "failed to convert `MountInfoEntry` to `FsTabEntry`. Invalid source {:?}. {}",
+                        source_path, e
+                    );
+                    log::debug!("MountInfoEntry::to_fstab_entry {}", err_msg);
+
+                    MountInfoEntryError::Action(err_msg)
+                })?;
+
+                Source::from_str(source)?
+            }
+        };
+
+        let options = self.merged_options();
+
+        let builder = FsTbEntBuilder {
+            comment_line: None,
+            source,
+            target: self
+                .target()
+                .map(|path| path.to_path_buf())
+                .unwrap_or_default(),
+            file_system_type: self.file_system_type(),
+            mount_options: (!options.is_empty()).then_some(options),
+            backup_frequency: Some(0),
+            fsck_checking_order: Some(0),
+        };
+
+        let entry = builder.into_entry()?;
+        log::debug!("MountInfoEntry::to_fstab_entry converted `MountInfoEntry` to `FsTabEntry`");
+
+        Ok(entry)
+    }
+
     //---- END mutators
 
     //---- BEGIN predicates
@@ -849,6 +1155,25 @@ pub fn is_exact_target<T>(&self, path: T) -> bool
     //---- END predicates
 }
 
+impl PartialEq for MountInfoEntry {
+    /// Returns `true` if both entries have the same mount ID, parent ID, source, root, target,
+    /// file system type, and file system options.
+    ///
+    /// Options are compared as a set, i.e. regardless of the order in which they are listed
+    /// (`rw,relatime` is equal to `relatime,rw`).
+    fn eq(&self, other: &Self) -> bool {
+        self.mount_id() == other.mount_id()
+            && self.parent_id() == other.parent_id()
+            && self.source_path() == other.source_path()
+            && self.root() == other.root()
+            && self.target() == other.target()
+            && self.file_system_type() == other.file_system_type()
+            && self.option_set() == other.option_set()
+    }
+}
+
+impl Eq for MountInfoEntry {}
+
 impl fmt::Display for MountInfoEntry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut output: Vec<String> = vec![];
@@ -900,3 +1225,111 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", output.join(" "))
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MountInfoEntry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(&MountInfoEntryRecord::from(self), serializer)
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use crate::tables::fixtures;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn mount_info_entry_equality_takes_mount_ids_into_account() -> crate::Result<()> {
+        let mut mount_info = fixtures::mount_info_from(
+            "21 1 8:3 / / rw,relatime - ext4 /dev/sda3 rw
+22 1 8:3 / /mnt relatime,rw - ext4 /dev/sda3 rw
+23 1 8:3 / /mnt relatime,rw - ext4 /dev/sda3 rw
+",
+        )?;
+        let mut other =
+            fixtures::mount_info_from("21 1 8:3 / / relatime,rw - ext4 /dev/sda3 rw\n")?;
+
+        // Options are compared regardless of their order.
+        let entry = mount_info.find_target("/").unwrap();
+        let actual = entry == other.find_target("/").unwrap();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        // Same mount, different mount IDs.
+        let actual = mount_info[1] == mount_info[2];
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_entry_can_be_converted_to_a_fstab_entry() -> crate::Result<()> {
+        let mut mount_info = fixtures::mount_info()?;
+
+        let entry = mount_info.find_target("/").unwrap();
+        let fstab_entry = entry.to_fstab_entry(None)?;
+
+        let actual = fstab_entry.to_string();
+        let expected = "/dev/sda3 / ext4 rw,relatime 0 0";
+        assert_eq!(actual, expected);
+
+        let entry = mount_info.find_target("/dev/pts").unwrap();
+        let fstab_entry = entry.to_fstab_entry(None)?;
+
+        let actual = fstab_entry.to_string();
+        let expected = "devpts /dev/pts devpts rw,nosuid,noexec,relatime,gid=5,mode=620 0 0";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_entry_can_be_converted_to_a_fstab_entry_with_a_tag() -> crate::Result<()> {
+        // Swap area image, with its signature, UUID, and label in a version 1 header.
+        let tmp_dir = tempfile::tempdir().unwrap();
+        let image = tmp_dir.path().join("swap.img");
+        let mut content = vec![0u8; 10 * 4096];
+        content[1024..1028].copy_from_slice(&1u32.to_le_bytes());
+        content[1028..1032].copy_from_slice(&9u32.to_le_bytes());
+        content[1036..1052].copy_from_slice(&[
+            0x5e, 0x0a, 0x0a, 0x5c, 0x1d, 0x5e, 0x4c, 0x32, 0x9a, 0x9f, 0x3d, 0x1f, 0x6f, 0x9f,
+            0x8a, 0x11,
+        ]);
+        content[1052..1059].copy_from_slice(b"rsmount");
+        content[4086..4096].copy_from_slice(b"SWAPSPACE2");
+        std::fs::write(&image, content).unwrap();
+
+        let line = format!(
+            "30 21 7:0 / /mnt/image rw,relatime - ext4 {} rw\n",
+            image.display()
+        );
+        let mut mount_info = fixtures::mount_info_from(&line)?;
+        let entry = mount_info.find_target("/mnt/image").unwrap();
+
+        let cache = Cache::new()?;
+        let fstab_entry = entry.to_fstab_entry(Some(&cache))?;
+
+        let actual = fstab_entry.tag();
+        let expected = Some(Tag::from_str("UUID=5e0a0a5c-1d5e-4c32-9a9f-3d1f6f9f8a11")?);
+        assert_eq!(actual, expected);
+
+        let actual = fstab_entry.to_string();
+        let expected = "UUID=5e0a0a5c-1d5e-4c32-9a9f-3d1f6f9f8a11 /mnt/image ext4 rw,relatime 0 0";
+        assert_eq!(actual, expected);
+
+        // Without a cache, the device is identified by its path.
+        let fstab_entry = entry.to_fstab_entry(None)?;
+
+        let actual = fstab_entry.source_path();
+        let expected = Some(image.as_path());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
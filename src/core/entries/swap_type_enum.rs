@@ -0,0 +1,135 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+use std::str::FromStr;
+
+// From this library
+use crate::core::errors::ParserError;
+
+/// Type of swap area, as listed in the `Type` column of `/proc/swaps`.
+///
+/// Parsing a string into a `SwapType` ignores surrounding whitespace. Unlisted swap types are
+/// parsed into [`SwapType::Other`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum SwapType {
+    /// A swap area on a file.
+    File,
+
+    /// A swap area on a block device.
+    Partition,
+
+    /// A swap type not listed above, holding its name.
+    Other(String),
+}
+
+impl SwapType {
+    /// View this `SwapType` as a UTF-8 `str`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::File => "file",
+            Self::Partition => "partition",
+            Self::Other(name) => name.as_str(),
+        }
+    }
+}
+
+impl AsRef<SwapType> for SwapType {
+    #[inline]
+    fn as_ref(&self) -> &SwapType {
+        self
+    }
+}
+
+impl fmt::Display for SwapType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl TryFrom<&str> for SwapType {
+    type Error = ParserError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.trim() {
+            "file" => Ok(Self::File),
+            "partition" => Ok(Self::Partition),
+            "" => {
+                let err_msg = format!("unsupported swap type: {s:?}");
+                Err(ParserError::SwapType(err_msg))
+            }
+            swap_type => Ok(Self::Other(swap_type.to_owned())),
+        }
+    }
+}
+
+impl TryFrom<String> for SwapType {
+    type Error = ParserError;
+
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl TryFrom<&String> for SwapType {
+    type Error = ParserError;
+
+    #[inline]
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
+impl FromStr for SwapType {
+    type Err = ParserError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    #[should_panic(expected = "unsupported swap type")]
+    fn swap_type_can_not_parse_an_empty_string() {
+        let _: SwapType = "".parse().unwrap();
+    }
+
+    #[test]
+    fn swap_type_can_parse_an_unlisted_swap_type() -> crate::Result<()> {
+        let actual: SwapType = " zram ".parse()?;
+        let expected = SwapType::Other("zram".to_owned());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swap_type_can_parse_string_file() -> crate::Result<()> {
+        let actual: SwapType = "file".parse()?;
+        let expected = SwapType::File;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swap_type_can_parse_string_partition() -> crate::Result<()> {
+        let actual: SwapType = "partition".parse()?;
+        let expected = SwapType::Partition;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
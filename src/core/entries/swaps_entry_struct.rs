@@ -8,10 +8,12 @@
 use std::fs::File;
 use std::mem::MaybeUninit;
 use std::path::Path;
+use std::str::FromStr;
 
 // From this library
 use crate::core::cache::Cache;
 use crate::core::device::Source;
+use crate::core::entries::SwapType;
 use crate::core::errors::SwapsEntryError;
 use crate::ffi_utils;
 
@@ -22,7 +24,7 @@
 /// ```text
 /// /dev/sda2                               partition       1048572         0               -2
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 #[repr(transparent)]
 pub struct SwapsEntry {
     pub(crate) inner: *mut libmount::libmnt_fs,
@@ -157,8 +159,9 @@ pub fn source_path(&self) -> Option<&Path> {
         }
     }
 
-    /// Returns the type of swap partition.
-    pub fn swap_type(&self) -> Option<&str> {
+    /// Returns the type of swap area (i.e. the second column in `/proc/swaps`), or `None` if it
+    /// is missing.
+    pub fn swap_type(&self) -> Option<SwapType> {
         log::debug!("SwapsEntry::swap_type getting swap type");
 
         let mut ptr = MaybeUninit::<*const libc::c_char>::zeroed();
@@ -175,10 +178,12 @@ pub fn swap_type(&self) -> Option<&str> {
             }
 
             ptr => {
-                let swap_type = ffi_utils::const_char_array_to_str_ref(ptr);
+                let swap_type = ffi_utils::const_char_array_to_str_ref(ptr)
+                    .ok()
+                    .and_then(|swap_type| SwapType::from_str(swap_type).ok());
                 log::debug!("SwapsEntry::swap_type value: {:?}", swap_type);
 
-                swap_type.ok()
+                swap_type
             }
         }
     }
@@ -199,6 +204,21 @@ pub fn size_used(&self) -> usize {
         size
     }
 
+    /// Returns the fraction of the swap space used, between `0.0` and `1.0`.
+    ///
+    /// Returns `0.0` if the swap space has a size of `0`.
+    pub fn usage_ratio(&self) -> f64 {
+        let size = self.size();
+        let ratio = if size == 0 {
+            0.0
+        } else {
+            self.size_used() as f64 / size as f64
+        };
+        log::debug!("SwapsEntry::usage_ratio value: {:?}", ratio);
+
+        ratio
+    }
+
     /// Returns the priority number of the swap partition.
     pub fn priority(&self) -> i32 {
         let priority = unsafe { libmount::mnt_fs_get_priority(self.inner) };
@@ -433,6 +453,22 @@ pub fn is_swap(&self) -> bool {
         state
     }
 
+    /// Returns `true` if this `SwapsEntry` is a swap file.
+    pub fn is_file(&self) -> bool {
+        let state = self.swap_type() == Some(SwapType::File);
+        log::debug!("SwapsEntry::is_file value: {:?}", state);
+
+        state
+    }
+
+    /// Returns `true` if this `SwapsEntry` is a swap partition.
+    pub fn is_partition(&self) -> bool {
+        let state = self.swap_type() == Some(SwapType::Partition);
+        log::debug!("SwapsEntry::is_partition value: {:?}", state);
+
+        state
+    }
+
     /// Returns `true` if the `source` parameter matches the `source` field in this `SwapsEntry`.
     ///
     /// Using the provided `cache`, this method will perform the following comparisons in sequence:
@@ -495,6 +531,17 @@ pub fn is_exact_source(&self, source: &Source) -> bool {
     //---- END predicates
 }
 
+impl PartialEq for SwapsEntry {
+    /// Returns `true` if both entries have the same source, swap type, and priority.
+    fn eq(&self, other: &Self) -> bool {
+        self.source_path() == other.source_path()
+            && self.swap_type() == other.swap_type()
+            && self.priority() == other.priority()
+    }
+}
+
+impl Eq for SwapsEntry {}
+
 impl fmt::Display for SwapsEntry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Formatting from Linux Kernel
@@ -4,6 +4,7 @@
 // From dependency library
 
 // From standard library
+use std::collections::BTreeSet;
 use std::fmt;
 use std::fs::File;
 use std::mem::MaybeUninit;
@@ -12,11 +13,14 @@
 // From this library
 use crate::core::cache::Cache;
 use crate::core::device::Source;
+use crate::core::entries::MountInfoEntry;
 use crate::core::entries::UTabEntryBuilder;
 use crate::core::entries::UTbEntBuilder;
 use crate::core::errors::UTabEntryError;
+use crate::core::optstring;
 use crate::core::utils;
 use crate::ffi_utils;
+use crate::tables::MountOption;
 
 /// A line in `/run/mount/utab`.
 ///
@@ -24,7 +28,7 @@
 /// ```text
 /// SRC=/dev/vda TARGET=/mnt ROOT=/ OPTS=x-initrd.mount
 /// ```
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 #[repr(transparent)]
 pub struct UTabEntry {
     pub(crate) inner: *mut libmount::libmnt_fs,
@@ -399,6 +403,25 @@ pub fn mount_options(&self) -> Option<&str> {
         }
     }
 
+    /// Returns the list of mount options of this entry, parsed into [`MountOption`]s.
+    ///
+    /// Returns an empty `Vec` if the entry has no mount options. Quoted values are returned without
+    /// their surrounding double quotes.
+    pub fn options(&self) -> Vec<MountOption> {
+        log::debug!("UTabEntry::options parsing mount options");
+
+        self.mount_options()
+            .map(optstring::parse_options)
+            .unwrap_or_default()
+    }
+
+    #[doc(hidden)]
+    /// Returns the set of mount options of this entry, to compare entries regardless of the order
+    /// in which their options are listed.
+    fn option_set(&self) -> BTreeSet<MountOption> {
+        self.options().into_iter().collect()
+    }
+
     /// Returns the entry's source path which can be
     /// - a directory for bind mounts (in `/etc/fstab` or `/etc/mtab` only)
     /// - a path to a block device for standard mounts.
@@ -752,6 +775,21 @@ pub fn set_mount_options<T>(&mut self, options: T) -> Result<(), UTabEntryError>
         }
     }
 
+    /// Sets the mount options of this entry from a list of [`MountOption`]s.
+    pub fn set_options_from(&mut self, options: &[MountOption]) -> Result<(), UTabEntryError> {
+        let options = options
+            .iter()
+            .map(|option| option.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        log::debug!(
+            "UTabEntry::set_options_from setting mount options to: {:?}",
+            options
+        );
+
+        self.set_mount_options(options)
+    }
+
     /// Sets the source path of the device to mount.
     pub fn set_source_path<T>(&mut self, source: T) -> Result<(), UTabEntryError>
     where
@@ -931,6 +969,55 @@ pub fn append_options<T>(&mut self, options: T) -> Result<(), UTabEntryError>
         }
     }
 
+    /// Returns a copy of the `mountinfo` `entry` describing the same mount point as this
+    /// `UTabEntry`, with this entry's userspace mount options appended to the kernel's list of
+    /// options.
+    ///
+    /// This reconstructs the full set of options of a mount point the way `findmnt` does. If this
+    /// `UTabEntry` describes a bind mount, its source directory is also copied to the new entry
+    /// (see [`MountInfoEntry::bind_source`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this entry and `entry` do not have the same mount point.
+    pub fn merge_into(&self, entry: &MountInfoEntry) -> Result<MountInfoEntry, UTabEntryError> {
+        log::debug!("UTabEntry::merge_into merging userspace data into `MountInfoEntry`");
+
+        if self.target().is_none() || self.target() != entry.target() {
+            let err_msg = format!(
+                "failed to merge entry with target {:?} into entry with target {:?}",
+                self.target(),
+                entry.target()
+            );
+            log::debug!("UTabEntry::merge_into {}", err_msg);
+
+            return Err(UTabEntryError::Action(err_msg));
+        }
+
+        let to_action_error = |err: MountInfoEntryError| {
+            let err_msg = format!("failed to merge `UTabEntry` into `MountInfoEntry`. {}", err);
+            log::debug!("UTabEntry::merge_into {}", err_msg);
+
+            UTabEntryError::Action(err_msg)
+        };
+
+        let mut merged = entry.copy().map_err(to_action_error)?;
+
+        if let Some(options) = self.mount_options() {
+            merged.append_options(options).map_err(to_action_error)?;
+        }
+
+        if let Some(bind_source) = self.bind_source() {
+            merged
+                .set_bind_source(bind_source)
+                .map_err(to_action_error)?;
+        }
+
+        log::debug!("UTabEntry::merge_into merged userspace data into `MountInfoEntry`");
+
+        Ok(merged)
+    }
+
     //---- END mutators
 
     //---- BEGIN predicates
@@ -1151,6 +1238,21 @@ pub fn is_exact_target<T>(&self, path: T) -> bool
     //---- END predicates
 }
 
+impl PartialEq for UTabEntry {
+    /// Returns `true` if both entries have the same source, root, target, and mount options.
+    ///
+    /// Mount options are compared as a set, i.e. regardless of the order in which they are
+    /// listed (`rw,relatime` is equal to `relatime,rw`).
+    fn eq(&self, other: &Self) -> bool {
+        self.source_path() == other.source_path()
+            && self.root() == other.root()
+            && self.target() == other.target()
+            && self.option_set() == other.option_set()
+    }
+}
+
+impl Eq for UTabEntry {}
+
 impl fmt::Display for UTabEntry {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Formatting `fprintf_utab_fs`
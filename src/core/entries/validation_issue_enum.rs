@@ -0,0 +1,90 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+use std::path::PathBuf;
+
+// From this library
+
+/// A semantic mistake found by
+/// [`FsTabEntry::validate`](crate::core::entries::FsTabEntry::validate), or
+/// [`FsTab::validate_all`](crate::tables::FsTab::validate_all).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ValidationIssue {
+    /// The entry has no mount point.
+    MissingTarget,
+
+    /// The mount point of the entry is not an absolute path.
+    RelativeTarget(PathBuf),
+
+    /// A pseudo, or network file system, not mounted at `/`, has a non-zero `fsck` checking
+    /// order.
+    NonZeroFsckOrder { target: PathBuf, order: usize },
+
+    /// A swap entry has a mount point other than `none`.
+    SwapTargetNotNone(PathBuf),
+
+    /// The entry's mount options contain two mutually exclusive flags (e.g. `ro`, and `rw`).
+    ContradictoryOptions(String, String),
+
+    /// The entry has the same mount point as the entry at index `first` in the table.
+    DuplicateTarget { target: PathBuf, first: usize },
+
+    /// The entry is listed before the entry at index `parent`, whose mount point is an ancestor
+    /// of its own; once mounted, the parent hides it.
+    MountedBeforeParent { target: PathBuf, parent: usize },
+
+    /// The entry is mounted at `/`, like the entry at index `first` in the table.
+    MultipleRoots { first: usize },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingTarget => write!(f, "missing mount point"),
+            Self::RelativeTarget(target) => {
+                write!(f, "mount point {:?} is not an absolute path", target)
+            }
+            Self::NonZeroFsckOrder { target, order } => write!(
+                f,
+                "pseudo, or network file system at {:?} has a non-zero fsck checking order: {}",
+                target, order
+            ),
+            Self::SwapTargetNotNone(target) => {
+                write!(
+                    f,
+                    "swap entry has mount point {:?} instead of \"none\"",
+                    target
+                )
+            }
+            Self::ContradictoryOptions(first, second) => {
+                write!(
+                    f,
+                    "contradictory mount options {:?}, and {:?}",
+                    first, second
+                )
+            }
+            Self::DuplicateTarget { target, first } => write!(
+                f,
+                "mount point {:?} is already used by entry at index {}",
+                target, first
+            ),
+            Self::MountedBeforeParent { target, parent } => write!(
+                f,
+                "mount point {:?} is listed before its parent at index {}",
+                target, parent
+            ),
+            Self::MultipleRoots { first } => {
+                write!(
+                    f,
+                    "root file system already mounted by entry at index {}",
+                    first
+                )
+            }
+        }
+    }
+}
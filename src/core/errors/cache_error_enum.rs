@@ -10,7 +10,7 @@
 // From this library
 
 /// [`Cache`](crate::core::cache::Cache) runtime error.
-#[derive(Debug, Error)]
+#[derive(Clone, Debug, Error)]
 #[non_exhaustive]
 pub enum CacheError {
     /// Error while creating a new [`Cache`](crate::core::cache::Cache) instance.
@@ -28,4 +28,12 @@ pub enum CacheError {
     /// Error while importing data from device.
     #[error("{0}")]
     Import(String),
+
+    /// Error while resolving a [`Source`](crate::core::device::Source) to a block device.
+    #[error("{0}")]
+    Resolve(String),
+
+    /// Error if no device matches a [`Tag`](crate::core::device::Tag).
+    #[error("{0}")]
+    UnresolvedTag(String),
 }
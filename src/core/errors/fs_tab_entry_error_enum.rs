@@ -36,6 +36,10 @@ pub enum FsTabEntryError {
     #[error(transparent)]
     IoError(#[from] std::io::Error),
 
+    /// Error while parsing a line in an `fstab` file.
+    #[error("{0}")]
+    Parse(String),
+
     /// Error when accessing a file without having the proper permissions.
     #[error("{0}")]
     Permission(String),
@@ -8,6 +8,8 @@
 use std::ffi::NulError;
 
 // From this library
+use crate::core::errors::FsTabEntryBuilderError;
+use crate::core::errors::ParserError;
 
 /// [`FsTabEntry`](crate::core::entries::FsTabEntry) runtime errors.
 #[derive(Debug, Error)]
@@ -29,9 +31,16 @@ pub enum MountInfoEntryError {
     #[error("failed to convert value to `CString`: {0}")]
     CStringConversion(#[from] NulError),
 
+    #[error(transparent)]
+    FsTabEntryBuilder(#[from] FsTabEntryBuilderError),
+
     #[error(transparent)]
     IoError(#[from] std::io::Error),
 
+    /// Error while parsing a field of a [`MountInfoEntry`](crate::core::entries::MountInfoEntry).
+    #[error(transparent)]
+    Parser(#[from] ParserError),
+
     /// Error when accessing a file without having the proper permissions.
     #[error("{0}")]
     Permission(String),
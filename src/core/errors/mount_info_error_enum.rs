@@ -12,6 +12,10 @@
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum MountInfoError {
+    /// Error while performing an action on a [`MountInfo`](crate::tables::MountInfo) instance.
+    #[error("{0}")]
+    Action(String),
+
     /// Error while creating a new [`MountInfo`](crate::tables::MountInfo) instance.
     #[error("{0}")]
     Creation(String),
@@ -24,7 +28,19 @@ pub enum MountInfoError {
     #[error("{0}")]
     Deduplicate(String),
 
+    /// Error returned by a `libmount` function.
+    #[error("libmount::{function} returned error code: {code}")]
+    Ffi { function: &'static str, code: i32 },
+
     /// Error while importing new entries into a [`MountInfo`](crate::tables::MountInfo).
     #[error("{0}")]
     Import(String),
+
+    /// Error while indexing entries in [`MountInfo`](crate::tables::MountInfo).
+    #[error("{0}")]
+    IndexOutOfBounds(String),
+
+    /// Error while parsing a line of a file system description file.
+    #[error("line {line}: {reason}")]
+    Parse { line: usize, reason: String },
 }
@@ -52,6 +52,10 @@ pub enum ParserError {
     #[error("{0}")]
     SshFs(String),
 
+    /// Error while parsing a string into a [`SwapType`](crate::core::entries::SwapType).
+    #[error("{0}")]
+    SwapType(String),
+
     /// Error while parsing a string into a [`Tag`](crate::core::device::Tag).
     #[error("{0}")]
     Tag(String),
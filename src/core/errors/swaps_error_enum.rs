@@ -12,6 +12,10 @@
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum SwapsError {
+    /// Error while performing an action on a [`Swaps`](crate::tables::Swaps) instance.
+    #[error("{0}")]
+    Action(String),
+
     /// Error while creating a new [`Swaps`](crate::tables::Swaps) instance.
     #[error("{0}")]
     Creation(String),
@@ -24,7 +28,19 @@ pub enum SwapsError {
     #[error("{0}")]
     Deduplicate(String),
 
+    /// Error returned by a `libmount` function.
+    #[error("libmount::{function} returned error code: {code}")]
+    Ffi { function: &'static str, code: i32 },
+
     /// Error while importing new entries into a [`Swaps`](crate::tables::Swaps).
     #[error("{0}")]
     Import(String),
+
+    /// Error while indexing entries in [`Swaps`](crate::tables::Swaps).
+    #[error("{0}")]
+    IndexOutOfBounds(String),
+
+    /// Error while parsing a line of a file system description file.
+    #[error("line {line}: {reason}")]
+    Parse { line: usize, reason: String },
 }
@@ -38,6 +38,10 @@ pub enum UTabError {
     #[error("{0}")]
     Export(String),
 
+    /// Error returned by a `libmount` function.
+    #[error("libmount::{function} returned error code: {code}")]
+    Ffi { function: &'static str, code: i32 },
+
     /// Error while importing new entries into a [`UTab`](crate::tables::UTab).
     #[error("{0}")]
     Import(String),
@@ -49,6 +53,10 @@ pub enum UTabError {
     #[error(transparent)]
     IoError(#[from] std::io::Error),
 
+    /// Error while parsing a line of a file system description file.
+    #[error("line {line}: {reason}")]
+    Parse { line: usize, reason: String },
+
     /// Error if a file lacks the required access permissions.
     #[error("{0}")]
     Permission(String),
@@ -9,7 +9,9 @@
 
 // From this library
 pub use mount_flag_enum::MountFlag;
+pub use propagation_mode_enum::PropagationMode;
 pub use userspace_mount_flag_enum::UserspaceMountFlag;
 
 mod mount_flag_enum;
+mod propagation_mode_enum;
 mod userspace_mount_flag_enum;
@@ -0,0 +1,58 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+use enum_iterator::Sequence;
+
+// From standard library
+use std::fmt;
+
+// From this library
+use crate::core::flags::MountFlag;
+
+/// Propagation type of a mount point.
+///
+/// Determines whether mount and unmount events occurring on a mount point propagate to, or are
+/// received from, other mount points (see the [`mount_namespaces`
+/// manpage](https://www.man7.org/linux/man-pages/man7/mount_namespaces.7.html#SHARED_SUBTREES)).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Sequence)]
+#[non_exhaustive]
+pub enum PropagationMode {
+    /// Mount and unmount events propagate to, and are received from, the members of the mount
+    /// point's peer group.
+    Shared,
+
+    /// Mount and unmount events do not propagate into or out of the mount point (kernel default).
+    Private,
+
+    /// Mount and unmount events propagate into the mount point from its master peer group, but
+    /// not out of it.
+    Slave,
+
+    /// A `private` mount point that can not be bind mounted.
+    Unbindable,
+}
+
+impl From<PropagationMode> for MountFlag {
+    fn from(mode: PropagationMode) -> MountFlag {
+        match mode {
+            PropagationMode::Shared => MountFlag::Shared,
+            PropagationMode::Private => MountFlag::Private,
+            PropagationMode::Slave => MountFlag::Slave,
+            PropagationMode::Unbindable => MountFlag::Unbindable,
+        }
+    }
+}
+
+impl fmt::Display for PropagationMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode = match self {
+            Self::Shared => "shared",
+            Self::Private => "private",
+            Self::Slave => "slave",
+            Self::Unbindable => "unbindable",
+        };
+
+        write!(f, "{}", mode)
+    }
+}
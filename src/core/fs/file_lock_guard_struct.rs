@@ -0,0 +1,49 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::ops::Deref;
+
+// From this library
+use crate::core::fs::FileLock;
+
+/// Guard releasing a [`FileLock`] when it goes out of scope.
+///
+/// Returned by [`FileLock::lock`].
+#[derive(Debug)]
+pub struct FileLockGuard<'lock> {
+    lock: &'lock mut FileLock,
+}
+
+impl<'lock> FileLockGuard<'lock> {
+    #[doc(hidden)]
+    /// Creates a new `FileLockGuard`.
+    pub(crate) fn new(lock: &'lock mut FileLock) -> FileLockGuard<'lock> {
+        Self { lock }
+    }
+
+    /// Releases the lock on the associated file before this guard goes out of scope.
+    pub fn unlock(self) {
+        log::debug!("FileLockGuard::unlock releasing file lock");
+
+        drop(self)
+    }
+}
+
+impl<'lock> Deref for FileLockGuard<'lock> {
+    type Target = FileLock;
+
+    fn deref(&self) -> &Self::Target {
+        self.lock
+    }
+}
+
+impl<'lock> Drop for FileLockGuard<'lock> {
+    fn drop(&mut self) {
+        log::debug!("FileLockGuard::drop releasing file lock");
+
+        self.lock.unlock();
+    }
+}
@@ -9,6 +9,7 @@
 
 // From this library
 use crate::core::errors::FileLockError;
+use crate::core::fs::FileLockGuard;
 use crate::ffi_utils;
 
 /// File lock.
@@ -80,8 +81,9 @@ pub fn new<T>(file: T) -> Result<FileLock, FileLockError>
         }
     }
 
-    /// Locks the associated file.
-    pub fn lock(&mut self) -> Result<(), FileLockError> {
+    /// Locks the associated file, and returns a [`FileLockGuard`] releasing the lock when it goes
+    /// out of scope.
+    pub fn lock(&mut self) -> Result<FileLockGuard, FileLockError> {
         log::debug!("FileLock::lock locking file");
 
         let result = unsafe { libmount::mnt_lock_file(self.ptr) };
@@ -90,7 +92,7 @@ pub fn lock(&mut self) -> Result<(), FileLockError> {
             0 => {
                 log::debug!("FileLock::lock locked file");
 
-                Ok(())
+                Ok(FileLockGuard::new(self))
             }
             code => {
                 let err_msg = "failed to lock file".to_owned();
@@ -221,3 +223,98 @@ fn drop(&mut self) {
         unsafe { libmount::mnt_free_lock(self.ptr) }
     }
 }
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use tempfile::Builder;
+
+    use std::fs::OpenOptions;
+    use std::io::Write;
+    use std::path::PathBuf;
+    use std::thread;
+    use std::time::Duration;
+
+    fn write_in_critical_section(path: PathBuf, name: &str) -> Result<(), FileLockError> {
+        let mut lock = FileLock::new(&path)?;
+        lock.block_signals()?;
+        let guard = lock.lock()?;
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .unwrap();
+
+        writeln!(file, "{name} start").unwrap();
+        thread::sleep(Duration::from_millis(100));
+        writeln!(file, "{name} end").unwrap();
+
+        guard.unlock();
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_lock_can_serialize_concurrent_writes() -> crate::Result<()> {
+        let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+        let path = tmp_dir.path().join("mtab");
+
+        let handles: Vec<_> = ["first", "second"]
+            .into_iter()
+            .map(|name| {
+                let path = path.clone();
+                thread::spawn(move || write_in_critical_section(path, name))
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap()?;
+        }
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<_> = content.lines().collect();
+
+        let actual = lines.len();
+        let expected = 4;
+        assert_eq!(actual, expected);
+
+        // Each thread's writes must not be interleaved with the other's.
+        for pair in lines.chunks(2) {
+            let name = pair[0].trim_end_matches(" start");
+            let actual = pair[1];
+            let expected = format!("{name} end");
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_lock_releases_the_lock_when_its_guard_goes_out_of_scope() -> crate::Result<()> {
+        let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+        let path = tmp_dir.path().join("mtab");
+
+        let mut first = FileLock::new(&path)?;
+        {
+            let _guard = first.lock()?;
+        }
+
+        // Would block forever if the first lock was still held.
+        let mut second = FileLock::new(&path)?;
+        let guard = second.lock()?;
+        drop(guard);
+
+        // Explicitly unlocking a file is still possible.
+        std::mem::forget(first.lock()?);
+        first.unlock();
+
+        let mut third = FileLock::new(&path)?;
+        let guard = third.lock()?;
+        drop(guard);
+
+        Ok(())
+    }
+}
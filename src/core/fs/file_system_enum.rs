@@ -11,9 +11,161 @@
 
 // From this library
 use crate::core::errors::ParserError;
+use crate::core::utils;
+
+#[doc(hidden)]
+/// Number of known file systems.
+const KNOWN_FILE_SYSTEMS_COUNT: usize = 139;
+
+#[doc(hidden)]
+/// Known file systems, in the same order as the variants of [`FileSystem`].
+static KNOWN_FILE_SYSTEMS: [FileSystem; KNOWN_FILE_SYSTEMS_COUNT] = [
+    FileSystem::AdaptecRaid,
+    FileSystem::Adfs,
+    FileSystem::Afs,
+    FileSystem::Affs,
+    FileSystem::APFS,
+    FileSystem::Aio,
+    FileSystem::Autofs,
+    FileSystem::Bcache,
+    FileSystem::BcacheFs,
+    FileSystem::Bdev,
+    FileSystem::BeFS,
+    FileSystem::Bfs,
+    FileSystem::Binder,
+    FileSystem::BinfmtMisc,
+    FileSystem::BitLocker,
+    FileSystem::BlueStore,
+    FileSystem::Bpf,
+    FileSystem::BTRFS,
+    FileSystem::Ceph,
+    FileSystem::Cifs,
+    FileSystem::Cgroup,
+    FileSystem::Cgroup2,
+    FileSystem::Configfs,
+    FileSystem::Cpuset,
+    FileSystem::Cramfs,
+    FileSystem::Debugfs,
+    FileSystem::Devpts,
+    FileSystem::Devtmpfs,
+    FileSystem::DDFRaid,
+    FileSystem::DmIntegrity,
+    FileSystem::DmSnapshot,
+    FileSystem::DmVerify,
+    FileSystem::DRBD,
+    FileSystem::DRBDManage,
+    FileSystem::DRBDProxyDatalog,
+    FileSystem::Ecryptfs,
+    FileSystem::Efivarfs,
+    FileSystem::Efs,
+    FileSystem::EROFS,
+    FileSystem::Eventpollfs,
+    FileSystem::ExFAT,
+    FileSystem::Exfs,
+    FileSystem::Ext2,
+    FileSystem::Ext3,
+    FileSystem::Ext4,
+    FileSystem::Ext4Dev,
+    FileSystem::F2FS,
+    FileSystem::FileVault,
+    FileSystem::Fuse,
+    FileSystem::FusePortal,
+    FileSystem::Fuseblk,
+    FileSystem::Fusectl,
+    FileSystem::Futexfs,
+    FileSystem::GFS,
+    FileSystem::GFS2,
+    FileSystem::HFS,
+    FileSystem::HFSPlus,
+    FileSystem::HighPoint37x,
+    FileSystem::HighPoint45x,
+    FileSystem::Hostfs,
+    FileSystem::HPFS,
+    FileSystem::HugeTlbFs,
+    FileSystem::Iso9660,
+    FileSystem::ISWRaid,
+    FileSystem::JBD,
+    FileSystem::Jffs2,
+    FileSystem::JFS,
+    FileSystem::JmicronRaid,
+    FileSystem::LinuxRaid,
+    FileSystem::LSIRaid,
+    FileSystem::LUKS,
+    FileSystem::LVM1,
+    FileSystem::LVM2,
+    FileSystem::Minix,
+    FileSystem::None,
+    FileSystem::Mpool,
+    FileSystem::Mqueue,
+    FileSystem::Netware,
+    FileSystem::NFS,
+    FileSystem::Nilfs2,
+    FileSystem::NSFS,
+    FileSystem::NTFS,
+    FileSystem::NTFS3,
+    FileSystem::NvidiaRaid,
+    FileSystem::OCFS,
+    FileSystem::OCFS2,
+    FileSystem::OCFS2Dlmfs,
+    FileSystem::Omfs,
+    FileSystem::Openpromfs,
+    FileSystem::Overlay,
+    FileSystem::Pidfs,
+    FileSystem::Pipefs,
+    FileSystem::Proc,
+    FileSystem::PromiseRaid,
+    FileSystem::PseudoEROFS,
+    FileSystem::Pstore,
+    FileSystem::Pvfs2,
+    FileSystem::QNX4,
+    FileSystem::QNX6,
+    FileSystem::ReFs,
+    FileSystem::Reiserfs,
+    FileSystem::Reiser4,
+    FileSystem::Ramfs,
+    FileSystem::Romfs,
+    FileSystem::Rootfs,
+    FileSystem::RpcPipefs,
+    FileSystem::SecurityFs,
+    FileSystem::SeLinuxFs,
+    FileSystem::SiliconRaid,
+    FileSystem::Sockfs,
+    FileSystem::Squashfs,
+    FileSystem::Squashfs3,
+    FileSystem::Stratis,
+    FileSystem::Swap,
+    FileSystem::SwapSuspend,
+    FileSystem::Sysfs,
+    FileSystem::SYSV,
+    FileSystem::Tmpfs,
+    FileSystem::Tracefs,
+    FileSystem::UBI,
+    FileSystem::UBIFS,
+    FileSystem::UDF,
+    FileSystem::UFS,
+    FileSystem::Usbfs,
+    FileSystem::Usbdevfs,
+    FileSystem::Vboxsf,
+    FileSystem::VDO,
+    FileSystem::VFAT,
+    FileSystem::VIARaid,
+    FileSystem::Virtiofs,
+    FileSystem::VMFS,
+    FileSystem::VMFSVolume,
+    FileSystem::Vxfs,
+    FileSystem::Xenix,
+    FileSystem::XFS,
+    FileSystem::XFSLog,
+    FileSystem::ZfsFs,
+    FileSystem::ZFS,
+    FileSystem::Zonefs,
+];
 
 /// Combination of file systems supported by the Linux Kernel, and `libblkid`.
-#[derive(Debug, Eq, PartialEq, Sequence)]
+///
+/// Parsing a string into a `FileSystem` ignores case and surrounding whitespace. Unlisted file
+/// system types are parsed into [`FileSystem::Other`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum FileSystem {
     /// Name: `"adaptec_raid_member"`
@@ -194,6 +346,8 @@ pub enum FileSystem {
     Omfs,
     /// Name: `"openpromfs"`
     Openpromfs,
+    /// Name: `"overlay"`
+    Overlay,
     /// Name: `"pidfs"`
     Pidfs,
     /// Name: `"pipefs"`
@@ -286,11 +440,15 @@ pub enum FileSystem {
     XFS,
     /// Name: `"xfs_external_log"`
     XFSLog,
+    /// Name: `"zfs"`
+    ZfsFs,
     /// Name: `"zfs_member"`
     ZFS,
     /// Name: `"zonefs"`
     Zonefs,
     Unknown,
+    /// A file system type not listed above (e.g. `"fuse.sshfs"`), holding its name.
+    Other(String),
 }
 
 impl FileSystem {
@@ -409,6 +567,7 @@ pub fn as_str(&self) -> &str {
             Self::OCFS2Dlmfs => "ocfs2_dlmfs",
             Self::Omfs => "omfs",
             Self::Openpromfs => "openpromfs",
+            Self::Overlay => "overlay",
             Self::Pidfs => "pidfs",
             Self::Pipefs => "pipefs",
             Self::Proc => "proc",
@@ -455,16 +614,56 @@ pub fn as_str(&self) -> &str {
             Self::Xenix => "xenix",
             Self::XFS => "xfs",
             Self::XFSLog => "xfs_external_log",
+            Self::ZfsFs => "zfs",
             Self::ZFS => "zfs_member",
             Self::Zonefs => "zonefs",
             Self::Unknown => "",
+            Self::Other(name) => name.as_str(),
         }
     }
 
+    /// Returns an iterator over all known file systems.
+    ///
+    /// **Note:** the iterator does not yield [`FileSystem::Unknown`], nor [`FileSystem::Other`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsmount::fs::FileSystem;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let known: Vec<_> = FileSystem::all().collect();
+    ///
+    ///     assert!(known.contains(&FileSystem::Ext4));
+    ///     assert!(!known.contains(&FileSystem::Unknown));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn all() -> impl Iterator<Item = FileSystem> {
+        KNOWN_FILE_SYSTEMS.iter().cloned()
+    }
+
+    /// Returns `true` if this is a network file system (e.g. [`FileSystem::NFS`],
+    /// [`FileSystem::Cifs`]).
+    pub fn is_network(&self) -> bool {
+        utils::is_network_fs(self)
+    }
+
+    /// Returns `true` if this is a pseudo file system (e.g. [`FileSystem::Proc`],
+    /// [`FileSystem::Sysfs`]).
+    pub fn is_pseudo(&self) -> bool {
+        utils::is_pseudo_fs(self)
+    }
+
     /// Converts this `Filesystem` to a [`CString`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name held by a [`FileSystem::Other`] contains a NUL character.
     pub fn to_c_string(&self) -> CString {
-        // FileSystem's string representation does not contain NULL characters,  we can safely
-        // unwrap the new CString.
+        // FileSystem's string representation does not contain NULL characters (parsing rejects
+        // them), we can safely unwrap the new CString.
         CString::new(self.as_str()).unwrap()
     }
 }
@@ -493,7 +692,9 @@ impl FromStr for FileSystem {
     type Err = ParserError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.trim() {
+        let s = s.trim();
+
+        match s.to_ascii_lowercase().as_str() {
             "adaptec_raid_member" => Ok(Self::AdaptecRaid),
             "adfs" => Ok(Self::Adfs),
             "afs" => Ok(Self::Afs),
@@ -508,7 +709,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "bfs" => Ok(Self::Bfs),
             "binder" => Ok(Self::Binder),
             "binfmt_misc" => Ok(Self::BinfmtMisc),
-            "BitLocker" => Ok(Self::BitLocker),
+            "bitlocker" => Ok(Self::BitLocker),
             "ceph_bluestore" => Ok(Self::BlueStore),
             "bpf" => Ok(Self::Bpf),
             "btrfs" => Ok(Self::BTRFS),
@@ -523,9 +724,9 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "devpts" => Ok(Self::Devpts),
             "devtmpfs" => Ok(Self::Devtmpfs),
             "ddf_raid_member" => Ok(Self::DDFRaid),
-            "DM_integrity" => Ok(Self::DmIntegrity),
-            "DM_snapshot_cow" => Ok(Self::DmSnapshot),
-            "DM_verify_hash" => Ok(Self::DmVerify),
+            "dm_integrity" => Ok(Self::DmIntegrity),
+            "dm_snapshot_cow" => Ok(Self::DmSnapshot),
+            "dm_verify_hash" => Ok(Self::DmVerify),
             "drbd" => Ok(Self::DRBD),
             "drbdmanage_control_volume" => Ok(Self::DRBDManage),
             "drbdproxy_datalog" => Ok(Self::DRBDProxyDatalog),
@@ -564,9 +765,9 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "jmicron_raid_member" => Ok(Self::JmicronRaid),
             "linux_raid_member" => Ok(Self::LinuxRaid),
             "lsi_mega_raid_member" => Ok(Self::LSIRaid),
-            "crypto_LUKS" => Ok(Self::LUKS),
-            "LVM1_member" => Ok(Self::LVM1),
-            "LVM2_member" => Ok(Self::LVM2),
+            "crypto_luks" => Ok(Self::LUKS),
+            "lvm1_member" => Ok(Self::LVM1),
+            "lvm2_member" => Ok(Self::LVM2),
             "minix" => Ok(Self::Minix),
             "mpool" => Ok(Self::Mpool),
             "mqueue" => Ok(Self::Mqueue),
@@ -583,6 +784,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "ocfs2_dlmfs" => Ok(Self::OCFS2Dlmfs),
             "omfs" => Ok(Self::Omfs),
             "openpromfs" => Ok(Self::Openpromfs),
+            "overlay" => Ok(Self::Overlay),
             "pidfs" => Ok(Self::Pidfs),
             "pipefs" => Ok(Self::Pipefs),
             "proc" => Ok(Self::Proc),
@@ -592,7 +794,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "pvfs2" => Ok(Self::Pvfs2),
             "qnx4" => Ok(Self::QNX4),
             "qnx6" => Ok(Self::QNX6),
-            "ReFs" => Ok(Self::ReFs),
+            "refs" => Ok(Self::ReFs),
             "reiserfs" => Ok(Self::Reiserfs),
             "reiser4" => Ok(Self::Reiser4),
             "ramfs" => Ok(Self::Ramfs),
@@ -623,23 +825,66 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             "vfat" => Ok(Self::VFAT),
             "via_raid_member" => Ok(Self::VIARaid),
             "virtiofs" => Ok(Self::Virtiofs),
-            "VMFS" => Ok(Self::VMFS),
-            "VMFS_volume_member" => Ok(Self::VMFSVolume),
+            "vmfs" => Ok(Self::VMFS),
+            "vmfs_volume_member" => Ok(Self::VMFSVolume),
             "vxfs" => Ok(Self::Vxfs),
             "xenix" => Ok(Self::Xenix),
             "xfs" => Ok(Self::XFS),
             "xfs_external_log" => Ok(Self::XFSLog),
+            "zfs" => Ok(Self::ZfsFs),
             "zfs_member" => Ok(Self::ZFS),
             "zonefs" => Ok(Self::Zonefs),
             "" => Ok(Self::Unknown),
-            unsupported => {
-                let err_msg = format!("unsupported file system: {:?}", unsupported);
+            _ if s.contains('\0') => {
+                let err_msg = format!(
+                    "invalid file system type: {:?}. Contains a NUL character",
+                    s
+                );
                 Err(ParserError::FileSystem(err_msg))
             }
+            _ => Ok(Self::Other(s.to_owned())),
         }
     }
 }
 
+// `FileSystem::Other` holds an arbitrary name, `Sequence` can not be derived. The sequence is
+// made of every known file system, in declaration order, followed by `FileSystem::Unknown`.
+impl Sequence for FileSystem {
+    const CARDINALITY: usize = KNOWN_FILE_SYSTEMS_COUNT + 1;
+
+    fn next(&self) -> Option<Self> {
+        match self {
+            Self::Unknown | Self::Other(_) => None,
+            known => match KNOWN_FILE_SYSTEMS.iter().position(|fs| fs == known) {
+                Some(i) if i + 1 < KNOWN_FILE_SYSTEMS.len() => {
+                    Some(KNOWN_FILE_SYSTEMS[i + 1].clone())
+                }
+                _ => Some(Self::Unknown),
+            },
+        }
+    }
+
+    fn previous(&self) -> Option<Self> {
+        match self {
+            Self::Unknown => KNOWN_FILE_SYSTEMS.last().cloned(),
+            Self::Other(_) => None,
+            known => KNOWN_FILE_SYSTEMS
+                .iter()
+                .position(|fs| fs == known)
+                .and_then(|i| i.checked_sub(1))
+                .map(|i| KNOWN_FILE_SYSTEMS[i].clone()),
+        }
+    }
+
+    fn first() -> Option<Self> {
+        KNOWN_FILE_SYSTEMS.first().cloned()
+    }
+
+    fn last() -> Option<Self> {
+        Some(Self::Unknown)
+    }
+}
+
 #[cfg(test)]
 #[allow(unused_imports)]
 mod tests {
@@ -647,9 +892,132 @@ mod tests {
     use pretty_assertions::{assert_eq, assert_ne};
 
     #[test]
-    #[should_panic(expected = "unsupported file system")]
-    fn file_system_can_not_parse_an_invalid_file_system_type() {
-        let _: FileSystem = "DUMMY".parse().unwrap();
+    #[should_panic(expected = "invalid file system type")]
+    fn file_system_can_not_parse_a_file_system_type_with_a_nul_character() {
+        let _: FileSystem = "ext\04".parse().unwrap();
+    }
+
+    #[test]
+    fn file_system_can_parse_an_unlisted_file_system_type() -> crate::Result<()> {
+        let actual: FileSystem = " fuse.sshfs ".parse()?;
+        let expected = FileSystem::Other("fuse.sshfs".to_owned());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_system_can_parse_ignoring_case_and_whitespace() -> crate::Result<()> {
+        let actual: FileSystem = " EXT4\n".parse()?;
+        let expected = FileSystem::Ext4;
+        assert_eq!(actual, expected);
+
+        let actual: FileSystem = "vfat".parse()?;
+        let expected = FileSystem::VFAT;
+        assert_eq!(actual, expected);
+
+        let actual: FileSystem = "TmpFs".parse()?;
+        let expected = FileSystem::Tmpfs;
+        assert_eq!(actual, expected);
+
+        let actual: FileSystem = "bitlocker".parse()?;
+        let expected = FileSystem::BitLocker;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_system_can_parse_the_third_column_of_an_fstab_line() -> crate::Result<()> {
+        let line = "UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f /  ext4  rw,relatime 0 1";
+        let actual: FileSystem = line.split_whitespace().nth(2).unwrap().parse()?;
+        let expected = FileSystem::Ext4;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_system_can_identify_network_file_systems() {
+        assert!(FileSystem::NFS.is_network());
+        assert!(FileSystem::Cifs.is_network());
+        assert!(!FileSystem::Ext4.is_network());
+        assert!(!FileSystem::Tmpfs.is_network());
+    }
+
+    #[test]
+    fn file_system_can_identify_pseudo_file_systems() {
+        assert!(FileSystem::Proc.is_pseudo());
+        assert!(FileSystem::Sysfs.is_pseudo());
+        assert!(FileSystem::Tmpfs.is_pseudo());
+        assert!(!FileSystem::Ext4.is_pseudo());
+        assert!(!FileSystem::NFS.is_pseudo());
+    }
+
+    #[test]
+    fn file_system_all_yields_every_known_file_system() -> crate::Result<()> {
+        let actual = FileSystem::all().count();
+        let expected = 139;
+        assert_eq!(actual, expected);
+
+        for fs in FileSystem::all() {
+            let actual: FileSystem = fs.to_string().parse()?;
+            let expected = fs;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_system_sequence_yields_every_known_file_system_then_unknown() {
+        let actual: Vec<_> = enum_iterator::all::<FileSystem>().collect();
+        let expected: Vec<_> = FileSystem::all()
+            .chain(std::iter::once(FileSystem::Unknown))
+            .collect();
+        assert_eq!(actual, expected);
+
+        let actual: Vec<_> = enum_iterator::reverse_all::<FileSystem>().collect();
+        let expected: Vec<_> = expected.into_iter().rev().collect();
+        assert_eq!(actual, expected);
+
+        let actual = enum_iterator::cardinality::<FileSystem>();
+        let expected = 140;
+        assert_eq!(actual, expected);
+
+        let actual = FileSystem::Other("fuse.sshfs".to_owned()).next();
+        let expected = None;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn file_system_all_does_not_yield_catch_all_variants() {
+        assert!(!FileSystem::all().any(|fs| fs == FileSystem::Unknown));
+        assert!(!FileSystem::all().any(|fs| matches!(fs, FileSystem::Other(_))));
+    }
+
+    #[test]
+    fn file_system_display_round_trips() -> crate::Result<()> {
+        for fs_str in [
+            "ext4",
+            "vfat",
+            "tmpfs",
+            "BitLocker",
+            "crypto_LUKS",
+            "fuse.sshfs",
+        ] {
+            let fs: FileSystem = fs_str.parse()?;
+
+            let actual = fs.to_string();
+            let expected = fs_str;
+            assert_eq!(actual, expected);
+
+            let actual: FileSystem = fs.to_string().parse()?;
+            let expected = fs;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
     }
 
     #[test]
@@ -1099,6 +1467,11 @@ fn file_system_can_parse_a_valid_file_system_type() -> crate::Result<()> {
         let expected = FileSystem::Openpromfs;
         assert_eq!(actual, expected);
 
+        let fs_str = "overlay";
+        let actual: FileSystem = fs_str.parse()?;
+        let expected = FileSystem::Overlay;
+        assert_eq!(actual, expected);
+
         let fs_str = "pidfs";
         let actual: FileSystem = fs_str.parse()?;
         let expected = FileSystem::Pidfs;
@@ -1329,6 +1702,11 @@ fn file_system_can_parse_a_valid_file_system_type() -> crate::Result<()> {
         let expected = FileSystem::XFSLog;
         assert_eq!(actual, expected);
 
+        let fs_str = "zfs";
+        let actual: FileSystem = fs_str.parse()?;
+        let expected = FileSystem::ZfsFs;
+        assert_eq!(actual, expected);
+
         let fs_str = "zfs_member";
         let actual: FileSystem = fs_str.parse()?;
         let expected = FileSystem::ZFS;
@@ -9,6 +9,7 @@
 
 // From this library
 pub use file_change_struct::FileChange;
+pub use file_lock_guard_struct::FileLockGuard;
 pub use file_lock_struct::FileLock;
 pub use file_system_enum::FileSystem;
 pub use fs_type_enum::FsType;
@@ -16,6 +17,7 @@
 pub use monitor_status_enum::MonitorStatus;
 
 mod file_change_struct;
+mod file_lock_guard_struct;
 mod file_lock_struct;
 mod file_system_enum;
 mod fs_type_enum;
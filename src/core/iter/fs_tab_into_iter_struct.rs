@@ -0,0 +1,87 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::mem::MaybeUninit;
+
+// From this library
+use crate::core::entries::FsTabEntry;
+use crate::core::errors::FsTabIterError;
+use crate::core::iter::Direction;
+use crate::core::iter::GenIterator;
+use crate::tables::FsTab;
+
+/// Consuming iterator over [`FsTab`] entries.
+///
+/// Each [`FsTabEntry`] it yields is independent from the table, which stays alive until the iterator is
+/// dropped.
+#[derive(Debug)]
+pub struct FsTabIntoIter {
+    table: FsTab,
+    /// Forward iterator.
+    iter: GenIterator,
+    /// Number of entries left to yield.
+    remaining: usize,
+}
+
+impl FsTabIntoIter {
+    /// Creates a new `FsTabIntoIter`.
+    pub(crate) fn new(table: FsTab) -> Result<FsTabIntoIter, FsTabIterError> {
+        let iter = GenIterator::new(Direction::Forward)?;
+        let remaining = table.len();
+
+        let iterator = Self {
+            table,
+            iter,
+            remaining,
+        };
+
+        Ok(iterator)
+    }
+}
+
+impl Iterator for FsTabIntoIter {
+    type Item = FsTabEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        log::debug!("FsTabIntoIter::next getting next table entry");
+
+        let mut entry_ptr = MaybeUninit::<*mut libmount::libmnt_fs>::zeroed();
+
+        let result = unsafe {
+            libmount::mnt_table_next_fs(self.table.inner, self.iter.inner, entry_ptr.as_mut_ptr())
+        };
+
+        match result {
+            0 => {
+                log::debug!("FsTabIntoIter::next got next table entry");
+
+                let ptr = unsafe { entry_ptr.assume_init() };
+                self.remaining = self.remaining.saturating_sub(1);
+
+                // Increment the entry's reference counter, so that it outlives the table.
+                let entry = FsTabEntry::borrow_ptr(ptr);
+
+                Some(entry)
+            }
+            1 => {
+                log::debug!("FsTabIntoIter::next reached end of `FsTabIntoIter`");
+
+                None
+            }
+            code => {
+                log::debug!( "FsTabIntoIter::next failed to get next table entry. libmount::mnt_table_next_fs returned error code: {code:?}");
+
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for FsTabIntoIter {}
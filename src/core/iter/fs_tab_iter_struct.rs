@@ -29,6 +29,8 @@ pub struct FsTabIter<'table> {
     bwd_cursor: *mut libmount::libmnt_fs,
     /// Indicator of forward and backward iterators meeting in the middle.
     have_iterators_met: bool,
+    /// Number of entries left to yield.
+    remaining: usize,
 }
 
 impl<'table> FsTabIter<'table> {
@@ -40,6 +42,7 @@ pub(crate) fn new(table: &'table FsTab) -> Result<FsTabIter<'table>, FsTabIterEr
         let fwd_cursor = std::ptr::null_mut();
         let bwd_cursor = std::ptr::null_mut();
         let have_iterators_met = false;
+        let remaining = table.len();
 
         let iterator = Self {
             table,
@@ -48,6 +51,7 @@ pub(crate) fn new(table: &'table FsTab) -> Result<FsTabIter<'table>, FsTabIterEr
             fwd_cursor,
             bwd_cursor,
             have_iterators_met,
+            remaining,
         };
 
         Ok(iterator)
@@ -72,6 +76,7 @@ pub fn advance_to(&mut self, n: usize) -> Result<(), NonZeroUsize> {
         self.fwd_cursor = std::ptr::null_mut();
         self.bwd_cursor = std::ptr::null_mut();
         self.have_iterators_met = false;
+        self.remaining = self.table.len();
 
         let mut result;
 
@@ -100,12 +105,14 @@ pub fn advance_to(&mut self, n: usize) -> Result<(), NonZeroUsize> {
                                     );
 
                         self.have_iterators_met = true;
+                        self.remaining = 0;
 
                         return Err(unsafe { NonZeroUsize::new_unchecked(n - i) });
                     } else {
                         log::debug!("FsTabIter::advance_to advanced to the {i}th table entry");
 
                         self.fwd_cursor = ptr;
+                        self.remaining = self.remaining.saturating_sub(1);
                     }
                 }
                 1 => {
@@ -155,12 +162,14 @@ fn next(&mut self) -> Option<Self::Item> {
                     );
 
                     self.have_iterators_met = true;
+                    self.remaining = 0;
 
                     None
                 } else {
                     log::debug!("FsTabIter::next got next table entry");
 
                     self.fwd_cursor = ptr;
+                    self.remaining = self.remaining.saturating_sub(1);
                     let entry = owning_ref_from_ptr!(self.table, FsTabEntry, ptr);
 
                     Some(entry)
@@ -179,6 +188,10 @@ fn next(&mut self) -> Option<Self::Item> {
         }
     }
 
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
     // Skips n-1 entries, and updates cursor.
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         log::debug!("FsTabIter::nth getting {n}th table entry");
@@ -210,12 +223,14 @@ fn nth(&mut self, n: usize) -> Option<Self::Item> {
                         );
 
                         self.have_iterators_met = true;
+                        self.remaining = 0;
 
                         return None;
                     } else {
                         log::debug!("FsTabIter::nth got {i}th table entry");
 
                         self.fwd_cursor = ptr;
+                        self.remaining = self.remaining.saturating_sub(1);
                     }
                 }
                 1 => {
@@ -235,6 +250,8 @@ fn nth(&mut self, n: usize) -> Option<Self::Item> {
     }
 }
 
+impl<'table> ExactSizeIterator for FsTabIter<'table> {}
+
 impl<'table> DoubleEndedIterator for FsTabIter<'table> {
     fn next_back(&mut self) -> Option<Self::Item> {
         log::debug!("FsTabIter::next_back getting next table entry from the back");
@@ -263,12 +280,14 @@ fn next_back(&mut self) -> Option<Self::Item> {
                                 );
 
                     self.have_iterators_met = true;
+                    self.remaining = 0;
 
                     None
                 } else {
                     log::debug!("FsTabIter::next_back got next table entry");
 
                     self.bwd_cursor = ptr;
+                    self.remaining = self.remaining.saturating_sub(1);
                     let entry = owning_ref_from_ptr!(self.table, FsTabEntry, ptr);
 
                     Some(entry)
@@ -324,6 +343,7 @@ fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
                         ));
 
                         self.have_iterators_met = true;
+                        self.remaining = 0;
 
                         return None;
                     } else {
@@ -336,6 +356,7 @@ fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
                         );
 
                         self.bwd_cursor = ptr;
+                        self.remaining = self.remaining.saturating_sub(1);
                     }
                 }
                 1 => {
@@ -10,30 +10,40 @@
 // From this library
 pub use direction_enum::Direction;
 pub use fs_tab_diff_iter_struct::FsTabDiffIter;
+pub use fs_tab_into_iter_struct::FsTabIntoIter;
 pub use fs_tab_iter_mut_struct::FsTabIterMut;
 pub use fs_tab_iter_struct::FsTabIter;
 pub use gen_iterator_struct::GenIterator;
 pub use mount_info_child_iter_struct::MountInfoChildIter;
 pub use mount_info_diff_iter_struct::MountInfoDiffIter;
+pub use mount_info_into_iter_struct::MountInfoIntoIter;
 pub use mount_info_iter_struct::MountInfoIter;
 pub use mount_info_overmount_iter_struct::MountInfoOvermountIter;
 pub use swaps_diff_iter_struct::SwapsDiffIter;
+pub use swaps_into_iter_struct::SwapsIntoIter;
 pub use swaps_iter_struct::SwapsIter;
+pub use table_monitor_iter_struct::TableMonitorIter;
 pub use utab_diff_iter_struct::UTabDiffIter;
+pub use utab_into_iter_struct::UTabIntoIter;
 pub use utab_iter_mut_struct::UTabIterMut;
 pub use utab_iter_struct::UTabIter;
 
 mod direction_enum;
 mod fs_tab_diff_iter_struct;
+mod fs_tab_into_iter_struct;
 mod fs_tab_iter_mut_struct;
 mod fs_tab_iter_struct;
 mod gen_iterator_struct;
 mod mount_info_child_iter_struct;
 mod mount_info_diff_iter_struct;
+mod mount_info_into_iter_struct;
 mod mount_info_iter_struct;
 mod mount_info_overmount_iter_struct;
 mod swaps_diff_iter_struct;
+mod swaps_into_iter_struct;
 mod swaps_iter_struct;
+mod table_monitor_iter_struct;
 mod utab_diff_iter_struct;
+mod utab_into_iter_struct;
 mod utab_iter_mut_struct;
 mod utab_iter_struct;
@@ -0,0 +1,87 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::mem::MaybeUninit;
+
+// From this library
+use crate::core::entries::MountInfoEntry;
+use crate::core::errors::MountInfoIterError;
+use crate::core::iter::Direction;
+use crate::core::iter::GenIterator;
+use crate::tables::MountInfo;
+
+/// Consuming iterator over [`MountInfo`] entries.
+///
+/// Each [`MountInfoEntry`] it yields is independent from the table, which stays alive until the iterator is
+/// dropped.
+#[derive(Debug)]
+pub struct MountInfoIntoIter {
+    table: MountInfo,
+    /// Forward iterator.
+    iter: GenIterator,
+    /// Number of entries left to yield.
+    remaining: usize,
+}
+
+impl MountInfoIntoIter {
+    /// Creates a new `MountInfoIntoIter`.
+    pub(crate) fn new(table: MountInfo) -> Result<MountInfoIntoIter, MountInfoIterError> {
+        let iter = GenIterator::new(Direction::Forward)?;
+        let remaining = table.len();
+
+        let iterator = Self {
+            table,
+            iter,
+            remaining,
+        };
+
+        Ok(iterator)
+    }
+}
+
+impl Iterator for MountInfoIntoIter {
+    type Item = MountInfoEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        log::debug!("MountInfoIntoIter::next getting next table entry");
+
+        let mut entry_ptr = MaybeUninit::<*mut libmount::libmnt_fs>::zeroed();
+
+        let result = unsafe {
+            libmount::mnt_table_next_fs(self.table.inner, self.iter.inner, entry_ptr.as_mut_ptr())
+        };
+
+        match result {
+            0 => {
+                log::debug!("MountInfoIntoIter::next got next table entry");
+
+                let ptr = unsafe { entry_ptr.assume_init() };
+                self.remaining = self.remaining.saturating_sub(1);
+
+                // Increment the entry's reference counter, so that it outlives the table.
+                let entry = MountInfoEntry::borrow_ptr(ptr);
+
+                Some(entry)
+            }
+            1 => {
+                log::debug!("MountInfoIntoIter::next reached end of `MountInfoIntoIter`");
+
+                None
+            }
+            code => {
+                log::debug!( "MountInfoIntoIter::next failed to get next table entry. libmount::mnt_table_next_fs returned error code: {code:?}");
+
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for MountInfoIntoIter {}
@@ -28,6 +28,8 @@ pub struct MountInfoIter<'table> {
     bwd_cursor: *mut libmount::libmnt_fs,
     /// Indicator of forward and backward iterators meeting in the middle.
     have_iterators_met: bool,
+    /// Number of entries left to yield.
+    remaining: usize,
 }
 
 impl<'table> MountInfoIter<'table> {
@@ -41,6 +43,7 @@ pub(crate) fn new(
         let fwd_cursor = std::ptr::null_mut();
         let bwd_cursor = std::ptr::null_mut();
         let have_iterators_met = false;
+        let remaining = table.len();
 
         let iterator = Self {
             table,
@@ -49,6 +52,7 @@ pub(crate) fn new(
             fwd_cursor,
             bwd_cursor,
             have_iterators_met,
+            remaining,
         };
 
         Ok(iterator)
@@ -73,6 +77,7 @@ pub fn advance_to(&mut self, n: usize) -> Result<(), NonZeroUsize> {
         self.fwd_cursor = std::ptr::null_mut();
         self.bwd_cursor = std::ptr::null_mut();
         self.have_iterators_met = false;
+        self.remaining = self.table.len();
 
         let mut result;
 
@@ -101,12 +106,14 @@ pub fn advance_to(&mut self, n: usize) -> Result<(), NonZeroUsize> {
                                     );
 
                         self.have_iterators_met = true;
+                        self.remaining = 0;
 
                         return Err(unsafe { NonZeroUsize::new_unchecked(n - i) });
                     } else {
                         log::debug!("MountInfoIter::advance_to advanced to the {i}th table entry");
 
                         self.fwd_cursor = ptr;
+                        self.remaining = self.remaining.saturating_sub(1);
                     }
                 }
                 1 => {
@@ -156,12 +163,14 @@ fn next(&mut self) -> Option<Self::Item> {
                     );
 
                     self.have_iterators_met = true;
+                    self.remaining = 0;
 
                     None
                 } else {
                     log::debug!("MountInfoIter::next got next table entry");
 
                     self.fwd_cursor = ptr;
+                    self.remaining = self.remaining.saturating_sub(1);
                     let entry = owning_ref_from_ptr!(self.table, MountInfoEntry, ptr);
 
                     Some(entry)
@@ -180,6 +189,10 @@ fn next(&mut self) -> Option<Self::Item> {
         }
     }
 
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
     // Skips n-1 entries, and updates cursor.
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         log::debug!("MountInfoIter::nth getting {n}th table entry");
@@ -211,12 +224,14 @@ fn nth(&mut self, n: usize) -> Option<Self::Item> {
                         );
 
                         self.have_iterators_met = true;
+                        self.remaining = 0;
 
                         return None;
                     } else {
                         log::debug!("MountInfoIter::nth got {i}th table entry");
 
                         self.fwd_cursor = ptr;
+                        self.remaining = self.remaining.saturating_sub(1);
                     }
                 }
                 1 => {
@@ -236,6 +251,8 @@ fn nth(&mut self, n: usize) -> Option<Self::Item> {
     }
 }
 
+impl<'table> ExactSizeIterator for MountInfoIter<'table> {}
+
 impl<'table> DoubleEndedIterator for MountInfoIter<'table> {
     fn next_back(&mut self) -> Option<Self::Item> {
         log::debug!("MountInfoIter::next_back getting next table entry from the back");
@@ -264,12 +281,14 @@ fn next_back(&mut self) -> Option<Self::Item> {
                     );
 
                     self.have_iterators_met = true;
+                    self.remaining = 0;
 
                     None
                 } else {
                     log::debug!("MountInfoIter::next_back got next table entry");
 
                     self.bwd_cursor = ptr;
+                    self.remaining = self.remaining.saturating_sub(1);
                     let entry = owning_ref_from_ptr!(self.table, MountInfoEntry, ptr);
 
                     Some(entry)
@@ -318,12 +337,14 @@ fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
                         );
 
                         self.have_iterators_met = true;
+                        self.remaining = 0;
 
                         return None;
                     } else {
                         log::debug!("MountInfoIter::nth_back got {i}th table entry from the back");
 
                         self.bwd_cursor = ptr;
+                        self.remaining = self.remaining.saturating_sub(1);
                     }
                 }
                 1 => {
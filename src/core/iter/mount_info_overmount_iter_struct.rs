@@ -12,10 +12,14 @@
 use crate::tables::MountInfo;
 
 /// Iterator over all [`MountInfo`] entries sharing the same mount point.
+///
+/// Entries are yielded from the bottom to the top of the stack of file systems mounted over the
+/// initial entry.
 #[derive(Debug)]
 pub struct MountInfoOvermountIter<'table> {
     table: &'table MountInfo,
-    parent: &'table MountInfoEntry,
+    /// Last entry yielded (or the initial entry before iteration starts).
+    cursor: *mut libmount::libmnt_fs,
 }
 
 impl<'table> MountInfoOvermountIter<'table> {
@@ -25,7 +29,10 @@ pub(crate) fn new(
         table: &'table MountInfo,
         parent: &'table MountInfoEntry,
     ) -> MountInfoOvermountIter<'table> {
-        Self { table, parent }
+        Self {
+            table,
+            cursor: parent.inner,
+        }
     }
 }
 
@@ -38,11 +45,7 @@ fn next(&mut self) -> Option<Self::Item> {
         let mut overmount_ptr = MaybeUninit::<*mut libmount::libmnt_fs>::zeroed();
 
         let result = unsafe {
-            libmount::mnt_table_over_fs(
-                self.table.inner,
-                self.parent.inner,
-                overmount_ptr.as_mut_ptr(),
-            )
+            libmount::mnt_table_over_fs(self.table.inner, self.cursor, overmount_ptr.as_mut_ptr())
         };
 
         match result {
@@ -50,6 +53,8 @@ fn next(&mut self) -> Option<Self::Item> {
                 let ptr = unsafe { overmount_ptr.assume_init() };
                 log::debug!("MountInfoOvermountIter::next got next over mounted entry");
 
+                // Move up the stack of mounts sharing the same mount point.
+                self.cursor = ptr;
                 let overmount = owning_ref_from_ptr!(self.table, MountInfoEntry, ptr);
 
                 Some(overmount)
@@ -78,7 +83,7 @@ fn nth(&mut self, n: usize) -> Option<Self::Item> {
             result = unsafe {
                 libmount::mnt_table_over_fs(
                     self.table.inner,
-                    self.parent.inner,
+                    self.cursor,
                     overmount_ptr.as_mut_ptr(),
                 )
             };
@@ -86,6 +91,8 @@ fn nth(&mut self, n: usize) -> Option<Self::Item> {
             match result {
                 0 => {
                     log::debug!("MountInfoOvermountIter::nth got {i:?}th over mounted entry");
+
+                    self.cursor = unsafe { overmount_ptr.assume_init() };
                 }
                 1 => {
                     log::debug!(
@@ -0,0 +1,87 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::mem::MaybeUninit;
+
+// From this library
+use crate::core::entries::SwapsEntry;
+use crate::core::errors::SwapsIterError;
+use crate::core::iter::Direction;
+use crate::core::iter::GenIterator;
+use crate::tables::Swaps;
+
+/// Consuming iterator over [`Swaps`] entries.
+///
+/// Each [`SwapsEntry`] it yields is independent from the table, which stays alive until the iterator is
+/// dropped.
+#[derive(Debug)]
+pub struct SwapsIntoIter {
+    table: Swaps,
+    /// Forward iterator.
+    iter: GenIterator,
+    /// Number of entries left to yield.
+    remaining: usize,
+}
+
+impl SwapsIntoIter {
+    /// Creates a new `SwapsIntoIter`.
+    pub(crate) fn new(table: Swaps) -> Result<SwapsIntoIter, SwapsIterError> {
+        let iter = GenIterator::new(Direction::Forward)?;
+        let remaining = table.len();
+
+        let iterator = Self {
+            table,
+            iter,
+            remaining,
+        };
+
+        Ok(iterator)
+    }
+}
+
+impl Iterator for SwapsIntoIter {
+    type Item = SwapsEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        log::debug!("SwapsIntoIter::next getting next table entry");
+
+        let mut entry_ptr = MaybeUninit::<*mut libmount::libmnt_fs>::zeroed();
+
+        let result = unsafe {
+            libmount::mnt_table_next_fs(self.table.inner, self.iter.inner, entry_ptr.as_mut_ptr())
+        };
+
+        match result {
+            0 => {
+                log::debug!("SwapsIntoIter::next got next table entry");
+
+                let ptr = unsafe { entry_ptr.assume_init() };
+                self.remaining = self.remaining.saturating_sub(1);
+
+                // Increment the entry's reference counter, so that it outlives the table.
+                let entry = SwapsEntry::borrow_ptr(ptr);
+
+                Some(entry)
+            }
+            1 => {
+                log::debug!("SwapsIntoIter::next reached end of `SwapsIntoIter`");
+
+                None
+            }
+            code => {
+                log::debug!( "SwapsIntoIter::next failed to get next table entry. libmount::mnt_table_next_fs returned error code: {code:?}");
+
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for SwapsIntoIter {}
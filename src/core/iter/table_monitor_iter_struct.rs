@@ -0,0 +1,47 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::fs::FileChange;
+use crate::tables::TableMonitor;
+
+/// Iterator over pending [`TableMonitor`] file changes.
+#[derive(Debug)]
+pub struct TableMonitorIter<'monitor> {
+    monitor: &'monitor mut TableMonitor,
+}
+
+impl<'monitor> TableMonitorIter<'monitor> {
+    #[doc(hidden)]
+    /// Creates a new `TableMonitorIter`.
+    #[allow(dead_code)]
+    pub(crate) fn new(monitor: &'monitor mut TableMonitor) -> TableMonitorIter<'monitor> {
+        Self { monitor }
+    }
+}
+
+impl<'monitor> Iterator for TableMonitorIter<'monitor> {
+    type Item = FileChange;
+
+    /// Returns the next pending file change, or `None` if there is no more change to process or
+    /// an error occurred.
+    fn next(&mut self) -> Option<Self::Item> {
+        log::debug!("TableMonitorIter::next getting next file change");
+
+        match self.monitor.next_file_change() {
+            Ok(change) => change,
+            Err(e) => {
+                log::debug!(
+                    "TableMonitorIter::next failed to get next file change. {:?}",
+                    e
+                );
+
+                None
+            }
+        }
+    }
+}
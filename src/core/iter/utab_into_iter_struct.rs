@@ -0,0 +1,87 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::mem::MaybeUninit;
+
+// From this library
+use crate::core::entries::UTabEntry;
+use crate::core::errors::UTabIterError;
+use crate::core::iter::Direction;
+use crate::core::iter::GenIterator;
+use crate::tables::UTab;
+
+/// Consuming iterator over [`UTab`] entries.
+///
+/// Each [`UTabEntry`] it yields is independent from the table, which stays alive until the iterator is
+/// dropped.
+#[derive(Debug)]
+pub struct UTabIntoIter {
+    table: UTab,
+    /// Forward iterator.
+    iter: GenIterator,
+    /// Number of entries left to yield.
+    remaining: usize,
+}
+
+impl UTabIntoIter {
+    /// Creates a new `UTabIntoIter`.
+    pub(crate) fn new(table: UTab) -> Result<UTabIntoIter, UTabIterError> {
+        let iter = GenIterator::new(Direction::Forward)?;
+        let remaining = table.len();
+
+        let iterator = Self {
+            table,
+            iter,
+            remaining,
+        };
+
+        Ok(iterator)
+    }
+}
+
+impl Iterator for UTabIntoIter {
+    type Item = UTabEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        log::debug!("UTabIntoIter::next getting next table entry");
+
+        let mut entry_ptr = MaybeUninit::<*mut libmount::libmnt_fs>::zeroed();
+
+        let result = unsafe {
+            libmount::mnt_table_next_fs(self.table.inner, self.iter.inner, entry_ptr.as_mut_ptr())
+        };
+
+        match result {
+            0 => {
+                log::debug!("UTabIntoIter::next got next table entry");
+
+                let ptr = unsafe { entry_ptr.assume_init() };
+                self.remaining = self.remaining.saturating_sub(1);
+
+                // Increment the entry's reference counter, so that it outlives the table.
+                let entry = UTabEntry::borrow_ptr(ptr);
+
+                Some(entry)
+            }
+            1 => {
+                log::debug!("UTabIntoIter::next reached end of `UTabIntoIter`");
+
+                None
+            }
+            code => {
+                log::debug!( "UTabIntoIter::next failed to get next table entry. libmount::mnt_table_next_fs returned error code: {code:?}");
+
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for UTabIntoIter {}
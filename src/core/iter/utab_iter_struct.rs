@@ -28,6 +28,8 @@ pub struct UTabIter<'table> {
     bwd_cursor: *mut libmount::libmnt_fs,
     /// Indicator of forward and backward iterators meeting in the middle.
     have_iterators_met: bool,
+    /// Number of entries left to yield.
+    remaining: usize,
 }
 
 impl<'table> UTabIter<'table> {
@@ -39,6 +41,7 @@ pub(crate) fn new(table: &'table UTab) -> Result<UTabIter<'table>, UTabIterError
         let fwd_cursor = std::ptr::null_mut();
         let bwd_cursor = std::ptr::null_mut();
         let have_iterators_met = false;
+        let remaining = table.len();
 
         let iterator = Self {
             table,
@@ -47,6 +50,7 @@ pub(crate) fn new(table: &'table UTab) -> Result<UTabIter<'table>, UTabIterError
             fwd_cursor,
             bwd_cursor,
             have_iterators_met,
+            remaining,
         };
 
         Ok(iterator)
@@ -71,6 +75,7 @@ pub fn advance_to(&mut self, n: usize) -> Result<(), NonZeroUsize> {
         self.fwd_cursor = std::ptr::null_mut();
         self.bwd_cursor = std::ptr::null_mut();
         self.have_iterators_met = false;
+        self.remaining = self.table.len();
 
         let mut result;
 
@@ -99,12 +104,14 @@ pub fn advance_to(&mut self, n: usize) -> Result<(), NonZeroUsize> {
                                     );
 
                         self.have_iterators_met = true;
+                        self.remaining = 0;
 
                         return Err(unsafe { NonZeroUsize::new_unchecked(n - i) });
                     } else {
                         log::debug!("UTabIter::advance_to advanced to the {i}th table entry");
 
                         self.fwd_cursor = ptr;
+                        self.remaining = self.remaining.saturating_sub(1);
                     }
                 }
                 1 => {
@@ -154,12 +161,14 @@ fn next(&mut self) -> Option<Self::Item> {
                     );
 
                     self.have_iterators_met = true;
+                    self.remaining = 0;
 
                     None
                 } else {
                     log::debug!("UTabIter::next got next table entry");
 
                     self.fwd_cursor = ptr;
+                    self.remaining = self.remaining.saturating_sub(1);
                     let entry = owning_ref_from_ptr!(self.table, UTabEntry, ptr);
 
                     Some(entry)
@@ -178,6 +187,10 @@ fn next(&mut self) -> Option<Self::Item> {
         }
     }
 
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
     // Skips n-1 entries, and updates cursor.
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
         log::debug!("UTabIter::nth getting {n}th table entry");
@@ -209,12 +222,14 @@ fn nth(&mut self, n: usize) -> Option<Self::Item> {
                         );
 
                         self.have_iterators_met = true;
+                        self.remaining = 0;
 
                         return None;
                     } else {
                         log::debug!("UTabIter::nth got {i}th table entry");
 
                         self.fwd_cursor = ptr;
+                        self.remaining = self.remaining.saturating_sub(1);
                     }
                 }
                 1 => {
@@ -234,6 +249,8 @@ fn nth(&mut self, n: usize) -> Option<Self::Item> {
     }
 }
 
+impl<'table> ExactSizeIterator for UTabIter<'table> {}
+
 impl<'table> DoubleEndedIterator for UTabIter<'table> {
     fn next_back(&mut self) -> Option<Self::Item> {
         log::debug!("UTabIter::next_back getting next table entry from the back");
@@ -262,12 +279,14 @@ fn next_back(&mut self) -> Option<Self::Item> {
                     );
 
                     self.have_iterators_met = true;
+                    self.remaining = 0;
 
                     None
                 } else {
                     log::debug!("UTabIter::next_back got next table entry");
 
                     self.bwd_cursor = ptr;
+                    self.remaining = self.remaining.saturating_sub(1);
                     let entry = owning_ref_from_ptr!(self.table, UTabEntry, ptr);
 
                     Some(entry)
@@ -316,12 +335,14 @@ fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
                         );
 
                         self.have_iterators_met = true;
+                        self.remaining = 0;
 
                         return None;
                     } else {
                         log::debug!("UTabIter::nth_back got {i}th table entry from the back");
 
                         self.bwd_cursor = ptr;
+                        self.remaining = self.remaining.saturating_sub(1);
                     }
                 }
                 1 => {
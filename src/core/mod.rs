@@ -15,6 +15,7 @@
 pub mod flags;
 pub mod fs;
 pub mod iter;
+pub mod optmap;
 pub mod optstring;
 pub mod utils;
 pub mod version;
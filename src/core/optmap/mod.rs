@@ -0,0 +1,44 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Built-in maps of mount options recognized by `libmount`.
+//!
+//! An [`OptionMap`] associates each mount option name with the flag it sets, and a set of
+//! [`OptionFilter`](crate::core::optstring::OptionFilter)s describing how `libmount` treats it.
+//!
+//! # Examples
+//!
+//! ```
+//! # use pretty_assertions::assert_eq;
+//! use rsmount::optmap::OptionMap;
+//! use rsmount::optstring::OptionFilter;
+//!
+//! fn main() -> rsmount::Result<()> {
+//!     let map = OptionMap::linux();
+//!
+//!     let exec = map.lookup("exec").unwrap();
+//!     let noexec = map.lookup("noexec").unwrap();
+//!
+//!     // Both options refer to the same flag...
+//!     assert_eq!(exec.id(), noexec.id());
+//!
+//!     // ...but `exec` clears it.
+//!     assert!(exec.has_filter(OptionFilter::Negated));
+//!     assert!(!noexec.has_filter(OptionFilter::Negated));
+//!
+//!     Ok(())
+//! }
+//! ```
+
+// From dependency library
+
+// From standard library
+
+// From this library
+pub use option_map_entry_struct::OptionMapEntry;
+pub use option_map_iter_struct::OptionMapIter;
+pub use option_map_struct::OptionMap;
+
+mod option_map_entry_struct;
+mod option_map_iter_struct;
+mod option_map_struct;
@@ -0,0 +1,79 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::optstring::OptionFilter;
+
+/// An entry in an [`OptionMap`](crate::core::optmap::OptionMap).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OptionMapEntry {
+    name: String,
+    id: u64,
+    mask: i32,
+}
+
+impl OptionMapEntry {
+    #[doc(hidden)]
+    /// Creates a new `OptionMapEntry`.
+    pub(crate) fn new(name: String, id: u64, mask: i32) -> OptionMapEntry {
+        Self { name, id, mask }
+    }
+
+    /// Returns the option's name, stripped of the value syntax used in the map (e.g. `offset` for
+    /// `offset=`, `user` for `user[=]`).
+    pub fn name(&self) -> &str {
+        self.name.trim_end_matches("[=]").trim_end_matches('=')
+    }
+
+    /// Returns the option's name as it appears in the map, including any value syntax (e.g.
+    /// `offset=`, `user[=]`).
+    pub fn raw_name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns `true` if the option requires a value (e.g. `offset=`).
+    pub fn requires_value(&self) -> bool {
+        self.name.ends_with('=') && !self.name.ends_with("[=]")
+    }
+
+    /// Returns the flag set (or cleared, for [`OptionFilter::Negated`] options) by this option.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns `true` if this option matches the given `filter`.
+    pub fn has_filter(&self, filter: OptionFilter) -> bool {
+        let filter: i32 = filter.into();
+
+        self.mask & filter != 0
+    }
+
+    /// Returns the list of [`OptionFilter`]s matching this option.
+    pub fn filters(&self) -> Vec<OptionFilter> {
+        [
+            OptionFilter::Negated,
+            OptionFilter::Prefixed,
+            OptionFilter::NotInMountInfo,
+            OptionFilter::NotForMountHelpers,
+            #[cfg(mount = "v2_39")]
+            OptionFilter::FsIo,
+        ]
+        .into_iter()
+        .filter(|&filter| self.has_filter(filter))
+        .collect()
+    }
+
+    #[doc(hidden)]
+    /// Returns `true` if this entry describes the option `name`.
+    pub(crate) fn matches(&self, name: &str) -> bool {
+        if self.has_filter(OptionFilter::Prefixed) {
+            name.starts_with(self.name())
+        } else {
+            name == self.name()
+        }
+    }
+}
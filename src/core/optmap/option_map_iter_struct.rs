@@ -0,0 +1,54 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::optmap::OptionMap;
+use crate::core::optmap::OptionMapEntry;
+use crate::ffi_utils;
+
+/// Iterator over the entries of an [`OptionMap`].
+#[derive(Debug)]
+pub struct OptionMapIter {
+    cursor: *const libmount::libmnt_optmap,
+}
+
+impl OptionMapIter {
+    #[doc(hidden)]
+    /// Creates a new `OptionMapIter`.
+    pub(crate) fn new(map: &OptionMap) -> OptionMapIter {
+        Self { cursor: map.inner }
+    }
+}
+
+impl Iterator for OptionMapIter {
+    type Item = OptionMapEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        log::debug!("OptionMapIter::next getting next option map entry");
+
+        if self.cursor.is_null() {
+            return None;
+        }
+
+        let entry = unsafe { &*self.cursor };
+
+        // Option maps end with an entry without name.
+        if entry.name.is_null() {
+            log::debug!("OptionMapIter::next reached the end of the option map");
+            self.cursor = std::ptr::null();
+
+            return None;
+        }
+
+        let name = ffi_utils::c_char_array_to_string(entry.name);
+        let item = OptionMapEntry::new(name, entry.id as u32 as u64, entry.mask);
+
+        self.cursor = unsafe { self.cursor.add(1) };
+
+        Some(item)
+    }
+}
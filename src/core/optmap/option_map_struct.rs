@@ -0,0 +1,133 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::optmap::OptionMapEntry;
+use crate::core::optmap::OptionMapIter;
+
+/// A built-in map of mount options.
+#[derive(Clone, Copy, Debug)]
+pub struct OptionMap {
+    pub(crate) inner: *const libmount::libmnt_optmap,
+}
+
+impl OptionMap {
+    #[doc(hidden)]
+    /// Returns the built-in option map matching `id`.
+    fn builtin(id: i32) -> OptionMap {
+        let inner = unsafe { libmount::mnt_get_builtin_optmap(id) };
+
+        Self { inner }
+    }
+
+    /// Returns the map of file system independent options (e.g. `ro`, `noexec`, `bind`) passed to
+    /// the [`mount` syscall](https://www.man7.org/linux/man-pages/man2/mount.2.html) as flags.
+    pub fn linux() -> OptionMap {
+        log::debug!("OptionMap::linux getting built-in Linux option map");
+
+        Self::builtin(libmount::MNT_LINUX_MAP as i32)
+    }
+
+    /// Returns the map of userspace options (e.g. `noauto`, `nofail`, `x-*`) interpreted by
+    /// `libmount` and mount helpers, but never sent to the kernel.
+    pub fn userspace() -> OptionMap {
+        log::debug!("OptionMap::userspace getting built-in userspace option map");
+
+        Self::builtin(libmount::MNT_USERSPACE_MAP as i32)
+    }
+
+    /// Searches the map for the entry matching the option `name`. If `name` has a value (e.g.
+    /// `offset=1024`), only its name part is used for the search.
+    pub fn lookup(&self, name: &str) -> Option<OptionMapEntry> {
+        let name = name.split_once('=').map_or(name, |(name, _)| name);
+        log::debug!("OptionMap::lookup searching entry for option {:?}", name);
+
+        self.iter().find(|entry| entry.matches(name))
+    }
+
+    /// Returns an iterator over the entries in this map.
+    pub fn iter(&self) -> OptionMapIter {
+        OptionMapIter::new(self)
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use crate::core::optstring::OptionFilter;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn option_map_can_look_up_an_option_in_the_linux_map() {
+        let map = OptionMap::linux();
+
+        let noexec = map.lookup("noexec").unwrap();
+
+        let actual = noexec.name();
+        let expected = "noexec";
+        assert_eq!(actual, expected);
+
+        let actual = noexec.id();
+        let expected = libc::MS_NOEXEC;
+        assert_eq!(actual, expected);
+
+        let actual = noexec.has_filter(OptionFilter::Negated);
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        let exec = map.lookup("exec").unwrap();
+
+        let actual = exec.id();
+        let expected = libc::MS_NOEXEC;
+        assert_eq!(actual, expected);
+
+        let actual = exec.filters();
+        let expected = vec![OptionFilter::Negated];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn option_map_can_look_up_an_option_in_the_userspace_map() {
+        let map = OptionMap::userspace();
+
+        let actual = map.lookup("noauto").map(|entry| entry.id());
+        let expected = Some(libmount::MNT_MS_NOAUTO as u64);
+        assert_eq!(actual, expected);
+
+        let entry = map.lookup("x-systemd.automount").unwrap();
+
+        let actual = entry.has_filter(OptionFilter::Prefixed);
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        let actual = map.lookup("offset=1024").map(|entry| entry.id());
+        let expected = Some(libmount::MNT_MS_OFFSET as u64);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn option_map_can_not_look_up_an_unknown_option() {
+        let actual = OptionMap::linux().lookup("size");
+        assert!(actual.is_none());
+
+        let actual = OptionMap::userspace().lookup("noexec");
+        assert!(actual.is_none());
+    }
+
+    #[test]
+    fn option_map_can_iterate_over_its_entries() {
+        let names: Vec<_> = OptionMap::linux()
+            .iter()
+            .map(|entry| entry.name().to_owned())
+            .collect();
+
+        assert!(names.contains(&"ro".to_owned()));
+        assert!(names.contains(&"rw".to_owned()));
+        assert!(!names.contains(&"noauto".to_owned()));
+    }
+}
@@ -6,6 +6,8 @@
 // From dependency library
 
 // From standard library
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::mem::MaybeUninit;
 
@@ -14,11 +16,20 @@
 use crate::core::flags::MountFlag;
 use crate::core::flags::UserspaceMountFlag;
 use crate::ffi_utils;
+use crate::tables::MountOption;
 pub use option_filter_enum::OptionFilter;
 pub use option_iter_struct::OptionIter;
+pub use option_scanner_struct::OptionScanner;
+pub use options_diff_struct::OptionsDiff;
+pub use owned_option_iter_struct::OwnedOptionIter;
+pub use split_options_struct::SplitOptions;
 
 mod option_filter_enum;
 mod option_iter_struct;
+mod option_scanner_struct;
+mod options_diff_struct;
+mod owned_option_iter_struct;
+mod split_options_struct;
 
 /// Returns a new list of mount options with `option_name=options_value,` prepended to it, or `None` if
 /// an error occurred.
@@ -399,7 +410,10 @@ pub fn remove_option(options_list: &str, option_name: &str) -> Option<String> {
 ///
 /// **Warning:** some option values might contain commas, in which case the value has to be
 /// properly quoted. Otherwise, the function will interpret the commas as a separator between mount
-/// options.
+/// options. Inside a quoted value, a double quote preceded by a backslash (e.g. `"a\"b"`) does not
+/// end the value.
+///
+/// The value returned is a slice of `options_list`, quotes and escape characters included.
 ///
 /// # Examples
 ///
@@ -446,60 +460,112 @@ pub fn option_value<'a>(options_list: &'a str, option_name: &str) -> Option<&'a
         options_list
     );
 
-    let options_list_cstr = ffi_utils::as_ref_str_to_c_string(options_list).ok()?;
-    let option_name_cstr = ffi_utils::as_ref_str_to_c_string(option_name).ok()?;
-
-    let mut start_ptr = MaybeUninit::<*mut libc::c_char>::zeroed();
-    let mut size_ptr = MaybeUninit::<usize>::zeroed();
-
-    let result = unsafe {
-        libmount::mnt_optstr_get_option(
-            options_list_cstr.as_ptr(),
-            option_name_cstr.as_ptr(),
-            start_ptr.as_mut_ptr(),
-            size_ptr.as_mut_ptr(),
-        )
-    };
-
-    match result {
-        0 => match unsafe { start_ptr.assume_init() } {
-            ptr if ptr.is_null() => {
-                log::debug!("core::optstring::option_value option {:?} does not have a value. libmount::mnt_optstr_get_option returned a NULL pointer", option_name);
-
-                None
-            }
-            ptr => {
-                let size = unsafe { size_ptr.assume_init() };
-                let start = unsafe { ptr.offset_from(options_list_cstr.as_ptr()) as usize };
-                let end = start + size;
+    match scan_options(options_list).find(|(name, _)| *name == option_name) {
+        Some((_, Some(value))) => {
+            log::debug!(
+                "core::optstring::option_value option {:?} has value: {:?}",
+                option_name,
+                value
+            );
 
-                let value = &options_list[start..end];
-                log::debug!(
-                    "core::optstring::option_value option {:?} has value: {:?}",
-                    option_name,
-                    value
-                );
+            Some(value)
+        }
+        Some((_, None)) => {
+            log::debug!(
+                "core::optstring::option_value option {:?} does not have a value",
+                option_name
+            );
 
-                Some(value)
-            }
-        },
-        1 => {
+            None
+        }
+        None => {
             let err_msg = format!(
                 "did not find the value of option {:?} in the options list {:?}",
                 option_name, options_list
             );
-            log::debug!("core::optstring::option_value {}. libmount::mnt_optstr_get_option returned error code 1", err_msg);
+            log::debug!("core::optstring::option_value {}", err_msg);
 
             None
         }
-        code => {
-            let err_msg = format!(
-                "failed to find option {:?} in options list {:?}",
-                option_name, options_list
+    }
+}
+
+#[doc(hidden)]
+/// Splits a list of mount options into `(name, value)` pairs of slices borrowed from the list.
+///
+/// Commas inside double quotes do not separate options, and a character preceded by a backslash
+/// is taken literally. `libmount`'s own parser does not support escaped quotes, a value like
+/// `"a\"b"` would swallow the options following it.
+fn scan_options(options_list: &str) -> impl Iterator<Item = (&str, Option<&str>)> {
+    let mut rest = options_list;
+
+    std::iter::from_fn(move || loop {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut quoted = false;
+        let mut escaped = false;
+        let mut end = rest.len();
+
+        for (i, byte) in rest.bytes().enumerate() {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => quoted = !quoted,
+                b',' if !quoted => {
+                    end = i;
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        let option = &rest[..end];
+        rest = rest.get(end + 1..).unwrap_or_default();
+
+        // Skip empty items, e.g. in `ro,,noatime`.
+        if option.is_empty() {
+            continue;
+        }
+
+        let pair = match option.split_once('=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (option, None),
+        };
+
+        return Some(pair);
+    })
+}
+
+#[doc(hidden)]
+/// Parses a list of mount options into [`MountOption`]s with [`iter_options`], removing the
+/// double quotes around quoted values.
+///
+/// Parsing stops at a malformed option (e.g. one with an unbalanced double quote), only the
+/// options preceding it are returned.
+pub(crate) fn parse_options(options_list: &str) -> Vec<MountOption> {
+    match iter_options(options_list) {
+        Ok(options) => options
+            .map(|option| {
+                let value = option.value().map(|value| {
+                    value
+                        .strip_prefix('"')
+                        .and_then(|v| v.strip_suffix('"'))
+                        .unwrap_or(value)
+                });
+
+                MountOption::new(option.name(), value)
+            })
+            .collect(),
+        Err(e) => {
+            log::debug!(
+                "core::optstring::parse_options failed to parse options list {:?}. {:?}",
+                options_list,
+                e
             );
-            log::debug!("core::optstring::option_value {}. libmount::mnt_optstr_get_option returned error code {:?}", err_msg, code);
 
-            None
+            vec![]
         }
     }
 }
@@ -788,6 +854,163 @@ pub fn find_userspace_mount_flags(options_list: &str) -> Option<HashSet<Userspac
     get_flags(options_list, option_map).and_then(flags_to_userspace_mount_flags)
 }
 
+#[doc(hidden)]
+/// Rewrites an options list to match the given bit `flags`, using the provided option map.
+fn apply_flags(
+    options_list: &str,
+    flags: libc::c_ulong,
+    option_map: *const libmount::libmnt_optmap,
+) -> Option<String> {
+    let mut options_list_c_copy = ffi_utils::as_ref_str_to_owned_c_char_array(options_list).ok()?;
+
+    let result = unsafe {
+        libmount::mnt_optstr_apply_flags(options_list_c_copy.as_mut_ptr(), flags, option_map)
+    };
+
+    let ptr = unsafe { options_list_c_copy.assume_init() };
+
+    match result {
+        0 => {
+            log::debug!(
+                "optstring::apply_flags applied flags {:x} to list {:?}",
+                flags,
+                options_list
+            );
+
+            let new_list = ffi_utils::c_char_array_to_string(ptr);
+            // mnt_optstr_apply_flags reallocates the options list. We need to free it to avoid a
+            // memory leak.
+            unsafe {
+                libc::free(ptr as *mut _);
+            }
+
+            Some(new_list)
+        }
+        code => {
+            let err_msg = format!(
+                "failed to apply flags {:x} to list {:?}",
+                flags, options_list
+            );
+            log::debug!(
+                "optstring::apply_flags {}. mnt_optstr_apply_flags returned error code {:?}",
+                err_msg,
+                code
+            );
+
+            // Free the memory allocated for the C copy of the options list.
+            unsafe {
+                libc::free(ptr as *mut _);
+            }
+
+            None
+        }
+    }
+}
+
+/// Returns a new list of options with the options matching `flags` added (if `add` is `true`) or
+/// removed (if `add` is `false`), or `None` if an error occurred.
+///
+/// **Note:** the `ro`/`rw` option is always placed at the beginning of the resulting list, and
+/// new options are appended at the end.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use std::collections::HashSet;
+/// use rsmount::flags::MountFlag;
+/// use rsmount::optstring;
+///
+/// fn main() -> rsmount::Result<()> {
+///     let options_list = "rw,relatime";
+///     let flags = HashSet::from([MountFlag::Remount, MountFlag::ReadOnly]);
+///
+///     let actual = optstring::apply_mount_flags(options_list, &flags, true);
+///     let options = "ro,relatime,remount".to_owned();
+///     let expected = Some(options);
+///     assert_eq!(actual, expected);
+///
+///     let options_list = "ro,noexec,relatime";
+///     let flags = HashSet::from([MountFlag::NoExecute]);
+///
+///     let actual = optstring::apply_mount_flags(options_list, &flags, false);
+///     let options = "ro,relatime".to_owned();
+///     let expected = Some(options);
+///     assert_eq!(actual, expected);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn apply_mount_flags(
+    options_list: &str,
+    flags: &HashSet<MountFlag>,
+    add: bool,
+) -> Option<String> {
+    log::debug!(
+        "optstring::apply_mount_flags {} flags {:?} to list {:?}",
+        if add { "adding" } else { "removing" },
+        flags,
+        options_list
+    );
+
+    let option_map = unsafe { libmount::mnt_get_builtin_optmap(libmount::MNT_LINUX_MAP as i32) };
+
+    let current = get_flags(options_list, option_map)?;
+    let bits = flags
+        .iter()
+        .fold(0, |acc, &flag| acc | flag as libc::c_ulong);
+    let new_flags = if add { current | bits } else { current & !bits };
+
+    apply_flags(options_list, new_flags, option_map)
+}
+
+/// Returns a new list of options with the options matching the userspace `flags` added (if
+/// `add` is `true`) or removed (if `add` is `false`), or `None` if an error occurred.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use std::collections::HashSet;
+/// use rsmount::flags::UserspaceMountFlag;
+/// use rsmount::optstring;
+///
+/// fn main() -> rsmount::Result<()> {
+///     let options_list = "noatime,noauto";
+///     let flags = HashSet::from([UserspaceMountFlag::NoAuto]);
+///
+///     let actual = optstring::apply_userspace_mount_flags(options_list, &flags, false);
+///     let options = "noatime".to_owned();
+///     let expected = Some(options);
+///     assert_eq!(actual, expected);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn apply_userspace_mount_flags(
+    options_list: &str,
+    flags: &HashSet<UserspaceMountFlag>,
+    add: bool,
+) -> Option<String> {
+    log::debug!(
+        "optstring::apply_userspace_mount_flags {} flags {:?} to list {:?}",
+        if add { "adding" } else { "removing" },
+        flags,
+        options_list
+    );
+
+    let option_map =
+        unsafe { libmount::mnt_get_builtin_optmap(libmount::MNT_USERSPACE_MAP as i32) };
+
+    let current = get_flags(options_list, option_map)?;
+    let bits = flags
+        .iter()
+        .fold(0, |acc, &flag| acc | flag as libc::c_ulong);
+    let new_flags = if add { current | bits } else { current & !bits };
+
+    apply_flags(options_list, new_flags, option_map)
+}
+
 macro_rules! unwrap_or_return {
     ($e:expr, $ret:expr) => {
         match $e {
@@ -913,6 +1136,59 @@ pub fn iter_options(options_list: &str) -> Result<OptionIter, OptionIterError> {
     OptionIter::new(options_list)
 }
 
+/// Returns an iterator over the options in the given `options_list`, taking ownership of the list.
+///
+/// Unlike [`iter_options`], the iterator returned does not borrow its options list.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use rsmount::tables::MountOption;
+/// use rsmount::optstring;
+///
+/// fn compute_options() -> String {
+///     ["noatime", "ro=recursive"].join(",")
+/// }
+///
+/// fn main() -> rsmount::Result<()> {
+///     let actual: Vec<_> = optstring::iter_options_owned(compute_options())?.collect();
+///     let expected: Vec<MountOption> = vec!["noatime".parse()?, "ro=recursive".parse()?];
+///     assert_eq!(actual, expected);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn iter_options_owned(options_list: String) -> Result<OwnedOptionIter, OptionIterError> {
+    OwnedOptionIter::new(options_list)
+}
+
+/// Returns a scanner over the options in the given `options_list`, yielding `(name, value)` pairs
+/// of string slices borrowed from the list.
+///
+/// Unlike [`iter_options`], this function does not allocate a [`MountOption`](crate::tables::MountOption)
+/// per option, which makes it better suited for read-only scanning of long lists.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use rsmount::optstring;
+///
+/// fn main() -> rsmount::Result<()> {
+///     let options_list = "ro=,noatime,mode=1777";
+///
+///     let actual: Vec<_> = optstring::scan(options_list)?.collect();
+///     let expected = vec![("ro", Some("")), ("noatime", None), ("mode", Some("1777"))];
+///     assert_eq!(actual, expected);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn scan(options_list: &str) -> Result<OptionScanner, OptionIterError> {
+    OptionScanner::new(options_list)
+}
+
 #[doc(hidden)]
 /// Extracts options from the options list that match mount flags/userspace mount flags.
 fn get_options(
@@ -1149,45 +1425,353 @@ pub fn take_fs_specific_options(options_list: &str) -> Option<String> {
     }
 }
 
-#[cfg(test)]
-#[allow(unused_imports)]
-mod tests {
-    use super::*;
-    use pretty_assertions::{assert_eq, assert_ne};
-
-    #[test]
-    fn prepend_option_does_not_prepend_an_option_with_both_an_empty_name_and_value() {
-        let option_name = "";
-        let option_value = "";
-
-        let options_list = "";
-
-        let actual = prepend_option(options_list, option_name, option_value);
-        let options = String::new();
-        let expected = Some(options);
-        assert_eq!(actual, expected);
+#[doc(hidden)]
+/// Converts a C string allocated by `libmount` to an owned `String`, then frees it. Returns `None`
+/// for a NULL pointer.
+fn take_c_string(ptr: *mut libc::c_char) -> Option<String> {
+    if ptr.is_null() {
+        None
+    } else {
+        let options = ffi_utils::c_char_array_to_string(ptr);
 
-        let options_list = "noatime";
+        // ptr points to memory allocated by `mnt_split_optstr`, we free it here to avoid a leak.
+        unsafe {
+            libc::free(ptr as *mut _);
+        }
 
-        let actual = prepend_option(options_list, option_name, option_value);
-        let options = "noatime".to_owned();
-        let expected = Some(options);
-        assert_eq!(actual, expected);
+        Some(options)
     }
+}
 
-    #[test]
-    fn prepend_option_does_not_prepend_an_option_with_an_empty_name() {
-        let option_name = "";
-        let option_value = "recursive";
+/// Splits a list of mount options into file system independent, file system specific, and
+/// userspace options in a single pass. Returns `None` if an error occurred.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use rsmount::optstring;
+///
+/// fn main() -> rsmount::Result<()> {
+///     let options_list = "noowner,protect,sync,noauto,verbose,rw,lazytime";
+///
+///     let options = optstring::split(options_list).unwrap();
+///
+///     assert_eq!(options.vfs(), Some("sync,rw,lazytime"));
+///     assert_eq!(options.fs(), Some("protect,verbose"));
+///     assert_eq!(options.user(), Some("noowner,noauto"));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn split(options_list: &str) -> Option<SplitOptions> {
+    log::debug!(
+        "optstring::split splitting options list: {:?}",
+        options_list
+    );
 
-        let options_list = "";
+    let options_list_cstr = ffi_utils::as_ref_str_to_c_string(options_list).ok()?;
+    let mut user_ptr = MaybeUninit::<*mut libc::c_char>::zeroed();
+    let mut vfs_ptr = MaybeUninit::<*mut libc::c_char>::zeroed();
+    let mut fs_ptr = MaybeUninit::<*mut libc::c_char>::zeroed();
 
-        let actual = prepend_option(options_list, option_name, option_value);
-        let options = String::new();
-        let expected = Some(options);
-        assert_eq!(actual, expected);
+    let result = unsafe {
+        libmount::mnt_split_optstr(
+            options_list_cstr.as_ptr(),
+            user_ptr.as_mut_ptr(),
+            vfs_ptr.as_mut_ptr(),
+            fs_ptr.as_mut_ptr(),
+            0,
+            0,
+        )
+    };
 
-        let options_list = "noatime";
+    let user = take_c_string(unsafe { user_ptr.assume_init() });
+    let vfs = take_c_string(unsafe { vfs_ptr.assume_init() });
+    let fs = take_c_string(unsafe { fs_ptr.assume_init() });
+
+    match result {
+        0 => {
+            log::debug!(
+                "optstring::split split options into vfs: {:?}, fs: {:?}, user: {:?}",
+                vfs,
+                fs,
+                user
+            );
+
+            Some(SplitOptions::new(vfs, fs, user))
+        }
+        code => {
+            let err_msg = format!("failed to split options list {:?}", options_list);
+            log::debug!(
+                "optstring::split {}. mnt_split_optstr returned error code: {:?}",
+                err_msg,
+                code
+            );
+
+            None
+        }
+    }
+}
+
+#[doc(hidden)]
+/// Pairs of mutually exclusive mount options.
+const OPPOSITE_OPTIONS: [(&str, &str); 15] = [
+    ("async", "sync"),
+    ("atime", "noatime"),
+    ("auto", "noauto"),
+    ("dev", "nodev"),
+    ("diratime", "nodiratime"),
+    ("exec", "noexec"),
+    ("iversion", "noiversion"),
+    ("lazytime", "nolazytime"),
+    ("mand", "nomand"),
+    ("relatime", "norelatime"),
+    ("ro", "rw"),
+    ("strictatime", "nostrictatime"),
+    ("suid", "nosuid"),
+    ("symfollow", "nosymfollow"),
+    ("user", "nouser"),
+];
+
+#[doc(hidden)]
+/// Returns the option cancelling the option named `name`, if there is one (e.g. `rw` for `ro`).
+fn opposite_option(name: &str) -> Option<&'static str> {
+    OPPOSITE_OPTIONS.iter().find_map(|&(option, opposite)| {
+        if name == option {
+            Some(opposite)
+        } else if name == opposite {
+            Some(option)
+        } else {
+            None
+        }
+    })
+}
+
+/// Returns a canonical form of a list of mount options, for example to compare lists of options
+/// regardless of the order in which they are written.
+///
+/// Options are grouped in the following order: file system independent options, file system
+/// specific options, then userspace options (see [`split`]). Within each group, options are sorted
+/// by name. When an option appears more than once, only its last occurrence is kept. Likewise,
+/// when a list holds contradictory options (e.g. `rw,ro`), only the last one is kept, preserving
+/// the meaning of the list. Quoted values are preserved as is.
+///
+/// **Note:** this function assumes that the order of options in a list is not significant, apart
+/// from the last occurrence of an option overriding the previous ones, or its opposite.
+///
+/// Returns `options_list` unchanged if it can not be parsed.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use rsmount::optstring;
+///
+/// fn main() -> rsmount::Result<()> {
+///     let actual = optstring::canonicalize("rw,relatime,nosuid");
+///     let expected = optstring::canonicalize("nosuid,relatime,rw");
+///     assert_eq!(actual, expected);
+///
+///     let options_list = "nofail,mode=1777,noexec,mode=755,nodev";
+///
+///     let actual = optstring::canonicalize(options_list);
+///     let expected = "nodev,noexec,mode=755,nofail";
+///     assert_eq!(actual, expected);
+///
+///     // Contradictory options resolve to the last one.
+///     let actual = optstring::canonicalize("rw,nosuid,ro");
+///     let expected = "nosuid,ro";
+///     assert_eq!(actual, expected);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn canonicalize(options_list: &str) -> String {
+    log::debug!(
+        "optstring::canonicalize canonicalizing options list: {:?}",
+        options_list
+    );
+
+    let Some(options) = split(options_list) else {
+        log::debug!(
+            "optstring::canonicalize failed to split options list {:?}. Returning it unchanged",
+            options_list
+        );
+
+        return options_list.to_owned();
+    };
+
+    let mut canonical: Vec<String> = vec![];
+
+    for group in [options.vfs(), options.fs(), options.user()]
+        .into_iter()
+        .flatten()
+    {
+        let Ok(scanner) = scan(group) else {
+            return options_list.to_owned();
+        };
+
+        let mut sorted: BTreeMap<&str, Option<&str>> = BTreeMap::new();
+
+        for (name, value) in scanner {
+            // Later occurrences of an option, or of its opposite, override earlier ones.
+            if let Some(opposite) = opposite_option(name) {
+                sorted.remove(opposite);
+            }
+
+            sorted.insert(name, value);
+        }
+
+        canonical.extend(sorted.into_iter().map(|(name, value)| match value {
+            Some(value) => format!("{}={}", name, value),
+            None => name.to_owned(),
+        }));
+    }
+
+    let canonical = canonical.join(",");
+    log::debug!(
+        "optstring::canonicalize canonical options list: {:?}",
+        canonical
+    );
+
+    canonical
+}
+
+/// Computes the differences between an `old` and a `new` list of mount options, for example to
+/// find out which options to pass to a remount.
+///
+/// Options are compared by name. An option present in both lists with different values is
+/// reported as changed. When an option appears more than once in a list, only its last occurrence
+/// is considered. A list that can not be parsed is treated as empty.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use rsmount::tables::MountOption;
+/// use rsmount::optstring;
+///
+/// fn main() -> rsmount::Result<()> {
+///     let diff = optstring::diff("rw,relatime", "ro,relatime,nosuid");
+///
+///     let actual = diff.added();
+///     let expected: Vec<MountOption> = vec!["ro".parse()?, "nosuid".parse()?];
+///     assert_eq!(actual, expected);
+///
+///     let actual = diff.removed();
+///     let expected: Vec<MountOption> = vec!["rw".parse()?];
+///     assert_eq!(actual, expected);
+///
+///     assert!(diff.changed().is_empty());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn diff(old: &str, new: &str) -> OptionsDiff {
+    log::debug!(
+        "optstring::diff computing differences between {:?} and {:?}",
+        old,
+        new
+    );
+
+    let old_options: Vec<MountOption> = iter_options(old)
+        .map(|iter| iter.collect())
+        .unwrap_or_default();
+    let new_options: Vec<MountOption> = iter_options(new)
+        .map(|iter| iter.collect())
+        .unwrap_or_default();
+
+    // Later occurrences of an option override earlier ones.
+    let old_values: HashMap<&str, Option<&str>> = old_options
+        .iter()
+        .map(|option| (option.name(), option.value()))
+        .collect();
+    let new_values: HashMap<&str, Option<&str>> = new_options
+        .iter()
+        .map(|option| (option.name(), option.value()))
+        .collect();
+
+    let mut added = vec![];
+    let mut changed = vec![];
+    let mut seen = HashSet::new();
+
+    for option in new_options
+        .iter()
+        .filter(|option| seen.insert(option.name()))
+    {
+        let name = option.name();
+        let new_value = new_values[name];
+
+        match old_values.get(name) {
+            None => added.push(MountOption::new(name, new_value)),
+            Some(&old_value) if old_value != new_value => changed.push((
+                MountOption::new(name, old_value),
+                MountOption::new(name, new_value),
+            )),
+            Some(_) => {}
+        }
+    }
+
+    let mut removed = vec![];
+    let mut seen = HashSet::new();
+
+    for option in old_options
+        .iter()
+        .filter(|option| seen.insert(option.name()))
+    {
+        let name = option.name();
+
+        if !new_values.contains_key(name) {
+            removed.push(MountOption::new(name, old_values[name]));
+        }
+    }
+
+    let diff = OptionsDiff::new(added, removed, changed);
+    log::debug!("optstring::diff differences: {:?}", diff);
+
+    diff
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn prepend_option_does_not_prepend_an_option_with_both_an_empty_name_and_value() {
+        let option_name = "";
+        let option_value = "";
+
+        let options_list = "";
+
+        let actual = prepend_option(options_list, option_name, option_value);
+        let options = String::new();
+        let expected = Some(options);
+        assert_eq!(actual, expected);
+
+        let options_list = "noatime";
+
+        let actual = prepend_option(options_list, option_name, option_value);
+        let options = "noatime".to_owned();
+        let expected = Some(options);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn prepend_option_does_not_prepend_an_option_with_an_empty_name() {
+        let option_name = "";
+        let option_value = "recursive";
+
+        let options_list = "";
+
+        let actual = prepend_option(options_list, option_name, option_value);
+        let options = String::new();
+        let expected = Some(options);
+        assert_eq!(actual, expected);
+
+        let options_list = "noatime";
 
         let actual = prepend_option(options_list, option_name, option_value);
         let options = "noatime".to_owned();
@@ -1420,6 +2004,85 @@ fn option_value_can_get_a_value_from_an_option_with_value() {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn option_value_can_get_a_quoted_value_containing_commas() {
+        let option_name = "context";
+        let options_list = r#"ro,context="system_u:object_r:tmp_t:s0:c127,c456",noatime"#;
+
+        let actual = option_value(options_list, option_name);
+        let value = r#""system_u:object_r:tmp_t:s0:c127,c456""#;
+        let expected = Some(value);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn option_value_can_get_a_quoted_value_containing_an_escaped_quote() {
+        let option_name = "context";
+        let options_list = r#"ro,context="a\"b,c",noatime"#;
+
+        let actual = option_value(options_list, option_name);
+        let value = r#""a\"b,c""#;
+        let expected = Some(value);
+        assert_eq!(actual, expected);
+
+        // Options after the escaped quote are still found.
+        let actual = option_value(r#"context="a\"b",mode=1777"#, "mode");
+        let expected = Some("1777");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parse_options_removes_the_quotes_around_option_values() {
+        let options_list = r#"nodev,context="system_u:object_r:tmp_t:s0:c127,c456",mode=1777"#;
+
+        let actual = parse_options(options_list);
+        let expected = vec![
+            MountOption::new("nodev", None::<&str>),
+            MountOption::new("context", Some("system_u:object_r:tmp_t:s0:c127,c456")),
+            MountOption::new("mode", Some("1777")),
+        ];
+        assert_eq!(actual, expected);
+
+        let actual = parse_options("");
+        let expected: Vec<MountOption> = vec![];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn option_value_can_get_a_quoted_value_followed_by_a_trailing_comma() {
+        let option_name = "context";
+        let options_list = r#"ro,context="a,b","#;
+
+        let actual = option_value(options_list, option_name);
+        let value = r#""a,b""#;
+        let expected = Some(value);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn option_value_can_get_a_value_containing_whitespace() {
+        let option_name = "comment";
+        let options_list = r#"ro,comment=my disk,label="Data Disk""#;
+
+        let actual = option_value(options_list, option_name);
+        let value = "my disk";
+        let expected = Some(value);
+        assert_eq!(actual, expected);
+
+        let actual = option_value(options_list, "label");
+        let expected = Some(r#""Data Disk""#);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn option_value_returns_a_slice_of_the_options_list() {
+        let options_list = r#"ro,context="a\"b""#;
+
+        let actual = option_value(options_list, "context").map(str::as_ptr);
+        let expected = Some(options_list[11..].as_ptr());
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     #[ignore]
     fn set_option_value_can_not_set_the_value_of_an_option_not_in_the_list() {
@@ -1487,6 +2150,267 @@ fn find_mount_flags_returns_flags_matching_the_ones_in_the_list() {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn split_returns_empty_buckets_given_an_empty_options_list() {
+        let options_list = "";
+
+        let actual = split(options_list);
+        let expected = Some(SplitOptions::default());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn split_separates_all_three_categories_of_options() {
+        let options_list = "ro,noauto,size=64k,x-systemd.automount,noexec,mode=1777,nofail";
+
+        let options = split(options_list).unwrap();
+
+        let actual = options.vfs();
+        let expected = Some("ro,noexec");
+        assert_eq!(actual, expected);
+
+        let actual = options.fs();
+        let expected = Some("size=64k,mode=1777");
+        assert_eq!(actual, expected);
+
+        let actual = options.user();
+        let expected = Some("noauto,x-systemd.automount,nofail");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn split_keeps_quoted_values_intact() {
+        let options_list = r#"context="system_u:object_r:tmp_t:s0:c127,c456",nodev"#;
+
+        let options = split(options_list).unwrap();
+
+        let actual = options.vfs();
+        let expected = Some("nodev");
+        assert_eq!(actual, expected);
+
+        let actual = options.fs();
+        let expected = Some(r#"context="system_u:object_r:tmp_t:s0:c127,c456""#);
+        assert_eq!(actual, expected);
+
+        let actual = options.user();
+        let expected = None;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn canonicalize_returns_an_empty_string_for_an_empty_options_list() {
+        let actual = canonicalize("");
+        let expected = "";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn canonicalize_ignores_the_order_of_options() {
+        let actual = canonicalize("rw,relatime,nosuid");
+        let expected = canonicalize("nosuid,relatime,rw");
+        assert_eq!(actual, expected);
+
+        let actual = canonicalize("nosuid,relatime,rw");
+        let expected = "nosuid,relatime,rw";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn canonicalize_groups_options_by_category() {
+        let options_list = "x-systemd.automount,size=64k,nofail,noexec,mode=1777,ro,noauto";
+
+        let actual = canonicalize(options_list);
+        let expected = "noexec,ro,mode=1777,size=64k,noauto,nofail,x-systemd.automount";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn canonicalize_keeps_the_last_occurrence_of_an_option() {
+        let actual = canonicalize("mode=755,nodev,mode=1777,nodev");
+        let expected = "nodev,mode=1777";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn canonicalize_keeps_the_last_of_contradictory_options() {
+        // `rw,ro` mounts a file system read-only.
+        let actual = canonicalize("rw,ro");
+        let expected = "ro";
+        assert_eq!(actual, expected);
+
+        // `ro,rw` mounts a file system read-write.
+        let actual = canonicalize("ro,rw");
+        let expected = "rw";
+        assert_eq!(actual, expected);
+
+        let actual = canonicalize("noexec,nodev,exec,noauto,auto");
+        let expected = "exec,nodev,auto";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn canonicalize_keeps_quoted_values_intact() {
+        let options_list = r#"nodev,context="system_u:object_r:tmp_t:s0:c127,c456",mode=1777"#;
+
+        let actual = canonicalize(options_list);
+        let expected = r#"nodev,context="system_u:object_r:tmp_t:s0:c127,c456",mode=1777"#;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn canonicalize_is_idempotent() {
+        let options_lists = [
+            "",
+            "rw,relatime,nosuid",
+            "x-systemd.automount,size=64k,nofail,noexec,mode=1777,ro,noauto",
+            "mode=755,nodev,mode=1777,nodev",
+            "rw,nosuid,ro,suid",
+            r#"nodev,context="system_u:object_r:tmp_t:s0:c127,c456",mode=1777"#,
+        ];
+
+        for options_list in options_lists {
+            let once = canonicalize(options_list);
+
+            let actual = canonicalize(&once);
+            let expected = once;
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn diff_finds_no_differences_between_identical_lists() {
+        let actual = diff("rw,relatime", "relatime,rw").is_empty();
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn diff_finds_added_and_removed_options() -> crate::Result<()> {
+        let diff = diff("rw,relatime", "ro,relatime,nosuid");
+
+        let actual = diff.added();
+        let expected: Vec<MountOption> = vec!["ro".parse()?, "nosuid".parse()?];
+        assert_eq!(actual, expected);
+
+        let actual = diff.removed();
+        let expected: Vec<MountOption> = vec!["rw".parse()?];
+        assert_eq!(actual, expected);
+
+        let actual = diff.changed().is_empty();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn diff_finds_options_with_changed_values() -> crate::Result<()> {
+        let diff = diff("ro=,noatime,mode=755", "ro=recursive,noatime,mode=1777");
+
+        let actual = diff.changed();
+        let expected: Vec<(MountOption, MountOption)> = vec![
+            ("ro=".parse()?, "ro=recursive".parse()?),
+            ("mode=755".parse()?, "mode=1777".parse()?),
+        ];
+        assert_eq!(actual, expected);
+
+        let actual = diff.added().is_empty() && diff.removed().is_empty();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn diff_reports_an_option_gaining_a_value_as_changed() -> crate::Result<()> {
+        let diff = diff("ro", "ro=recursive");
+
+        let actual = diff.changed();
+        let expected: Vec<(MountOption, MountOption)> =
+            vec![("ro".parse()?, "ro=recursive".parse()?)];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn diff_only_considers_the_last_occurrence_of_an_option() -> crate::Result<()> {
+        let diff = diff("mode=755,mode=1777", "mode=1777");
+
+        let actual = diff.is_empty();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn apply_mount_flags_adds_options_matching_flags() {
+        let options_list = "rw,relatime";
+        let flags = HashSet::from([MountFlag::Remount, MountFlag::ReadOnly]);
+
+        let actual = apply_mount_flags(options_list, &flags, true);
+        let options = "ro,relatime,remount".to_owned();
+        let expected = Some(options);
+        assert_eq!(actual, expected);
+
+        let options_list = "rw,noexec";
+        let flags = HashSet::from([MountFlag::ReadOnly, MountFlag::NoExecute]);
+
+        let actual = apply_mount_flags(options_list, &flags, true);
+        let options = "ro,noexec".to_owned();
+        let expected = Some(options);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn apply_mount_flags_removes_options_matching_flags() {
+        let options_list = "ro,noexec,relatime";
+        let flags = HashSet::from([MountFlag::NoExecute]);
+
+        let actual = apply_mount_flags(options_list, &flags, false);
+        let options = "ro,relatime".to_owned();
+        let expected = Some(options);
+        assert_eq!(actual, expected);
+
+        let options_list = "ro,relatime";
+        let flags = HashSet::from([MountFlag::ReadOnly]);
+
+        let actual = apply_mount_flags(options_list, &flags, false);
+        let options = "rw,relatime".to_owned();
+        let expected = Some(options);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn apply_mount_flags_keeps_fs_specific_options() {
+        let options_list = "rw,size=64k,noatime";
+        let flags = HashSet::from([MountFlag::NoDeviceAccess]);
+
+        let actual = apply_mount_flags(options_list, &flags, true);
+        let options = "rw,size=64k,noatime,nodev".to_owned();
+        let expected = Some(options);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn apply_userspace_mount_flags_adds_and_removes_options_matching_flags() {
+        let options_list = "noatime";
+        let flags = HashSet::from([UserspaceMountFlag::NoAuto]);
+
+        let actual = apply_userspace_mount_flags(options_list, &flags, true);
+        let options = "noatime,noauto".to_owned();
+        let expected = Some(options);
+        assert_eq!(actual, expected);
+
+        let options_list = "noatime,noauto";
+
+        let actual = apply_userspace_mount_flags(options_list, &flags, false);
+        let options = "noatime".to_owned();
+        let expected = Some(options);
+        assert_eq!(actual, expected);
+    }
+
     // see
     // https://github.com/util-linux/util-linux/blob/stable/v2.39/libmount/src/optmap.c#L71
     // for a full list of option-mount flag mapping
@@ -79,7 +79,7 @@ fn next(&mut self) -> Option<Self::Item> {
                 let name = &self.options_list[name_start..name_end];
 
                 let option = if value_ptr.is_null() {
-                    MountOption::new(name)
+                    MountOption::without_value(name)
                 } else {
                     let value_start = unsafe { value_ptr.offset_from(self.origin) as usize };
                     let value_end = value_start + value_size;
@@ -189,4 +189,17 @@ fn option_iter_can_iterate_over_an_options_list_with_more_than_one_element() ->
 
         Ok(())
     }
+
+    #[test]
+    fn option_iter_keeps_the_quotes_around_option_values() -> crate::Result<()> {
+        let options_list = r#"nodev,context="system_u:object_r:tmp_t:s0:c127,c456""#;
+        let actual: Vec<_> = OptionIter::new(options_list)?.collect();
+        let expected = vec![
+            MountOption::new("nodev", None::<&str>),
+            MountOption::new("context", Some(r#""system_u:object_r:tmp_t:s0:c127,c456""#)),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
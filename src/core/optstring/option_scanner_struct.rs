@@ -0,0 +1,190 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::mem::MaybeUninit;
+
+// From this library
+use crate::core::errors::OptionIterError;
+use crate::ffi_utils;
+
+/// A scanner over options in a list of mount options, yielding `(name, value)` pairs of string
+/// slices borrowed from the list.
+///
+/// An option without a value (e.g. `ro`) has a value of `None`, an option with an empty value
+/// (e.g. `ro=`) has a value of `Some("")`.
+#[derive(Debug)]
+pub struct OptionScanner<'a> {
+    options_list: &'a str,
+    cursor: MaybeUninit<*mut libc::c_char>,
+    origin: *const libc::c_char,
+}
+
+impl<'a> OptionScanner<'a> {
+    #[doc(hidden)]
+    /// Creates a new `OptionScanner` instance.
+    pub(crate) fn new(options_list: &'a str) -> Result<OptionScanner<'a>, OptionIterError> {
+        log::debug!("OptionScanner::new creating a new `OptionScanner` instance");
+
+        let cursor = ffi_utils::as_ref_str_to_owned_c_char_array(options_list).map_err(|err| {
+            let err_msg = format!(
+                "failed to create new `OptionScanner` for options list: {:?}. {:?}",
+                options_list, err
+            );
+
+            OptionIterError::Creation(err_msg)
+        })?;
+
+        let origin = unsafe { *cursor.as_ptr() };
+
+        let scanner = Self {
+            options_list,
+            cursor,
+            origin,
+        };
+
+        Ok(scanner)
+    }
+}
+
+impl<'a> Iterator for OptionScanner<'a> {
+    type Item = (&'a str, Option<&'a str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        log::debug!("OptionScanner::next getting next option in option list");
+        let mut name_ptr = MaybeUninit::<*mut libc::c_char>::zeroed();
+        let mut name_size = MaybeUninit::<usize>::zeroed();
+        let mut value_ptr = MaybeUninit::<*mut libc::c_char>::zeroed();
+        let mut value_size = MaybeUninit::<usize>::zeroed();
+
+        let result = unsafe {
+            libmount::mnt_optstr_next_option(
+                self.cursor.as_mut_ptr(),
+                name_ptr.as_mut_ptr(),
+                name_size.as_mut_ptr(),
+                value_ptr.as_mut_ptr(),
+                value_size.as_mut_ptr(),
+            )
+        };
+
+        match result {
+            0 => {
+                let name_ptr = unsafe { name_ptr.assume_init() };
+                let name_size = unsafe { name_size.assume_init() };
+                let value_ptr = unsafe { value_ptr.assume_init() };
+                let value_size = unsafe { value_size.assume_init() };
+
+                let name_start = unsafe { name_ptr.offset_from(self.origin) as usize };
+                let name_end = name_start + name_size;
+
+                let name = &self.options_list[name_start..name_end];
+
+                let value = if value_ptr.is_null() {
+                    None
+                } else {
+                    let value_start = unsafe { value_ptr.offset_from(self.origin) as usize };
+                    let value_end = value_start + value_size;
+
+                    Some(&self.options_list[value_start..value_end])
+                };
+
+                Some((name, value))
+            }
+            1 => {
+                log::debug!("OptionScanner::next reached the end of options list");
+
+                None
+            }
+            code => {
+                let err_msg = format!(
+                    "failed to get next option from list: {:?}",
+                    self.options_list
+                );
+                log::debug!(
+                    "OptionScanner::next {}. mnt_optstr_next_option returned error code: {:?}",
+                    err_msg,
+                    code
+                );
+
+                None
+            }
+        }
+    }
+}
+
+impl<'a> Drop for OptionScanner<'a> {
+    fn drop(&mut self) {
+        log::debug!("OptionScanner::drop deallocating `OptionScanner` instance");
+
+        unsafe { libc::free(self.origin as *mut _) }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    #[test]
+    fn option_scanner_can_scan_an_empty_options_list() -> crate::Result<()> {
+        let options_list = "";
+        let mut scanner = OptionScanner::new(options_list)?;
+
+        let actual = scanner.next();
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn option_scanner_can_not_scan_an_options_list_with_a_nul_byte() {
+        let options_list = "ro,\0noatime";
+
+        let actual = OptionScanner::new(options_list);
+        assert!(matches!(actual, Err(OptionIterError::Creation(_))));
+    }
+
+    #[test]
+    fn option_scanner_distinguishes_empty_values_from_missing_values() -> crate::Result<()> {
+        let options_list = "ro=,noatime,mode=1777";
+        let actual: Vec<_> = OptionScanner::new(options_list)?.collect();
+        let expected = vec![("ro", Some("")), ("noatime", None), ("mode", Some("1777"))];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn option_scanner_keeps_quoted_values_containing_commas() -> crate::Result<()> {
+        let options_list = r#"nodev,context="system_u:object_r:tmp_t:s0:c127,c456",ro"#;
+        let actual: Vec<_> = OptionScanner::new(options_list)?.collect();
+        let expected = vec![
+            ("nodev", None),
+            ("context", Some(r#""system_u:object_r:tmp_t:s0:c127,c456""#)),
+            ("ro", None),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn option_scanner_yields_slices_of_the_options_list() -> crate::Result<()> {
+        let options_list = "noatime,ro=recursive";
+        let (name, value) = OptionScanner::new(options_list)?.nth(1).unwrap();
+
+        let actual = name.as_ptr();
+        let expected = options_list[8..].as_ptr();
+        assert_eq!(actual, expected);
+
+        let actual = value.map(str::as_ptr);
+        let expected = Some(options_list[11..].as_ptr());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
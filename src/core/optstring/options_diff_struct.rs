@@ -0,0 +1,55 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::tables::MountOption;
+
+/// The differences between two lists of mount options.
+///
+/// Created by [`optstring::diff`](crate::core::optstring::diff).
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct OptionsDiff {
+    added: Vec<MountOption>,
+    removed: Vec<MountOption>,
+    changed: Vec<(MountOption, MountOption)>,
+}
+
+impl OptionsDiff {
+    #[doc(hidden)]
+    /// Creates a new `OptionsDiff`.
+    pub(crate) fn new(
+        added: Vec<MountOption>,
+        removed: Vec<MountOption>,
+        changed: Vec<(MountOption, MountOption)>,
+    ) -> OptionsDiff {
+        Self {
+            added,
+            removed,
+            changed,
+        }
+    }
+
+    /// Returns the options present in the new list, but not in the old one.
+    pub fn added(&self) -> &[MountOption] {
+        &self.added
+    }
+
+    /// Returns the options present in the old list, but not in the new one.
+    pub fn removed(&self) -> &[MountOption] {
+        &self.removed
+    }
+
+    /// Returns the options present in both lists with different values, as `(old, new)` pairs.
+    pub fn changed(&self) -> &[(MountOption, MountOption)] {
+        &self.changed
+    }
+
+    /// Returns `true` if both lists hold the same options with the same values.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
@@ -0,0 +1,197 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::mem::MaybeUninit;
+
+// From this library
+use crate::core::errors::OptionIterError;
+use crate::ffi_utils;
+use crate::tables::MountOption;
+
+/// An iterator over options in a list of mount options it owns.
+///
+/// Unlike an [`OptionIter`](crate::core::optstring::OptionIter), an `OwnedOptionIter` does not
+/// borrow its options list, and can outlive the expression that produced it.
+#[derive(Debug)]
+pub struct OwnedOptionIter {
+    options_list: String,
+    cursor: MaybeUninit<*mut libc::c_char>,
+    origin: *const libc::c_char,
+}
+
+impl OwnedOptionIter {
+    #[doc(hidden)]
+    /// Creates a new `OwnedOptionIter` instance.
+    pub(crate) fn new(options_list: String) -> Result<OwnedOptionIter, OptionIterError> {
+        log::debug!("OwnedOptionIter::new creating a new `OwnedOptionIter` instance");
+
+        let cursor = ffi_utils::as_ref_str_to_owned_c_char_array(&options_list).map_err(|err| {
+            let err_msg = format!(
+                "failed to create new `OwnedOptionIter` for options list: {:?}. {:?}",
+                options_list, err
+            );
+
+            OptionIterError::Creation(err_msg)
+        })?;
+
+        let origin = unsafe { *cursor.as_ptr() };
+
+        let iterator = Self {
+            options_list,
+            cursor,
+            origin,
+        };
+
+        Ok(iterator)
+    }
+
+    /// Returns the list of mount options this iterator goes through.
+    pub fn options_list(&self) -> &str {
+        &self.options_list
+    }
+}
+
+impl Iterator for OwnedOptionIter {
+    type Item = MountOption;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        log::debug!("OwnedOptionIter::next getting next option in option list");
+        let mut name_ptr = MaybeUninit::<*mut libc::c_char>::zeroed();
+        let mut name_size = MaybeUninit::<usize>::zeroed();
+        let mut value_ptr = MaybeUninit::<*mut libc::c_char>::zeroed();
+        let mut value_size = MaybeUninit::<usize>::zeroed();
+
+        let result = unsafe {
+            libmount::mnt_optstr_next_option(
+                self.cursor.as_mut_ptr(),
+                name_ptr.as_mut_ptr(),
+                name_size.as_mut_ptr(),
+                value_ptr.as_mut_ptr(),
+                value_size.as_mut_ptr(),
+            )
+        };
+
+        match result {
+            0 => {
+                let name_ptr = unsafe { name_ptr.assume_init() };
+                let name_size = unsafe { name_size.assume_init() };
+                let value_ptr = unsafe { value_ptr.assume_init() };
+                let value_size = unsafe { value_size.assume_init() };
+
+                let name_start = unsafe { name_ptr.offset_from(self.origin) as usize };
+                let name_end = name_start + name_size;
+
+                let name = &self.options_list[name_start..name_end];
+
+                let option = if value_ptr.is_null() {
+                    MountOption::without_value(name)
+                } else {
+                    let value_start = unsafe { value_ptr.offset_from(self.origin) as usize };
+                    let value_end = value_start + value_size;
+                    let value = &self.options_list[value_start..value_end];
+
+                    MountOption::new_with_value(name, value)
+                };
+
+                Some(option)
+            }
+            1 => {
+                log::debug!("OwnedOptionIter::next reached the end of options list");
+
+                None
+            }
+            code => {
+                let err_msg = format!(
+                    "failed to get next option from list: {:?}",
+                    self.options_list
+                );
+                log::debug!(
+                    "OwnedOptionIter::next {}. mnt_optstr_next_option returned error code: {:?}",
+                    err_msg,
+                    code
+                );
+
+                None
+            }
+        }
+    }
+}
+
+impl AsRef<OwnedOptionIter> for OwnedOptionIter {
+    #[inline]
+    fn as_ref(&self) -> &OwnedOptionIter {
+        self
+    }
+}
+
+impl Drop for OwnedOptionIter {
+    fn drop(&mut self) {
+        log::debug!("OwnedOptionIter::drop deallocating `OwnedOptionIter` instance");
+
+        unsafe { libc::free(self.origin as *mut _) }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use crate::tables::MountOption;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    fn compute_options() -> String {
+        ["noatime", "ro=recursive"].join(",")
+    }
+
+    #[test]
+    fn owned_option_iter_can_iterate_over_an_empty_options_list() -> crate::Result<()> {
+        let mut iterator = OwnedOptionIter::new(String::new())?;
+
+        let actual = iterator.next();
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn owned_option_iter_can_outlive_the_expression_producing_its_options_list() -> crate::Result<()>
+    {
+        let actual: Vec<_> = OwnedOptionIter::new(compute_options())?.collect();
+        let expected: Vec<MountOption> = vec!["noatime".parse()?, "ro=recursive".parse()?];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn owned_option_iter_keeps_quoted_values_containing_commas() -> crate::Result<()> {
+        let options_list = r#"noatime,context="a,b",nodev"#.to_owned();
+        let mut iterator = OwnedOptionIter::new(options_list)?;
+
+        let actual = iterator.next();
+        let option: MountOption = "noatime".parse()?;
+        let expected = Some(option);
+        assert_eq!(actual, expected);
+
+        let actual = iterator.next();
+        let option: MountOption = r#"context="a,b""#.parse()?;
+        let expected = Some(option);
+        assert_eq!(actual, expected);
+
+        let actual = iterator.next();
+        let option: MountOption = "nodev".parse()?;
+        let expected = Some(option);
+        assert_eq!(actual, expected);
+
+        // Reached the end of the list
+        let actual = iterator.next();
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
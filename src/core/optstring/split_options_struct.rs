@@ -0,0 +1,49 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+
+/// A list of mount options split into file system independent (VFS), file system specific, and
+/// userspace options.
+///
+/// Created by [`optstring::split`](crate::core::optstring::split).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SplitOptions {
+    vfs: Option<String>,
+    fs: Option<String>,
+    user: Option<String>,
+}
+
+impl SplitOptions {
+    #[doc(hidden)]
+    /// Creates a new `SplitOptions`.
+    pub(crate) fn new(
+        vfs: Option<String>,
+        fs: Option<String>,
+        user: Option<String>,
+    ) -> SplitOptions {
+        Self { vfs, fs, user }
+    }
+
+    /// Returns the file system independent options (e.g. `ro`, `sync`, `noatime`), or `None` if
+    /// there are none.
+    pub fn vfs(&self) -> Option<&str> {
+        self.vfs.as_deref()
+    }
+
+    /// Returns the file system specific options (e.g. `size=64k` for `tmpfs`), or `None` if there
+    /// are none.
+    pub fn fs(&self) -> Option<&str> {
+        self.fs.as_deref()
+    }
+
+    /// Returns the userspace options (e.g. `noauto`, `nofail`, `x-systemd.automount`), or `None`
+    /// if there are none.
+    pub fn user(&self) -> Option<&str> {
+        self.user.as_deref()
+    }
+}
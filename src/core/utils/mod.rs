@@ -11,8 +11,12 @@
 
 // From this library
 use crate::core::cache::Cache;
+use crate::core::errors::MountInfoError;
 use crate::core::fs::FileSystem;
 use crate::ffi_utils;
+use crate::tables::FsTab;
+use crate::tables::MountInfo;
+use crate::tables::Swaps;
 
 #[doc(hidden)]
 /// Converts a device number to its corresponding name.
@@ -95,6 +99,40 @@ pub fn device_number_to_cached_device_name(device_number: u64, cache: &Cache) ->
     guess_system_root(device_number, cache.inner)
 }
 
+/// Combines a `major` and a `minor` number into a device number, using the same encoding as
+/// the GNU C Library's `makedev` function.
+///
+/// # Examples
+///
+/// ```
+/// use rsmount::utils;
+///
+/// fn main() -> rsmount::Result<()> {
+///     // /dev/sda3
+///     assert_eq!(utils::makedev(8, 3), 0x803);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn makedev(major: u32, minor: u32) -> u64 {
+    let (major, minor) = (major as u64, minor as u64);
+
+    ((major & 0xfffff000) << 32)
+        | ((major & 0x00000fff) << 8)
+        | ((minor & 0xffffff00) << 12)
+        | (minor & 0x000000ff)
+}
+
+/// Extracts the major number from a device number.
+pub fn major(device_number: u64) -> u32 {
+    (((device_number >> 32) & 0xfffff000) | ((device_number >> 8) & 0x00000fff)) as u32
+}
+
+/// Extracts the minor number from a device number.
+pub fn minor(device_number: u64) -> u32 {
+    (((device_number >> 12) & 0xffffff00) | (device_number & 0x000000ff)) as u32
+}
+
 /// Finds the mountpoint of a mounted device.
 ///
 /// For better accuracy, the given `device_path` should be in canonical form.
@@ -145,6 +183,71 @@ pub fn find_device_mountpoint<T>(device_path: T) -> Option<PathBuf>
     }
 }
 
+/// Finds the mountpoint of the file system holding the file at `path`.
+///
+/// This function canonicalizes `path`, then searches `/proc/self/mountinfo` for the mountpoint
+/// that is the longest prefix of the result. If `path` is itself a mountpoint, it is returned
+/// as is. For a file reached through a bind mount, the function returns the target of the bind
+/// mount, not the mountpoint of the original file system.
+///
+/// # Errors
+///
+/// Returns an error if `path` does not exist, or if `/proc/self/mountinfo` can not be read.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use rsmount::utils;
+///
+/// fn main() -> rsmount::Result<()> {
+///     let mount_point = utils::mount_point_of("/")?;
+///
+///     assert_eq!(mount_point, Path::new("/"));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn mount_point_of<T>(path: T) -> Result<PathBuf, MountInfoError>
+where
+    T: AsRef<Path>,
+{
+    let path = path.as_ref();
+    log::debug!("mount_point_of searching mountpoint of path {:?}", path);
+
+    let canonical_path = std::fs::canonicalize(path).map_err(|e| {
+        let err_msg = format!("failed to canonicalize path {:?}. {}", path, e);
+        log::debug!("mount_point_of {}", err_msg);
+
+        MountInfoError::Action(err_msg)
+    })?;
+
+    let mut mount_info = MountInfo::new()?;
+    mount_info.import_mountinfo()?;
+
+    let mount_point = mount_info
+        .find_mount_point_of(&canonical_path)
+        .and_then(|entry| entry.target().map(Path::to_path_buf));
+
+    match mount_point {
+        Some(mount_point) => {
+            log::debug!(
+                "mount_point_of path {:?} is on file system mounted at {:?}",
+                path,
+                mount_point
+            );
+
+            Ok(mount_point)
+        }
+        None => {
+            let err_msg = format!("found no mountpoint for path {:?}", path);
+            log::debug!("mount_point_of {}", err_msg);
+
+            Err(MountInfoError::Action(err_msg))
+        }
+    }
+}
+
 /// Encodes a `string` to a format compatible with `fstab` by escaping space, tab, new line, and
 /// backslash characters.
 pub fn fstab_encode<T>(string: T) -> Option<String>
@@ -236,6 +339,57 @@ pub fn fstab_decode<T>(string: T) -> Option<String>
     }
 }
 
+/// Normalizes a mount point the way it is stored in an `fstab` entry once parsed, so that it can
+/// be compared to the target of table entries.
+///
+/// The function decodes escaped characters (see [`fstab_decode`]), collapses consecutive slashes,
+/// and removes trailing slashes (except for the root directory `/`). It does not follow symbolic
+/// links, nor does it resolve `.` or `..` components.
+///
+/// # Examples
+///
+/// ```
+/// # use pretty_assertions::assert_eq;
+/// use rsmount::utils;
+///
+/// let actual = utils::normalize_target(r"/mnt//my\040disk/");
+/// let expected = "/mnt/my disk";
+/// assert_eq!(actual, expected);
+/// ```
+pub fn normalize_target<T>(target: T) -> String
+where
+    T: AsRef<str>,
+{
+    let target = target.as_ref();
+    log::debug!("normalize_target normalizing mount point: {:?}", target);
+
+    let decoded = fstab_decode(target).unwrap_or_else(|| target.to_owned());
+    // Removing ASCII slashes from a UTF-8 string leaves a valid UTF-8 string.
+    let normalized = String::from_utf8(collapse_slashes(decoded.as_bytes())).unwrap_or(decoded);
+
+    log::debug!("normalize_target normalized mount point: {:?}", normalized);
+
+    normalized
+}
+
+#[doc(hidden)]
+/// Collapses consecutive slashes in `path`, and removes trailing slashes (except for the root
+/// directory `/`).
+pub(crate) fn collapse_slashes(path: &[u8]) -> Vec<u8> {
+    let mut collapsed = Vec::with_capacity(path.len());
+    for &byte in path {
+        if !(byte == b'/' && collapsed.last() == Some(&b'/')) {
+            collapsed.push(byte);
+        }
+    }
+
+    if collapsed.len() > 1 && collapsed.last() == Some(&b'/') {
+        collapsed.pop();
+    }
+
+    collapsed
+}
+
 /// Returns the path to either the file system description file `fstab`, or the one set in the
 /// environment variable `LIBMOUNT_FSTAB`.
 ///
@@ -298,6 +452,84 @@ pub fn path_to_swaps() -> Option<PathBuf> {
     }
 }
 
+/// Returns a [`MountInfo`] table filled with the entries in `/proc/self/mountinfo`.
+///
+/// A shorthand for creating a new `MountInfo`, then calling [`MountInfo::import_mountinfo`].
+///
+/// # Errors
+///
+/// Returns [`RsMountError::Io`](crate::RsMountError::Io) if `/proc/self/mountinfo` can not be
+/// opened (e.g. permission denied), or the error raised while importing its entries.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use rsmount::utils;
+///
+/// fn main() -> rsmount::Result<()> {
+///     let mut mount_info = utils::read_mountinfo()?;
+///
+///     assert!(mount_info.find_target(Path::new("/")).is_some());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn read_mountinfo() -> crate::Result<MountInfo> {
+    log::debug!("read_mountinfo reading /proc/self/mountinfo");
+
+    // Surface access errors as I/O errors, instead of an opaque import error.
+    std::fs::File::open("/proc/self/mountinfo")?;
+
+    let mut mount_info = MountInfo::new()?;
+    mount_info.import_mountinfo()?;
+
+    Ok(mount_info)
+}
+
+/// Returns a [`FsTab`] table filled with the entries in `/etc/fstab`, or the file set in the
+/// environment variable `LIBMOUNT_FSTAB`.
+///
+/// A shorthand for creating a new `FsTab`, then calling [`FsTab::import_etc_fstab`].
+///
+/// # Errors
+///
+/// Returns [`RsMountError::Io`](crate::RsMountError::Io) if the `fstab` file can not be opened
+/// (e.g. permission denied), or the error raised while importing its entries.
+pub fn read_fstab() -> crate::Result<FsTab> {
+    log::debug!("read_fstab reading `fstab`");
+
+    // Surface access errors as I/O errors, instead of an opaque import error.
+    if let Some(path) = path_to_fstab() {
+        std::fs::File::open(path)?;
+    }
+
+    let mut fstab = FsTab::new()?;
+    fstab.import_etc_fstab()?;
+
+    Ok(fstab)
+}
+
+/// Returns a [`Swaps`] table filled with the entries in `/proc/swaps`.
+///
+/// A shorthand for creating a new `Swaps`, then calling [`Swaps::import_proc_swaps`].
+///
+/// # Errors
+///
+/// Returns [`RsMountError::Io`](crate::RsMountError::Io) if `/proc/swaps` can not be opened
+/// (e.g. permission denied), or the error raised while importing its entries.
+pub fn read_swaps() -> crate::Result<Swaps> {
+    log::debug!("read_swaps reading /proc/swaps");
+
+    // Surface access errors as I/O errors, instead of an opaque import error.
+    std::fs::File::open("/proc/swaps")?;
+
+    let mut swaps = Swaps::new()?;
+    swaps.import_proc_swaps()?;
+
+    Ok(swaps)
+}
+
 /// Returns `true` if the given file system type is a network file system.
 ///
 /// # Examples
@@ -315,8 +547,12 @@ pub fn path_to_swaps() -> Option<PathBuf> {
 ///     Ok(())
 /// }
 /// ```
-pub fn is_network_fs(fs_type: FileSystem) -> bool {
-    let fs_type_cstr = ffi_utils::as_ref_str_to_c_string(&fs_type);
+pub fn is_network_fs<T>(fs_type: T) -> bool
+where
+    T: AsRef<FileSystem>,
+{
+    let fs_type = fs_type.as_ref();
+    let fs_type_cstr = ffi_utils::as_ref_str_to_c_string(fs_type);
     log::debug!(
         "is_network_fs checking if {:?} is a network file system",
         fs_type
@@ -354,8 +590,12 @@ pub fn is_network_fs(fs_type: FileSystem) -> bool {
 ///     Ok(())
 /// }
 /// ```
-pub fn is_pseudo_fs(fs_type: FileSystem) -> bool {
-    let fs_type_cstr = ffi_utils::as_ref_str_to_c_string(&fs_type);
+pub fn is_pseudo_fs<T>(fs_type: T) -> bool
+where
+    T: AsRef<FileSystem>,
+{
+    let fs_type = fs_type.as_ref();
+    let fs_type_cstr = ffi_utils::as_ref_str_to_c_string(fs_type);
     log::debug!(
         "is_pseudo_fs checking if {:?} is a pseudo file system",
         fs_type
@@ -431,8 +671,132 @@ pub fn matches_fs_type(fs_type: &str, pattern: &str) -> bool {
 #[allow(unused_imports)]
 mod tests {
     use super::*;
+    use crate::tables::fixtures;
     use pretty_assertions::{assert_eq, assert_ne};
 
+    fn fixture() -> crate::Result<MountInfo> {
+        let content = [
+            fixtures::MOUNTINFO,
+            "28 21 8:4 / /home rw,relatime shared:8 - ext4 /dev/sda4 rw
+29 21 8:3 /srv/data /mnt/data rw,relatime shared:1 - ext4 /dev/sda3 rw
+",
+        ]
+        .concat();
+        let mount_info = fixtures::mount_info_from(&content)?;
+
+        Ok(mount_info)
+    }
+
+    fn mount_point_in_fixture(path: &str, mount_info: &MountInfo) -> Option<PathBuf> {
+        mount_info
+            .find_mount_point_of(path)
+            .and_then(|entry| entry.target().map(Path::to_path_buf))
+    }
+
+    #[test]
+    fn mount_point_of_finds_the_root_mountpoint() -> crate::Result<()> {
+        let actual = mount_point_of("/")?;
+        let expected = Path::new("/");
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_point_of_finds_the_mountpoint_of_a_file() -> crate::Result<()> {
+        // `/proc/self` is a symlink to `/proc/<pid>`.
+        let actual = mount_point_of("/proc/self/mountinfo")?;
+        let expected = Path::new("/proc");
+        assert_eq!(actual, expected);
+
+        let actual = mount_point_of("/proc")?;
+        let expected = Path::new("/proc");
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_point_of_can_not_find_the_mountpoint_of_a_missing_file() {
+        let actual = mount_point_of("/no/such/file");
+        assert!(matches!(actual, Err(MountInfoError::Action(_))));
+    }
+
+    #[test]
+    fn mount_point_of_finds_the_deepest_mountpoint_in_a_fixture() -> crate::Result<()> {
+        let mount_info = fixture()?;
+
+        let actual = mount_point_in_fixture("/etc/fstab", &mount_info);
+        let expected = Some(PathBuf::from("/"));
+        assert_eq!(actual, expected);
+
+        let actual = mount_point_in_fixture("/dev/pts/0", &mount_info);
+        let expected = Some(PathBuf::from("/dev/pts"));
+        assert_eq!(actual, expected);
+
+        // Components are compared as a whole.
+        let actual = mount_point_in_fixture("/homes/user", &mount_info);
+        let expected = Some(PathBuf::from("/"));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_point_of_a_mountpoint_is_itself() -> crate::Result<()> {
+        let mount_info = fixture()?;
+
+        let actual = mount_point_in_fixture("/home", &mount_info);
+        let expected = Some(PathBuf::from("/home"));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_point_of_a_file_in_a_bind_mount_is_the_bind_mount_target() -> crate::Result<()> {
+        let mount_info = fixture()?;
+
+        let actual = mount_point_in_fixture("/mnt/data/file.txt", &mount_info);
+        let expected = Some(PathBuf::from("/mnt/data"));
+        assert_eq!(actual, expected);
+
+        // Same file, reached through the original file system.
+        let actual = mount_point_in_fixture("/srv/data/file.txt", &mount_info);
+        let expected = Some(PathBuf::from("/"));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn makedev_can_encode_a_device_number() {
+        // /dev/sda3
+        let actual = makedev(8, 3);
+        let expected = 0x803;
+        assert_eq!(actual, expected);
+
+        let actual = (major(0x803), minor(0x803));
+        let expected = (8, 3);
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn makedev_can_encode_a_device_number_with_a_large_minor_number() {
+        let actual = makedev(259, 300);
+        let expected = 0x11032c;
+        assert_eq!(actual, expected);
+
+        let actual = (major(0x11032c), minor(0x11032c));
+        let expected = (259, 300);
+        assert_eq!(actual, expected);
+
+        let dev_num = makedev(0xabcde, 0x123456);
+        let actual = (major(dev_num), minor(dev_num));
+        let expected = (0xabcde, 0x123456);
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn matches_fs_type_an_empty_pattern_matches_only_an_empty_fs_type() {
         let fs_type = "";
@@ -517,4 +881,82 @@ fn matches_fs_type_negated_patterns_match() {
         let expected = true;
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn normalize_target_decodes_octal_escaped_characters() {
+        let actual = normalize_target(r"/mnt/my\040disk");
+        let expected = "/mnt/my disk";
+        assert_eq!(actual, expected);
+
+        let actual = normalize_target(r"/mnt/tab\011and\134backslash");
+        let expected = "/mnt/tab\tand\\backslash";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn normalize_target_removes_redundant_slashes() {
+        let actual = normalize_target("//mnt///data//");
+        let expected = "/mnt/data";
+        assert_eq!(actual, expected);
+
+        let actual = normalize_target("///");
+        let expected = "/";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn normalize_target_keeps_a_normalized_target_unchanged() {
+        for target in ["", "/", "/mnt/data", "none"] {
+            let actual = normalize_target(target);
+            let expected = target;
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn read_mountinfo_returns_a_table_with_the_root_mount_point() -> crate::Result<()> {
+        let mut mount_info = read_mountinfo()?;
+
+        assert!(!mount_info.is_empty());
+        assert!(mount_info.find_target("/").is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_fstab_can_read_the_fstab_file() -> crate::Result<()> {
+        if let Some(content) = path_to_fstab().and_then(|path| std::fs::read_to_string(path).ok()) {
+            let has_entries = content.lines().any(|line| {
+                let line = line.trim();
+                !line.is_empty() && !line.starts_with('#')
+            });
+
+            let fstab = read_fstab()?;
+
+            let actual = fstab.is_empty();
+            let expected = !has_entries;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_swaps_can_read_proc_swaps() -> crate::Result<()> {
+        // Skip the header line.
+        let entries = std::fs::read_to_string("/proc/swaps")
+            .unwrap()
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty())
+            .count();
+
+        let swaps = read_swaps()?;
+
+        let actual = swaps.len();
+        let expected = entries;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
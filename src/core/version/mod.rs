@@ -61,6 +61,100 @@ pub fn version_string_to_release_code<T>(version_string: T) -> Result<i32, Versi
     }
 }
 
+/// Returns the version string of the `libmount` library linked at runtime (e.g. `"2.39.4"`).
+///
+/// Unlike [`VERSION_STRING`], which is set when this crate is compiled, the value returned by this
+/// function reflects the shared library actually loaded by the running program.
+///
+/// # Examples
+///
+/// ```
+/// use rsmount::version;
+///
+/// fn main() -> rsmount::Result<()> {
+///     let version_string = version::library_version();
+///     let release_code = version::version_string_to_release_code(&version_string)?;
+///
+///     assert!(release_code > 0);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn library_version() -> String {
+    log::debug!("library_version getting runtime library version");
+
+    let mut version_ptr = MaybeUninit::<*const libc::c_char>::zeroed();
+
+    let release_code = unsafe { libmount::mnt_get_library_version(version_ptr.as_mut_ptr()) };
+    let version_ptr = unsafe { version_ptr.assume_init() };
+    let version = ffi_utils::c_char_array_to_string(version_ptr);
+
+    log::debug!(
+        "library_version value: {:?} (release code: {:?})",
+        version,
+        release_code
+    );
+
+    version
+}
+
+#[doc(hidden)]
+/// Splits a version string into its `(major, minor, patch)` components, treating missing or
+/// non-numeric components as `0` (e.g. `"2.40"` gives `(2, 40, 0)`, `"2.40-rc1"` gives `(2, 40,
+/// 0)`).
+fn version_triple(version_string: &str) -> (u32, u32, u32) {
+    let mut components = version_string.trim().splitn(3, '.').map(|component| {
+        let digits: String = component
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+
+        digits.parse::<u32>().unwrap_or(0)
+    });
+
+    let major = components.next().unwrap_or(0);
+    let minor = components.next().unwrap_or(0);
+    let patch = components.next().unwrap_or(0);
+
+    (major, minor, patch)
+}
+
+/// Returns `true` if the version of the `libmount` library linked at runtime is greater than, or
+/// equal to `major.minor.patch`.
+///
+/// A version string without patch number (e.g. `"2.40"`) is treated as having a patch number of
+/// `0`.
+///
+/// # Examples
+///
+/// ```
+/// use rsmount::version;
+///
+/// fn main() -> rsmount::Result<()> {
+///     if version::at_least(2, 39, 0) {
+///         // Use features introduced in libmount v2.39
+///     }
+///
+///     assert!(version::at_least(2, 0, 0));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn at_least(major: u32, minor: u32, patch: u32) -> bool {
+    let version_string = library_version();
+    let state = version_triple(&version_string) >= (major, minor, patch);
+    log::debug!(
+        "at_least is library version {:?} at least {}.{}.{}? {:?}",
+        version_string,
+        major,
+        minor,
+        patch,
+        state
+    );
+
+    state
+}
+
 /// Returns a list of library features.
 pub fn library_features() -> Result<Vec<String>, VersionError> {
     log::debug!("library_features getting list of library features");
@@ -122,4 +216,63 @@ fn version_string_to_release_code_converts_up_to_first_invalid_character() {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn library_version_returns_a_parsable_version_string() {
+        let version_string = library_version();
+        assert!(!version_string.is_empty());
+
+        let mut version_ptr = MaybeUninit::<*const libc::c_char>::zeroed();
+        let expected = unsafe { libmount::mnt_get_library_version(version_ptr.as_mut_ptr()) };
+        let result = version_string_to_release_code(&version_string).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn library_version_matches_the_compile_time_major_version() {
+        let version_string = library_version();
+        let major = version_string
+            .split('.')
+            .next()
+            .and_then(|major| major.parse::<u32>().ok());
+
+        assert_eq!(major, Some(VERSION_NUMBER_MAJOR));
+    }
+
+    #[test]
+    fn version_triple_treats_missing_components_as_zero() {
+        assert_eq!(version_triple("2.39.4"), (2, 39, 4));
+        assert_eq!(version_triple("2.40"), (2, 40, 0));
+        assert_eq!(version_triple("2.40-rc1"), (2, 40, 0));
+        assert_eq!(version_triple("2"), (2, 0, 0));
+        assert_eq!(version_triple(""), (0, 0, 0));
+    }
+
+    #[test]
+    fn version_triple_compares_equal_greater_and_lesser_versions() {
+        let version = version_triple("2.40");
+
+        // Equal
+        assert!(version >= (2, 40, 0));
+
+        // Greater
+        assert!(version >= (2, 39, 4));
+        assert!(version >= (1, 99, 99));
+
+        // Lesser
+        assert!(version < (2, 40, 1));
+        assert!(version < (2, 41, 0));
+        assert!(version < (3, 0, 0));
+    }
+
+    #[test]
+    fn at_least_compares_against_the_runtime_library_version() {
+        let (major, minor, patch) = version_triple(&library_version());
+
+        assert!(at_least(major, minor, patch));
+        assert!(at_least(major, 0, 0));
+        assert!(!at_least(major + 1, 0, 0));
+        assert!(!at_least(major, minor, patch + 1));
+    }
 }
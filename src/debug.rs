@@ -101,6 +101,10 @@
 //! Debugging modes can not be modified after calling [`init_default_debug`] or [`init_full_debug`]
 //! once. The first function to get called sets the debug mode; a debug mode you can NOT change as
 //! long as your program is running.
+//!
+//! User-facing messages, like the ones a [`Mount`](crate::mount::Mount) in verbose mode emits
+//! (see [`MountBuilder::verbose`](crate::mount::MountBuilder::verbose)), go through the same
+//! *facade* at the `info` level.
 
 /// Activates library debugging messages. This function reads the `LIBMOUNT_DEBUG` environment
 /// variable to set the level of debug output.
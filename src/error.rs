@@ -80,8 +80,12 @@ pub enum RsMountError {
     #[error(transparent)]
     FileLock(#[from] FileLockError),
 
+    /// Error returned by a `libmount` function.
+    #[error("libmount::{function} returned error code: {code}")]
+    Ffi { function: &'static str, code: i32 },
+
     #[error(transparent)]
-    FsTab(#[from] FsTabError),
+    FsTab(FsTabError),
 
     #[error(transparent)]
     FsTabDiff(#[from] FsTabDiffError),
@@ -101,6 +105,10 @@ pub enum RsMountError {
     #[error(transparent)]
     GenIterator(#[from] GenIteratorError),
 
+    /// I/O error.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
     #[error(transparent)]
     Mount(#[from] MountError),
 
@@ -108,7 +116,7 @@ pub enum RsMountError {
     MountBuilder(#[from] MountBuilderError),
 
     #[error(transparent)]
-    MountInfo(#[from] MountInfoError),
+    MountInfo(MountInfoError),
 
     #[error(transparent)]
     MountInfoChildIter(#[from] MountInfoChildIterError),
@@ -145,11 +153,15 @@ pub enum RsMountError {
     #[error(transparent)]
     OptionIter(#[from] OptionIterError),
 
+    /// Error while parsing a line of a file system description file.
+    #[error("line {line}: {reason}")]
+    Parse { line: usize, reason: String },
+
     #[error(transparent)]
     Parser(#[from] ParserError),
 
     #[error(transparent)]
-    Swaps(#[from] SwapsError),
+    Swaps(SwapsError),
 
     #[error(transparent)]
     SwapsDiff(#[from] SwapsDiffError),
@@ -173,7 +185,7 @@ pub enum RsMountError {
     UnmountBuilder(#[from] UnmountBuilderError),
 
     #[error(transparent)]
-    UTab(#[from] UTabError),
+    UTab(UTabError),
 
     #[error(transparent)]
     UTabDiff(#[from] UTabDiffError),
@@ -196,3 +208,55 @@ pub enum RsMountError {
     #[error(transparent)]
     Version(#[from] VersionError),
 }
+
+impl From<FsTabError> for RsMountError {
+    /// Converts a [`FsTabError`] to an `RsMountError`, lifting FFI, I/O, and parsing errors to
+    /// their library-level counterparts ([`RsMountError::Ffi`], [`RsMountError::Io`], and
+    /// [`RsMountError::Parse`]).
+    fn from(error: FsTabError) -> RsMountError {
+        match error {
+            FsTabError::Ffi { function, code } => Self::Ffi { function, code },
+            FsTabError::IoError(e) => Self::Io(e),
+            FsTabError::Parse { line, reason } => Self::Parse { line, reason },
+            error => Self::FsTab(error),
+        }
+    }
+}
+
+impl From<MountInfoError> for RsMountError {
+    /// Converts a [`MountInfoError`] to an `RsMountError`, lifting FFI, and parsing errors to
+    /// their library-level counterparts ([`RsMountError::Ffi`], and [`RsMountError::Parse`]).
+    fn from(error: MountInfoError) -> RsMountError {
+        match error {
+            MountInfoError::Ffi { function, code } => Self::Ffi { function, code },
+            MountInfoError::Parse { line, reason } => Self::Parse { line, reason },
+            error => Self::MountInfo(error),
+        }
+    }
+}
+
+impl From<SwapsError> for RsMountError {
+    /// Converts a [`SwapsError`] to an `RsMountError`, lifting FFI, and parsing errors to their
+    /// library-level counterparts ([`RsMountError::Ffi`], and [`RsMountError::Parse`]).
+    fn from(error: SwapsError) -> RsMountError {
+        match error {
+            SwapsError::Ffi { function, code } => Self::Ffi { function, code },
+            SwapsError::Parse { line, reason } => Self::Parse { line, reason },
+            error => Self::Swaps(error),
+        }
+    }
+}
+
+impl From<UTabError> for RsMountError {
+    /// Converts a [`UTabError`] to an `RsMountError`, lifting FFI, I/O, and parsing errors to
+    /// their library-level counterparts ([`RsMountError::Ffi`], [`RsMountError::Io`], and
+    /// [`RsMountError::Parse`]).
+    fn from(error: UTabError) -> RsMountError {
+        match error {
+            UTabError::Ffi { function, code } => Self::Ffi { function, code },
+            UTabError::IoError(e) => Self::Io(e),
+            UTabError::Parse { line, reason } => Self::Parse { line, reason },
+            error => Self::UTab(error),
+        }
+    }
+}
@@ -7,11 +7,12 @@
 
 // From standard library
 use std::ffi::{CStr, CString, NulError, OsStr};
-use std::fs::File;
+use std::fs::{self, File};
 use std::io;
 use std::mem::MaybeUninit;
 use std::os::fd::AsRawFd;
 use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::FileExt;
 use std::path::{Path, PathBuf};
 use std::str::Utf8Error;
 
@@ -266,3 +267,106 @@ pub fn write_only_c_file_stream_from(file: &File) -> io::Result<*mut libc::FILE>
     let write_only = CString::new("w")?;
     c_file_stream_from(file, write_only.as_c_str())
 }
+
+#[doc(hidden)]
+/// Runs the `parse` function on `table`, recording the line numbers of the syntax errors met by
+/// the parser, and the names of the files they are in. The parser skips defective lines, unless
+/// `abort` is `true`, in which case it stops at the first one.
+///
+/// **Note:** this function temporarily replaces the table's parser error callback and userdata.
+/// Do not use it on a table with a user-defined parser error handler.
+pub fn parse_recording_syntax_errors<F>(
+    table: *mut libmount::libmnt_table,
+    abort: bool,
+    parse: F,
+) -> (libc::c_int, Vec<(usize, Option<PathBuf>)>)
+where
+    F: FnOnce(*mut libmount::libmnt_table) -> libc::c_int,
+{
+    #[doc(hidden)]
+    /// Locations of syntax errors, and what to do after each.
+    struct SyntaxErrors {
+        lines: Vec<(usize, Option<PathBuf>)>,
+        abort: bool,
+    }
+
+    #[doc(hidden)]
+    /// Callback function saving the location of a syntax error to the `SyntaxErrors` set as the
+    /// table's userdata.
+    unsafe extern "C" fn record_line(
+        table: *mut libmount::libmnt_table,
+        file_name: *const libc::c_char,
+        line: libc::c_int,
+    ) -> libc::c_int {
+        let errors_ptr = unsafe { libmount::mnt_table_get_userdata(table) };
+        // We set the userdata ourselves, it is never NULL.
+        let errors = unsafe { &mut *(errors_ptr as *mut SyntaxErrors) };
+        let file_name = if file_name.is_null() {
+            None
+        } else {
+            Some(const_c_char_array_to_path_buf(file_name))
+        };
+        errors.lines.push((line as usize, file_name));
+
+        // Negative values tell the parser to abort, positive values to skip the line.
+        if errors.abort {
+            -1
+        } else {
+            1
+        }
+    }
+
+    let mut errors = SyntaxErrors {
+        lines: vec![],
+        abort,
+    };
+
+    let errors_ptr = &mut errors as *mut SyntaxErrors;
+
+    // Both functions only fail when given a NULL table.
+    unsafe {
+        libmount::mnt_table_set_userdata(table, errors_ptr as *mut _);
+        libmount::mnt_table_set_parser_errcb(table, Some(record_line));
+    }
+
+    let result = parse(table);
+
+    unsafe {
+        libmount::mnt_table_set_parser_errcb(table, None);
+        libmount::mnt_table_set_userdata(table, std::ptr::null_mut());
+    }
+
+    (result, errors.lines)
+}
+
+#[doc(hidden)]
+/// Returns the line numbered `line`, counting from 1, in `content`.
+fn nth_line(content: &[u8], line: usize) -> Option<String> {
+    String::from_utf8_lossy(content)
+        .lines()
+        .nth(line.checked_sub(1)?)
+        .map(str::to_owned)
+}
+
+#[doc(hidden)]
+/// Returns the line numbered `line`, counting from 1, in the file at `file_path`, if the file is
+/// readable.
+pub fn read_line<T>(file_path: T, line: usize) -> Option<String>
+where
+    T: AsRef<Path>,
+{
+    let content = fs::read(file_path).ok()?;
+
+    nth_line(&content, line)
+}
+
+#[doc(hidden)]
+/// Returns the line numbered `line`, counting from 1, in the content of `file` starting at byte
+/// `offset`, without moving the file's cursor.
+pub fn read_line_at(file: &File, offset: u64, line: usize) -> Option<String> {
+    let size = file.metadata().ok()?.len();
+    let mut content = vec![0; size.checked_sub(offset)? as usize];
+    file.read_exact_at(&mut content, offset).ok()?;
+
+    nth_line(&content, line)
+}
@@ -125,6 +125,13 @@
 //!
 //! Finally, look to the [`debug`] module if you need to consult debug messages during development.
 //!
+//! ## Crate features
+//!
+//! - `serde`: implements [`serde::Serialize`](https://docs.rs/serde/latest/serde/trait.Serialize.html)
+//!   and [`serde::Deserialize`](https://docs.rs/serde/latest/serde/trait.Deserialize.html) for
+//!   [`FsTabEntry`](crate::core::entries::FsTabEntry), and `serde::Serialize` for
+//!   [`MountInfoEntry`](crate::core::entries::MountInfoEntry).
+//!
 //! ## From `libmount` to `rsmount` API
 //!
 //! This section maps `libmount` functions to `rsmount` methods. It follows the same layout as
@@ -158,9 +165,9 @@
 //! | [`MNT_EX_SOMEOK`][20]                    | [`ExitCode::PartialSuccess`](crate::mount::ExitCode::PartialSuccess)                                                                                                                                                           |
 //! | [`mnt_free_context`][21]                 | [`Mount`](crate::mount::Mount) and [`Unmount`](crate::mount::Unmount) are automatically deallocated when they go out of scope.                                                                                                 |
 //! | [`mnt_new_context`][22]                  | [`Mount::builder`](crate::mount::Mount::builder) <br> [`Unmount::builder`](crate::mount::Unmount::builder)                                                                                                                     |
-//! | [`mnt_reset_context`][23]                | Not implemented.                                                                                                                                                                                                               |
+//! | [`mnt_reset_context`][23]                | [`Mount::reset`](crate::mount::Mount::reset)                                                                                                                                                                                   |
 //! | [`mnt_context_append_options`][24]       | [`Mount::append_mount_options`](crate::mount::Mount::append_mount_options)                                                                                                                                                     |
-//! | [`mnt_context_apply_fstab`][25]          |                                                                                                                                                                                                                                |
+//! | [`mnt_context_apply_fstab`][25]          | [`Mount::apply_fstab`](crate::mount::Mount::apply_fstab)                                                                                                                                                                       |
 //! | [`mnt_context_disable_canonicalize`][26] | [`MountBuilder::disable_path_canonicalization`](crate::mount::MountBuilder::disable_path_canonicalization) <br> [`UnmountBuilder::disable_path_canonicalization`](crate::mount::UnmountBuilder::disable_path_canonicalization) |
 //! | [`mnt_context_disable_helpers`][27]      | [`MountBuilder::disable_helpers`](crate::mount::MountBuilder::disable_helpers) <br> [`UnmountBuilder::disable_helpers`](crate::mount::UnmountBuilder::disable_helpers)                                                         |
 //! | [`mnt_context_disable_mtab`][28]         | [`MountBuilder::do_not_update_utab`](crate::mount::MountBuilder::do_not_update_utab) <br> [`UnmountBuilder::do_not_update_utab`](crate::mount::UnmountBuilder::do_not_update_utab)                                             |
@@ -169,7 +176,7 @@
 //! | [`mnt_context_enable_force`][31]         | [`UnmountBuilder::force_unmount`](crate::mount::UnmountBuilder::force_unmount)                                                                                                                                                 |
 //! | [`mnt_context_enable_fork`][32]          | [`MountBuilder::parallel_mount`](crate::mount::MountBuilder::parallel_mount)                                                                                                                                                   |
 //! | [`mnt_context_enable_lazy`][33]          | [`UnmountBuilder::lazy_unmount`](crate::mount::UnmountBuilder::lazy_unmount)                                                                                                                                                   |
-//! | [`mnt_context_enable_loopdel`][34]       | [`UnmountBuilder::detach_loop_device`](crate::mount::UnmountBuilder::detach_loop_device)                                                                                                                                       |
+//! | [`mnt_context_enable_loopdel`][34]       | [`UnmountBuilder::detach_loop_device`](crate::mount::UnmountBuilder::detach_loop_device) <br> [`Mount::enable_loop_delete_on_umount`](crate::mount::Mount::enable_loop_delete_on_umount)                                       |
 //! | [`mnt_context_enable_noautofs`][35]      | [`MountBuilder::ignore_autofs`](crate::mount::MountBuilder::ignore_autofs)                                                                                                                                                     |
 //! | [`mnt_context_enable_onlyonce`][36]      | [`MountBuilder::mount_only_once`](crate::mount::MountBuilder::mount_only_once)                                                                                                                                                 |
 //! | [`mnt_context_enable_rdonly_umount`][37] | [`UnmountBuilder::on_fail_remount_read_only`](crate::mount::UnmountBuilder::on_fail_remount_read_only)                                                                                                                         |
@@ -180,7 +187,7 @@
 //! | [`mnt_context_force_unrestricted`][42]   | [`MountBuilder::force_user_mount`](crate::mount::MountBuilder::force_user_mount)                                                                                                                                               |
 //! | [`mnt_context_get_cache`][43]            | [`Mount::cache`](crate::mount::Mount::cache)                                                                                                                                                                                   |
 //! | [`mnt_context_get_excode`][44]           | Not implemented, managed internally.                                                                                                                                                                                           |
-//! | [`mnt_context_get_fs`][45]               | [`Mount::internal_table_entry`](crate::mount::Mount::internal_table_entry)                                                                                                                                                     |
+//! | [`mnt_context_get_fs`][45]               | [`Mount::internal_table_entry`](crate::mount::Mount::internal_table_entry) <br> [`Unmount::internal_table_entry`](crate::mount::Unmount::internal_table_entry)                                                                 |
 //! | [`mnt_context_get_fstab`][46]            | [`Mount::fstab`](crate::mount::Mount::fstab)                                                                                                                                                                                   |
 //! | [`mnt_context_get_fstab_userdata`][47]   | [`Mount::fstab_user_data`](crate::mount::Mount::fstab_user_data)                                                                                                                                                               |
 //! | [`mnt_context_get_fstype`][48]           | [`Mount::file_system_type`](crate::mount::Mount::file_system_type)                                                                                                                                                             |
@@ -210,7 +217,7 @@
 //! | [`mnt_context_is_fork`][72]              | [`Mount::does_parallel_mount`](crate::mount::Mount::does_parallel_mount)                                                                                                                                                       |
 //! | [`mnt_context_is_fs_mounted`][73]        | [`Mount::is_entry_mounted`](crate::mount::Mount::is_entry_mounted)                                                                                                                                                             |
 //! | [`mnt_context_is_lazy`][74]              | [`Unmount::does_lazy_unmount`](crate::mount::Unmount::does_lazy_unmount)                                                                                                                                                       |
-//! | [`mnt_context_is_loopdel`][75]           | [`Unmount::detaches_loop_device`](crate::mount::Unmount::detaches_loop_device)                                                                                                                                                 |
+//! | [`mnt_context_is_loopdel`][75]           | [`Unmount::detaches_loop_device`](crate::mount::Unmount::detaches_loop_device) <br> [`Mount::deletes_loop_device_on_umount`](crate::mount::Mount::deletes_loop_device_on_umount)                                               |
 //! | [`mnt_context_is_nocanonicalize`][76]    | [`Mount::disabled_path_canonicalization`](crate::mount::Mount::disabled_path_canonicalization) <br> [`Unmount::disabled_path_canonicalization`](crate::mount::Unmount::disabled_path_canonicalization)                         |
 //! | [`mnt_context_is_nohelpers`][77]         | [`Mount::has_disabled_helpers`](crate::mount::Mount::has_disabled_helpers) <br> [`Unmount::has_disabled_helpers`](crate::mount::Unmount::has_disabled_helpers)                                                                 |
 //! | [`mnt_context_is_nomtab`][78]            | [`Mount::does_not_update_utab`](crate::mount::Mount::does_not_update_utab) <br> [`Unmount::does_not_update_utab`](crate::mount::Unmount::does_not_update_utab)                                                                 |
@@ -229,15 +236,15 @@
 //! | [`mnt_context_set_fstype`][91]           | [`MountBuilder::file_system`](crate::mount::MountBuilder::file_system)                                                                                                                                                         |
 //! | [`mnt_context_set_fstype_pattern`][92]   | [`MountBuilder::match_file_systems`](crate::mount::MountBuilder::match_file_systems) <br> [`UnmountBuilder::match_file_systems`](crate::mount::UnmountBuilder::match_file_systems)                                             |
 //! | [`mnt_context_set_mflags`][93]           | [`MountBuilder::mount_flags`](crate::mount::MountBuilder::mount_flags)                                                                                                                                                         |
-//! | [`mnt_context_set_mountdata`][94]        | [`MountBuilder::mount_data`](crate::mount::MountBuilder::mount_data)                                                                                                                                                           |
+//! | [`mnt_context_set_mountdata`][94]        | [`MountBuilder::mount_data`](crate::mount::MountBuilder::mount_data) <br> [`Mount::set_mount_data`](crate::mount::Mount::set_mount_data)                                                                                       |
 //! | [`mnt_context_set_options`][95]          | [`MountBuilder::mount_options`](crate::mount::MountBuilder::mount_options)                                                                                                                                                     |
 //! | [`mnt_context_set_options_pattern`][96]  | [`MountBuilder::match_mount_options`](crate::mount::MountBuilder::match_mount_options) <br> [`UnmountBuilder::match_mount_options`](crate::mount::UnmountBuilder::match_mount_options)                                         |
 //! | [`mnt_context_set_optsmode`][97]         | [`MountBuilder::mount_options_mode`](crate::mount::MountBuilder::mount_options_mode)                                                                                                                                           |
 //! | [`mnt_context_set_passwd_cb`][98]        | Deprecated.                                                                                                                                                                                                                    |
-//! | [`mnt_context_set_source`][99]           | [`MountBuilder::source`](crate::mount::MountBuilder::source) <br> [`UnmountBuilder::source`](crate::mount::UnmountBuilder::source)                                                                                             |
+//! | [`mnt_context_set_source`][99]           | [`MountBuilder::source`](crate::mount::MountBuilder::source) <br> [`UnmountBuilder::source`](crate::mount::UnmountBuilder::source) <br> [`Mount::set_mount_source`](crate::mount::Mount::set_mount_source)                     |
 //! | [`mnt_context_set_syscall_status`][100]  | [`Mount::set_syscall_exit_status`](crate::mount::Mount::set_syscall_exit_status) <br> [`Unmount::set_syscall_exit_status`](crate::mount::Unmount::set_syscall_exit_status)                                                     |
 //! | [`mnt_context_set_tables_errcb`][101]    | Can not implement it without a data pointer in the callback function see [Passing Rust closure to C](http://blog.sagetheprogrammer.com/neat-rust-tricks-passing-rust-closures-to-c)                                            |
-//! | [`mnt_context_set_target`][102]          | [`MountBuilder::target`](crate::mount::MountBuilder::target) <br> [`UnmountBuilder::target`](crate::mount::UnmountBuilder::target)                                                                                             |
+//! | [`mnt_context_set_target`][102]          | [`MountBuilder::target`](crate::mount::MountBuilder::target) <br> [`UnmountBuilder::target`](crate::mount::UnmountBuilder::target) <br> [`Mount::set_mount_target`](crate::mount::Mount::set_mount_target)                     |
 //! | [`mnt_context_set_target_ns`][103]       | [`MountBuilder::target_namespace`](crate::mount::MountBuilder::target_namespace) <br> [`UnmountBuilder::target_namespace`](crate::mount::UnmountBuilder::target_namespace)                                                     |
 //! | [`mnt_context_set_target_prefix`][104]   | [`MountBuilder::target_prefix`](crate::mount::MountBuilder::target_prefix)                                                                                                                                                     |
 //! | [`mnt_context_set_user_mflags`][105]     | [`MountBuilder::userspace_mount_flags`](crate::mount::MountBuilder::userspace_mount_flags)                                                                                                                                     |
@@ -484,14 +491,14 @@
 //!
 //! #### Umount context
 //!
-//! | `libmount`                           | `rsmount`                                                                                                                                                                                                                                                                |
-//! | ------------------                   | ---------                                                                                                                                                                                                                                                                |
-//! | [`mnt_context_find_umount_fs`][170]  | [`Mount::find_entry_matching_source`](crate::mount::Mount::find_entry_matching_source) <br> [`Mount::find_entry_matching_target`](crate::mount::Mount::find_entry_matching_target) <br> [`Mount::find_entry_matching_tag`](crate::mount::Mount::find_entry_matching_tag) |
-//! | [`mnt_context_do_umount`][171]       | [`Unmount::call_umount_syscall`](crate::mount::Unmount::call_umount_syscall)                                                                                                                                                                                             |
-//! | [`mnt_context_finalize_umount`][172] | [`Unmount::finalize_umount`](crate::mount::Unmount::finalize_umount)                                                                                                                                                                                                     |
-//! | [`mnt_context_next_umount`][173]     | [`Unmount`::seq_unmount](crate::mount::Unmount::seq_unmount)                                                                                                                                                                                                                   |
-//! | [`mnt_context_prepare_umount`][174]  | [`Unmount::prepare_unmount`](crate::mount::Unmount::prepare_unmount)                                                                                                                                                                                                     |
-//! | [`mnt_context_umount`][175]          | [`Unmount::unmount_device`](crate::mount::Unmount::unmount_device)                                                                                                                                                                                                       |
+//! | `libmount`                           | `rsmount`                                                                                                                                                                                                                                                                                                                                  |
+//! | ------------------                   | ---------                                                                                                                                                                                                                                                                                                                                  |
+//! | [`mnt_context_find_umount_fs`][170]  | [`Mount::find_entry_matching_source`](crate::mount::Mount::find_entry_matching_source) <br> [`Mount::find_entry_matching_target`](crate::mount::Mount::find_entry_matching_target) <br> [`Mount::find_entry_matching_tag`](crate::mount::Mount::find_entry_matching_tag) <br> [`Unmount::find_target`](crate::mount::Unmount::find_target) |
+//! | [`mnt_context_do_umount`][171]       | [`Unmount::call_umount_syscall`](crate::mount::Unmount::call_umount_syscall)                                                                                                                                                                                                                                                               |
+//! | [`mnt_context_finalize_umount`][172] | [`Unmount::finalize_umount`](crate::mount::Unmount::finalize_umount)                                                                                                                                                                                                                                                                       |
+//! | [`mnt_context_next_umount`][173]     | [`Unmount`::seq_unmount](crate::mount::Unmount::seq_unmount)                                                                                                                                                                                                                                                                               |
+//! | [`mnt_context_prepare_umount`][174]  | [`Unmount::prepare_unmount`](crate::mount::Unmount::prepare_unmount)                                                                                                                                                                                                                                                                       |
+//! | [`mnt_context_umount`][175]          | [`Unmount::unmount_device`](crate::mount::Unmount::unmount_device)                                                                                                                                                                                                                                                                         |
 //!
 //! [170]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libmount-docs/libmount-Umount-context.html#mnt-context-find-umount-fs
 //! [171]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libmount-docs/libmount-Umount-context.html#mnt-context-do-umount
@@ -517,7 +524,7 @@
 //! | [`mnt_table_append_intro_comment`][185]    | [`FsTab::append_to_intro_comments`](crate::tables::FsTab::append_to_intro_comments)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                        |
 //! | [`mnt_table_append_trailing_comment`][186] | [`FsTab::append_to_trailing_comments`](crate::tables::FsTab::append_to_trailing_comments)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                  |
 //! | [`mnt_table_enable_comments`][187]         | [`FsTab::import_with_comments`](crate::tables::FsTab::import_with_comments) <br> [`FsTab::import_without_comments`](crate::tables::FsTab::import_without_comments) <br> [`FsTab::export_with_comments`](crate::tables::FsTab::export_with_comments) <br> [`FsTab::export_without_comments`](crate::tables::FsTab::export_without_comments)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                 |
-//! | [`mnt_table_find_devno`][188]              | [`MountInfo::find_device`](crate::tables::MountInfo::find_device) <br> [`MountInfo::find_back_device`](crate::tables::MountInfo::find_back_device)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                         |
+//! | [`mnt_table_find_devno`][188]              | [`MountInfo::find_device`](crate::tables::MountInfo::find_device) <br> [`MountInfo::find_back_device`](crate::tables::MountInfo::find_back_device) <br> [`MountInfo::find_by_device_number`](crate::tables::MountInfo::find_by_device_number) <br> [`MountInfo::find_back_by_device_number`](crate::tables::MountInfo::find_back_by_device_number)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                         |
 //! | [`mnt_table_find_fs`][189]                 | [`FsTab::contains`](crate::tables::FsTab::contains) <br> [`FsTab::position`](crate::tables::FsTab::position) <br> [`MountInfo::position`](crate::tables::MountInfo::position) <br> [`Swaps::position`](crate::tables::Swaps::position) <br> [`UTab::contains`](crate::tables::UTab::contains) <br> [`UTab::position`](crate::tables::UTab::position)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                       |
 //! | [`mnt_table_find_mountpoint`][190]         | [`MountInfo::find_mount_point`](crate::tables::MountInfo::find_mount_point) <br> [`MountInfo::find_back_mount_point`](crate::tables::MountInfo::find_back_mount_point)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                     |
 //! | [`mnt_table_find_next_fs`][191]            | [`FsTab::find_first`](crate::tables::FsTab::find_first) <br> [`FsTab::find_back_first`](crate::tables::FsTab::find_back_first) <br> [`MountInfo::find_first`](crate::tables::MountInfo::find_first) <br> [`MountInfo::find_back_first`](crate::tables::MountInfo::find_back_first) <br> [`Swaps::find_first`](crate::tables::Swaps::find_first) <br> [`Swaps::find_back_first`](crate::tables::Swaps::find_back_first) <br> [`UTab::find_first`](crate::tables::UTab::find_first) <br> [`UTab::find_back_first`](crate::tables::UTab::find_back_first)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                     |
@@ -538,17 +545,17 @@
 //! | [`mnt_table_is_empty`][206]                | [`FsTab::is_empty`](crate::tables::FsTab::is_empty) <br> [`MountInfo::is_empty`](crate::tables::MountInfo::is_empty) <br> [`Swaps::is_empty`](crate::tables::Swaps::is_empty) <br> [`UTab::is_empty`](crate::tables::UTab::is_empty)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                       |
 //! | [`mnt_table_is_fs_mounted`][207]           | [`MountInfo::is_mounted`](crate::tables::MountInfo::is_mounted)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                            |
 //! | [`mnt_table_last_fs`][208]                 | [`FsTab::last`](crate::tables::FsTab::last) <br> [`MountInfo::last`](crate::tables::MountInfo::last) <br> [`Swaps::last`](crate::tables::Swaps::last) <br> [`UTab::last`](crate::tables::UTab::last)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                       |
-//! | [`mnt_table_move_fs`][209]                 | [`FsTab::transfer`](crate::tables::FsTab::transfer) <br> [`UTab::transfer`](crate::tables::UTab::transfer)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                 |
+//! | [`mnt_table_move_fs`][209]                 | [`FsTab::sort_by`](crate::tables::FsTab::sort_by) <br> [`FsTab::sort_by_target_depth`](crate::tables::FsTab::sort_by_target_depth) <br> [`FsTab::transfer`](crate::tables::FsTab::transfer) <br> [`FsTab::transfer_within`](crate::tables::FsTab::transfer_within) <br> [`UTab::transfer`](crate::tables::UTab::transfer)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                  |
 //! | [`mnt_table_next_child_fs`][210]           | [`MountInfo::iter_children`](crate::tables::MountInfo::iter_children) <br> [`MountInfo::try_iter_children`](crate::tables::MountInfo::try_iter_children)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                   |
 //! | [`mnt_table_next_fs`][211]                 | [`FsTab::iter`](crate::tables::FsTab::iter) <br> [`FsTab::try_iter`](crate::tables::FsTab::try_iter) <br> [`FsTab::iter_mut`](crate::tables::FsTab::iter_mut) <br> [`FsTab::try_iter_mut`](crate::tables::FsTab::try_iter_mut) <br> [`MountInfo::iter`](crate::tables::MountInfo::iter) <br> [`MountInfo::try_iter`](crate::tables::MountInfo::try_iter) <br> [`Swaps::iter`](crate::tables::Swaps::iter) <br> [`Swaps::try_iter`](crate::tables::Swaps::try_iter) <br> [`UTab::iter`](crate::tables::UTab::iter) <br> [`UTab::try_iter`](crate::tables::UTab::try_iter) <br> [`UTab::iter_mut`](crate::tables::UTab::iter_mut) <br> [`UTab::try_iter_mut`](crate::tables::UTab::try_iter_mut)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                             |
-//! | [`mnt_table_over_fs`][212]                 | [`MountInfo::iter_overmounts`](crate::tables::MountInfo::iter_overmounts)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                  |
+//! | [`mnt_table_over_fs`][212]                 | [`MountInfo::overmount`](crate::tables::MountInfo::overmount) <br> [`MountInfo::iter_overmounts`](crate::tables::MountInfo::iter_overmounts)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                               |
 //! | [`mnt_table_parse_dir`][213]               | [`FsTab::import_directory`](crate::tables::FsTab::import_directory)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                        |
 //! | [`mnt_table_parse_file`][214]              | [`FsTab::import_file`](crate::tables::FsTab::import_file)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                  |
 //! | [`mnt_table_parse_fstab`][215]             | [`FsTab::import_etc_fstab`](crate::tables::FsTab::import_etc_fstab)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                        |
 //! | [`mnt_table_parse_mtab`][216]              | [`MountInfo::import_mountinfo`](crate::tables::MountInfo::import_mountinfo)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                |
 //! | [`mnt_table_parse_stream`][217]            | [`FsTab::import_from_stream`](crate::tables::FsTab::import_from_stream)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                    |
-//! | [`mnt_table_parse_swaps`][218]             | [`Swaps::import_proc_swaps`](crate::tables::Swaps::import_proc_swaps)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                      |
-//! | [`mnt_table_remove_fs`][219]               | [`FsTab::remove`](crate::tables::FsTab::remove) <br> [`UTab::remove`](crate::tables::UTab::remove)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                         |
+//! | [`mnt_table_parse_swaps`][218]             | [`Swaps::import_proc_swaps`](crate::tables::Swaps::import_proc_swaps) <br> [`Swaps::import_from_stream`](crate::tables::Swaps::import_from_stream)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                         |
+//! | [`mnt_table_remove_fs`][219]               | [`FsTab::remove`](crate::tables::FsTab::remove) <br> [`FsTab::try_remove`](crate::tables::FsTab::try_remove) <br> [`MountInfo::remove`](crate::tables::MountInfo::remove) <br> [`MountInfo::try_remove`](crate::tables::MountInfo::try_remove) <br> [`Swaps::remove`](crate::tables::Swaps::remove) <br> [`Swaps::try_remove`](crate::tables::Swaps::try_remove) <br> [`UTab::remove`](crate::tables::UTab::remove) <br> [`UTab::try_remove`](crate::tables::UTab::try_remove)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                             |
 //! | [`mnt_table_set_cache`][220]               | [`FsTab::set_cache`](crate::tables::FsTab::set_cache) <br> [`MountInfo::set_cache`](crate::tables::MountInfo::set_cache) <br> [`Swaps::set_cache`](crate::tables::Swaps::set_cache) <br> [`UTab::set_cache`](crate::tables::UTab::set_cache)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                               |
 //! | [`mnt_table_set_intro_comment`][221]       | [`FsTab::set_intro_comments`](crate::tables::FsTab::set_intro_comments)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                    |
 //! | [`mnt_table_set_iter`][222]                | [`FsTabIter::advance_to`](crate::core::iter::FsTabIter::advance_to) <br> [`FsTabIterMut::advance_to`](crate::core::iter::FsTabIterMut::advance_to) <br> [`MountInfoIter::advance_to`](crate::core::iter::MountInfoIter::advance_to) <br> [`SwapsIter::advance_to`](crate::core::iter::SwapsIter::advance_to) <br> [`UTabIter::advance_to`](crate::core::iter::UTabIter::advance_to) <br> [`UTabIterMut::advance_to`](crate::core::iter::UTabIterMut::advance_to)                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                                           |
@@ -623,12 +630,12 @@
 //! | [`mnt_unref_fs`][233]               | Managed automatically.                                                                                                                                                                                                                                                                                                                                                                                                     |
 //! | [`mnt_fs_append_attributes`][234]   | [`UTabEntry::append_attributes`](crate::core::entries::UTabEntry::append_attributes)                                                                                                                                                                                                                                                                                                                                       |
 //! | [`mnt_fs_append_comment`][235]      | [`FsTabEntry::append_comment`](crate::core::entries::FsTabEntry::append_comment)                                                                                                                                                                                                                                                                                                                                           |
-//! | [`mnt_fs_append_options`][236]      | [`FsTabEntry::append_options`](crate::core::entries::FsTabEntry::append_options)                                                                                                                                                                                                                                                                                                                                           |
+//! | [`mnt_fs_append_options`][236]      | [`FsTabEntry::append_options`](crate::core::entries::FsTabEntry::append_options) <br> [`UTabEntry::merge_into`](crate::core::entries::UTabEntry::merge_into)                                                                                                                                                                                                                                                               |
 //! | [`mnt_fs_get_attribute`][237]       | [`UTabEntry::attribute_value`](crate::core::entries::UTabEntry::attribute_value)                                                                                                                                                                                                                                                                                                                                           |
 //! | [`mnt_fs_get_attributes`][238]      | [`UTabEntry::attributes`](crate::core::entries::UTabEntry::attributes)                                                                                                                                                                                                                                                                                                                                                     |
-//! | [`mnt_fs_get_bindsrc`][239]         | [`UTabEntry::bind_source`](crate::core::entries::UTabEntry::bind_source)                                                                                                                                                                                                                                                                                                                                                   |
+//! | [`mnt_fs_get_bindsrc`][239]         | [`UTabEntry::bind_source`](crate::core::entries::UTabEntry::bind_source) <br> [`MountInfoEntry::bind_source`](crate::core::entries::MountInfoEntry::bind_source)                                                                                                                                                                                                                                                           |
 //! | [`mnt_fs_get_comment`][240]         | [`FsTabEntry::comment`](crate::core::entries::FsTabEntry::comment)                                                                                                                                                                                                                                                                                                                                                         |
-//! | [`mnt_fs_get_devno`][241]           | [`MountInfoEntry::device_id`](crate::core::entries::MountInfoEntry::device_id)                                                                                                                                                                                                                                                                                                                                             |
+//! | [`mnt_fs_get_devno`][241]           | [`MountInfoEntry::device_id`](crate::core::entries::MountInfoEntry::device_id) <br> [`MountInfoEntry::device_numbers`](crate::core::entries::MountInfoEntry::device_numbers)                                                                                                                                                                                                                                               |
 //! | [`mnt_fs_get_freq`][242]            | [`FsTabEntry::backup_frequency`](crate::core::entries::FsTabEntry::backup_frequency)                                                                                                                                                                                                                                                                                                                                       |
 //! | [`mnt_fs_get_fs_options`][243]      | [`MountInfoEntry::fs_specific_options`](crate::core::entries::MountInfoEntry::fs_specific_options)                                                                                                                                                                                                                                                                                                                         |
 //! | [`mnt_fs_get_fstype`][244]          | [`FsTabEntry::file_system_type`](crate::core::entries::FsTabEntry::file_system_type) <br> [`MountInfoEntry::file_system_type`](crate::core::entries::MountInfoEntry::file_system_type)                                                                                                                                                                                                                                     |
@@ -667,7 +674,7 @@
 //! | [`mnt_fs_prepend_options`][277]     | [`FsTabEntry::prepend_options`](crate::core::entries::FsTabEntry::prepend_options)                                                                                                                                                                                                                                                                                                                                         |
 //! | [`mnt_fs_print_debug`][278]         | [`FsTabEntry::print_debug_to`](crate::core::entries::FsTabEntry::print_debug_to) <br> [`MountInfoEntry::print_debug_to`](crate::core::entries::MountInfoEntry::print_debug_to)                                                                                                                                                                                                                                             |
 //! | [`mnt_fs_set_attributes`][279]      | [`UTabEntry::set_attributes`](crate::core::entries::UTabEntry::set_attributes)                                                                                                                                                                                                                                                                                                                                             |
-//! | [`mnt_fs_set_bindsrc`][280]         | [`UTabEntry::set_bind_source`](crate::core::entries::UTabEntry::set_bind_source)                                                                                                                                                                                                                                                                                                                                           |
+//! | [`mnt_fs_set_bindsrc`][280]         | [`UTabEntry::set_bind_source`](crate::core::entries::UTabEntry::set_bind_source) <br> [`UTabEntry::merge_into`](crate::core::entries::UTabEntry::merge_into)                                                                                                                                                                                                                                                               |
 //! | [`mnt_fs_set_comment`][281]         | [`FsTabEntry::set_comment`](crate::core::entries::FsTabEntry::set_comment)                                                                                                                                                                                                                                                                                                                                                 |
 //! | [`mnt_fs_set_freq`][282]            | [`FsTabEntry::set_backup_frequency`](crate::core::entries::FsTabEntry::set_backup_frequency)                                                                                                                                                                                                                                                                                                                               |
 //! | [`mnt_fs_set_fstype`][283]          | [`FsTabEntry::set_file_system_type`](crate::core::entries::FsTabEntry::set_file_system_type)                                                                                                                                                                                                                                                                                                                               |
@@ -814,9 +821,9 @@
 //! | [`mnt_unref_monitor`][318]            | Managed automatically.                                                                                                                                                                                                                                  |
 //! | [`mnt_monitor_enable_userspace`][319] | [`TableMonitor::watch_user_space`](crate::tables::TableMonitor::watch_user_space) <br> [`TableMonitor::watch_file`](crate::tables::TableMonitor::watch_file) <br> [`TableMonitor::unwatch_user_space`](crate::tables::TableMonitor::unwatch_user_space) |
 //! | [`mnt_monitor_enable_kernel`][320]    | [`TableMonitor::watch_kernel`](crate::tables::TableMonitor::watch_kernel) <br> [`TableMonitor::unwatch_kernel`](crate::tables::TableMonitor::unwatch_kernel)                                                                                            |
-//! | [`mnt_monitor_get_fd`][321]           | [`TableMonitor::event_notifier_create`](crate::tables::TableMonitor::event_notifier_create)                                                                                                                                                             |
+//! | [`mnt_monitor_get_fd`][321]           | [`TableMonitor::event_notifier_create`](crate::tables::TableMonitor::event_notifier_create) <br> [`TableMonitor::as_raw_fd`](crate::tables::TableMonitor#method.as_raw_fd)                                                                              |
 //! | [`mnt_monitor_close_fd`][322]         | [`TableMonitor::event_notifier_delete`](crate::tables::TableMonitor::event_notifier_delete)                                                                                                                                                             |
-//! | [`mnt_monitor_next_change`][323]      | [`TableMonitor::next_file_change`](crate::tables::TableMonitor::next_file_change)                                                                                                                                                                       |
+//! | [`mnt_monitor_next_change`][323]      | [`TableMonitor::next_file_change`](crate::tables::TableMonitor::next_file_change) <br> [`TableMonitor::iter_changes`](crate::tables::TableMonitor::iter_changes)                                                                                        |
 //! | [`mnt_monitor_event_cleanup`][324]    | [`TableMonitor::discard_last_event`](crate::tables::TableMonitor::discard_last_event)                                                                                                                                                                   |
 //! | [`mnt_monitor_wait`][325]             | [`TableMonitor::wait_for_next_change`](crate::tables::TableMonitor::wait_for_next_change)                                                                                                                                                               |
 //!
@@ -851,20 +858,20 @@
 //! ### Mount options
 //! #### Options string
 //!
-//! | `libmount`                             | `rsmount`                                                                                         |
-//! | ------------------                     | ---------                                                                                         |
-//! | [`mnt_optstr_append_option`][330]      | [`core::optstring::append_option`]                                                                |
-//! | [`mnt_optstr_apply_flags`][331]        | Deprecated.                                                                                       |
-//! | [`mnt_optstr_deduplicate_option`][332] | [`core::optstring::deduplicate_option`]                                                           |
-//! | [`mnt_optstr_get_flags`][333]          | [`core::optstring::find_mount_flags`] <br> [`core::optstring::find_userspace_mount_flags`]        |
-//! | [`mnt_optstr_get_option`][334]         | [`core::optstring::option_value`]                                                                 |
-//! | [`mnt_optstr_get_options`][335]        | [`core::optstring::take_fs_independent_options`] <br> [`core::optstring::take_userspace_options`] |
-//! | [`mnt_optstr_next_option`][336]        | [`core::optstring::iter_options`]                                                                 |
-//! | [`mnt_optstr_prepend_option`][337]     | [`core::optstring::prepend_option`]                                                               |
-//! | [`mnt_optstr_remove_option`][338]      | [`core::optstring::remove_option`]                                                                |
-//! | [`mnt_optstr_set_option`][339]         | [`core::optstring::set_option_value`] <br> [`core::optstring::unset_option_value`]                |
-//! | [`mnt_split_optstr`][340]              | [`core::optstring::take_fs_specific_options`]                                                     |
-//! | [`mnt_match_options`][341]             | [`core::optstring::matches_any_option`]                                                           |
+//! | `libmount`                             | `rsmount`                                                                                                     |
+//! | ------------------                     | ---------                                                                                                     |
+//! | [`mnt_optstr_append_option`][330]      | [`core::optstring::append_option`]                                                                            |
+//! | [`mnt_optstr_apply_flags`][331]        | [`core::optstring::apply_mount_flags`] <br> [`core::optstring::apply_userspace_mount_flags`]                  |
+//! | [`mnt_optstr_deduplicate_option`][332] | [`core::optstring::deduplicate_option`]                                                                       |
+//! | [`mnt_optstr_get_flags`][333]          | [`core::optstring::find_mount_flags`] <br> [`core::optstring::find_userspace_mount_flags`]                    |
+//! | [`mnt_optstr_get_option`][334]         | [`core::optstring::option_value`]                                                                             |
+//! | [`mnt_optstr_get_options`][335]        | [`core::optstring::take_fs_independent_options`] <br> [`core::optstring::take_userspace_options`]             |
+//! | [`mnt_optstr_next_option`][336]        | [`core::optstring::iter_options`] <br> [`core::optstring::iter_options_owned`] <br> [`core::optstring::scan`] |
+//! | [`mnt_optstr_prepend_option`][337]     | [`core::optstring::prepend_option`]                                                                           |
+//! | [`mnt_optstr_remove_option`][338]      | [`core::optstring::remove_option`]                                                                            |
+//! | [`mnt_optstr_set_option`][339]         | [`core::optstring::set_option_value`] <br> [`core::optstring::unset_option_value`]                            |
+//! | [`mnt_split_optstr`][340]              | [`core::optstring::split`] <br> [`core::optstring::take_fs_specific_options`]                                 |
+//! | [`mnt_match_options`][341]             | [`core::optstring::matches_any_option`]                                                                       |
 //!
 //! [330]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libmount-docs/libmount-Options-string.html#mnt-optstr-append-option
 //! [331]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libmount-docs/libmount-Options-string.html#mnt-optstr-apply-flags
@@ -881,14 +888,14 @@
 //!
 //! #### Option maps
 //!
-//! | `libmount`                      | `rsmount`                                                                                      |
-//! | ------------------              | ---------                                                                                      |
-//! | [`struct libmnt_optmap`][342]   | Internal.                                                                                      |
-//! | [`MNT_INVERT`][343]             | [`OptionFilter::Negated`](crate::core::optstring::OptionFilter::Negated)                       |
-//! | [`MNT_NOMTAB`][344]             | [`OptionFilter::NotInMountInfo`](crate::core::optstring::OptionFilter::NotInMountInfo)         |
-//! | [`MNT_PREFIX`][345]             | [`OptionFilter::Prefixed`](crate::core::optstring::OptionFilter::Prefixed)                     |
-//! | [`MNT_NOHLPS`][346]             | [`OptionFilter::NotForMountHelpers`](crate::core::optstring::OptionFilter::NotForMountHelpers) |
-//! | [`mnt_get_builtin_optmap`][347] | Internal.                                                                                      |
+//! | `libmount`                      | `rsmount`                                                                                                                            |
+//! | ------------------              | ---------                                                                                                                            |
+//! | [`struct libmnt_optmap`][342]   | [`OptionMap`](crate::core::optmap::OptionMap) <br> [`OptionMapEntry`](crate::core::optmap::OptionMapEntry)                           |
+//! | [`MNT_INVERT`][343]             | [`OptionFilter::Negated`](crate::core::optstring::OptionFilter::Negated)                                                             |
+//! | [`MNT_NOMTAB`][344]             | [`OptionFilter::NotInMountInfo`](crate::core::optstring::OptionFilter::NotInMountInfo)                                               |
+//! | [`MNT_PREFIX`][345]             | [`OptionFilter::Prefixed`](crate::core::optstring::OptionFilter::Prefixed)                                                           |
+//! | [`MNT_NOHLPS`][346]             | [`OptionFilter::NotForMountHelpers`](crate::core::optstring::OptionFilter::NotForMountHelpers)                                       |
+//! | [`mnt_get_builtin_optmap`][347] | [`OptionMap::linux`](crate::core::optmap::OptionMap::linux) <br> [`OptionMap::userspace`](crate::core::optmap::OptionMap::userspace) |
 //!
 //! [342]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libmount-docs/libmount-Option-maps.html#libmnt-optmap
 //! [343]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libmount-docs/libmount-Option-maps.html#MNT-INVERT:CAPS
@@ -908,24 +915,24 @@
 //!
 //! #### Cache
 //!
-//! | `libmount`                        | `rsmount`                                                                                                                                                                                                        |
-//! | ------------------                | ---------                                                                                                                                                                                                        |
-//! | [`struct libmnt_cache`][349]      | [`Cache`](crate::core::cache::Cache)                                                                                                                                                                             |
-//! | [`mnt_new_cache`][350]            | [`Cache::new`](crate::core::cache::Cache::new)                                                                                                                                                                   |
-//! | [`mnt_free_cache`][351]           | [`Cache`](crate::core::cache::Cache) is automatically deallocated when it goes out of scope.                                                                                                                     |
-//! | [`mnt_ref_cache`][352]            | Managed automatically.                                                                                                                                                                                           |
-//! | [`mnt_unref_cache`][353]          | Managed automatically.                                                                                                                                                                                           |
-//! | [`mnt_cache_device_has_tag`][354] | [`Cache::device_has_tag`](crate::core::cache::Cache::device_has_tag)                                                                                                                                             |
-//! | [`mnt_cache_find_tag_value`][355] | [`Cache::find_tag_value`](crate::core::cache::Cache::find_tag_value)                                                                                                                                             |
-//! | [`mnt_cache_read_tags`][356]      | [`Cache::import_tags`](crate::core::cache::Cache::import_tags)                                                                                                                                                   |
-//! | [`mnt_cache_set_targets`][357]    | [`Cache::import_paths`](crate::core::cache::Cache::import_paths)                                                                                                                                                 |
-//! | [`mnt_cache_set_sbprobe`][358]    | [`Cache::collect_fs_properties`](crate::core::cache::Cache::collect_fs_properties)                                                                                                                               |
-//! | [`mnt_get_fstype`][359]           | [`Cache::find_file_system_type`](crate::core::cache::Cache::find_file_system_type)<br>[`Cache::find_and_cache_file_system_type`](crate::core::cache::Cache::find_and_cache_file_system_type)                     |
-//! | [`mnt_pretty_path`][360]          | [`Cache::canonicalize`](crate::core::cache::Cache::canonicalize)<br>[`Cache::canonicalize_and_cache`](crate::core::cache::Cache::canonicalize_and_cache)                                                         |
-//! | [`mnt_resolve_path`][361]         | [`Cache::resolve`](crate::core::cache::Cache::resolve)<br>[`Cache::resolve_and_cache`](crate::core::cache::Cache::resolve_and_cache)                                                                             |
-//! | [`mnt_resolve_spec`][362]         | Not implemented. Use the specialized functions corresponding to `mnt_resolve_path` or `mnt_resolve_tag` as applicable.                                                                                           |
-//! | [`mnt_resolve_tag`][363]          | [`Cache::find_first_device_with_tag`](crate::core::cache::Cache::find_first_device_with_tag)<br>[`Cache::find_and_cache_first_device_with_tag`](crate::core::cache::Cache::find_and_cache_first_device_with_tag) |
-//! | [`mnt_resolve_target`][364]       | [`Cache::find_device_mounted_at`](crate::core::cache::Cache::find_device_mounted_at)<br>[`Cache::find_and_cache_device_mounted_at`](crate::core::cache::Cache::find_and_cache_device_mounted_at)                 |
+//! | `libmount`                        | `rsmount`                                                                                                                                                                                                                                                                                |
+//! | ------------------                | ---------                                                                                                                                                                                                                                                                                |
+//! | [`struct libmnt_cache`][349]      | [`Cache`](crate::core::cache::Cache)                                                                                                                                                                                                                                                     |
+//! | [`mnt_new_cache`][350]            | [`Cache::new`](crate::core::cache::Cache::new)                                                                                                                                                                                                                                           |
+//! | [`mnt_free_cache`][351]           | [`Cache`](crate::core::cache::Cache) is automatically deallocated when it goes out of scope.                                                                                                                                                                                             |
+//! | [`mnt_ref_cache`][352]            | Managed automatically.                                                                                                                                                                                                                                                                   |
+//! | [`mnt_unref_cache`][353]          | Managed automatically.                                                                                                                                                                                                                                                                   |
+//! | [`mnt_cache_device_has_tag`][354] | [`Cache::device_has_tag`](crate::core::cache::Cache::device_has_tag)                                                                                                                                                                                                                     |
+//! | [`mnt_cache_find_tag_value`][355] | [`Cache::find_tag_value`](crate::core::cache::Cache::find_tag_value)                                                                                                                                                                                                                     |
+//! | [`mnt_cache_read_tags`][356]      | [`Cache::import_tags`](crate::core::cache::Cache::import_tags)                                                                                                                                                                                                                           |
+//! | [`mnt_cache_set_targets`][357]    | [`Cache::import_paths`](crate::core::cache::Cache::import_paths)                                                                                                                                                                                                                         |
+//! | [`mnt_cache_set_sbprobe`][358]    | [`Cache::collect_fs_properties`](crate::core::cache::Cache::collect_fs_properties)                                                                                                                                                                                                       |
+//! | [`mnt_get_fstype`][359]           | [`Cache::find_file_system_type`](crate::core::cache::Cache::find_file_system_type)<br>[`Cache::find_and_cache_file_system_type`](crate::core::cache::Cache::find_and_cache_file_system_type)                                                                                             |
+//! | [`mnt_pretty_path`][360]          | [`Cache::canonicalize`](crate::core::cache::Cache::canonicalize)<br>[`Cache::canonicalize_and_cache`](crate::core::cache::Cache::canonicalize_and_cache)                                                                                                                                 |
+//! | [`mnt_resolve_path`][361]         | [`Cache::resolve`](crate::core::cache::Cache::resolve)<br>[`Cache::resolve_and_cache`](crate::core::cache::Cache::resolve_and_cache)                                                                                                                                                     |
+//! | [`mnt_resolve_spec`][362]         | Not implemented. Use the specialized functions corresponding to `mnt_resolve_path` or `mnt_resolve_tag` as applicable.                                                                                                                                                                   |
+//! | [`mnt_resolve_tag`][363]          | [`Cache::find_first_device_with_tag`](crate::core::cache::Cache::find_first_device_with_tag)<br>[`Cache::find_and_cache_first_device_with_tag`](crate::core::cache::Cache::find_and_cache_first_device_with_tag)<br>[`Cache::resolve_source`](crate::core::cache::Cache::resolve_source) |
+//! | [`mnt_resolve_target`][364]       | [`Cache::find_device_mounted_at`](crate::core::cache::Cache::find_device_mounted_at)<br>[`Cache::find_and_cache_device_mounted_at`](crate::core::cache::Cache::find_and_cache_device_mounted_at)                                                                                         |
 //!
 //! [349]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libmount-docs/libmount-Cache.html#libmnt-cache
 //! [350]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libmount-docs/libmount-Cache.html#mnt-new-cache
@@ -962,20 +969,20 @@
 //!
 //! #### Utils
 //!
-//! | `libmount`                      | `rsmount`                                                                                             |
-//! | ------------------              | ---------                                                                                             |
-//! | [`mnt_fstype_is_netfs`][370]    | [`core::utils::is_network_fs`]                                                                        |
-//! | [`mnt_fstype_is_pseudofs`][371] | [`core::utils::is_pseudo_fs`]                                                                         |
-//! | [`mnt_get_fstab_path`][372]     | [`core::utils::path_to_fstab`]                                                                        |
-//! | [`mnt_get_mountpoint`][373]     | [`core::utils::find_device_mountpoint`]                                                               |
-//! | [`mnt_get_mtab_path`][374]      | Deprecated.                                                                                           |
-//! | [`mnt_get_swaps_path`][375]     | [`core::utils::path_to_swaps`]                                                                        |
-//! | [`mnt_guess_system_root`][376]  | [`core::utils::device_number_to_device_name`]<br>[`core::utils::device_number_to_cached_device_name`] |
-//! | [`mnt_has_regular_mtab`][377]   | Deprecated.                                                                                           |
-//! | [`mnt_mangle`][378]             | [`core::utils::fstab_encode`]                                                                         |
-//! | [`mnt_match_fstype`][379]       | [`core::utils::matches_fs_type`]                                                                      |
-//! | [`mnt_tag_is_valid`][380]       | Not implemented. [`Tag`](crate::core::device::Tag)s are valid by definition.                          |
-//! | [`mnt_unmangle`][381]           | [`core::utils::fstab_decode`]                                                                         |
+//! | `libmount`                      | `rsmount`                                                                                               |
+//! | ------------------              | ---------                                                                                               |
+//! | [`mnt_fstype_is_netfs`][370]    | [`core::utils::is_network_fs`] <br> [`FileSystem::is_network`](crate::core::fs::FileSystem::is_network) |
+//! | [`mnt_fstype_is_pseudofs`][371] | [`core::utils::is_pseudo_fs`] <br> [`FileSystem::is_pseudo`](crate::core::fs::FileSystem::is_pseudo)    |
+//! | [`mnt_get_fstab_path`][372]     | [`core::utils::path_to_fstab`]                                                                          |
+//! | [`mnt_get_mountpoint`][373]     | [`core::utils::find_device_mountpoint`]                                                                 |
+//! | [`mnt_get_mtab_path`][374]      | Deprecated.                                                                                             |
+//! | [`mnt_get_swaps_path`][375]     | [`core::utils::path_to_swaps`]                                                                          |
+//! | [`mnt_guess_system_root`][376]  | [`core::utils::device_number_to_device_name`]<br>[`core::utils::device_number_to_cached_device_name`]   |
+//! | [`mnt_has_regular_mtab`][377]   | Deprecated.                                                                                             |
+//! | [`mnt_mangle`][378]             | [`core::utils::fstab_encode`]                                                                           |
+//! | [`mnt_match_fstype`][379]       | [`core::utils::matches_fs_type`]                                                                        |
+//! | [`mnt_tag_is_valid`][380]       | Not implemented. [`Tag`](crate::core::device::Tag)s are valid by definition.                            |
+//! | [`mnt_unmangle`][381]           | [`core::utils::fstab_decode`]                                                                           |
 //!
 //! [370]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libmount-docs/libmount-Utils.html#mnt-fstype-is-netfs
 //! [371]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libmount-docs/libmount-Utils.html#mnt-fstype-is-pseudofs
@@ -999,7 +1006,7 @@
 //! | [`LIBMOUNT_PATCH_VERSION`][384]   | [`core::version::VERSION_NUMBER_PATCH`]           |
 //! | [`LIBMOUNT_VERSION`][385]         | [`core::version::VERSION_STRING`]                 |
 //! | [`mnt_parse_version_string`][386] | [`core::version::version_string_to_release_code`] |
-//! | [`mnt_get_library_version`][387]  | [`core::version::VERSION_STRING`]                 |
+//! | [`mnt_get_library_version`][387]  | [`core::version::library_version`]                |
 //! | [`mnt_get_library_features`][388] | [`core::version::library_features`]               |
 //!
 //! [382]: https://mirrors.edge.kernel.org/pub/linux/utils/util-linux/v2.39/libmount-docs/libmount-Version-functions.html#LIBMOUNT-MAJOR-VERSION:CAPS
@@ -1024,6 +1031,7 @@ mod prelude {}
 pub use core::flags;
 pub use core::fs;
 pub use core::iter;
+pub use core::optmap;
 pub use core::optstring;
 pub use core::utils;
 pub use core::version;
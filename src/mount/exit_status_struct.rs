@@ -13,16 +13,22 @@
 pub struct ExitStatus {
     exit_code: ExitCode,
     error_message: String,
+    syscall_errno: Option<i32>,
 }
 
 impl ExitStatus {
     #[doc(hidden)]
     /// Creates a  new `ExitStatus`.
     #[allow(dead_code)]
-    pub(crate) fn new(exit_code: ExitCode, error_message: String) -> ExitStatus {
+    pub(crate) fn new(
+        exit_code: ExitCode,
+        error_message: String,
+        syscall_errno: Option<i32>,
+    ) -> ExitStatus {
         Self {
             exit_code,
             error_message,
+            syscall_errno,
         }
     }
 
@@ -36,4 +42,11 @@ pub fn exit_code(&self) -> &ExitCode {
     pub fn error_message(&self) -> &str {
         &self.error_message
     }
+
+    /// Returns the [errno](https://www.man7.org/linux/man-pages/man3/errno.3.html) set by a
+    /// failed `mount`/`umount` syscall (e.g. `libc::EBUSY`), or `None` if the syscall was never
+    /// invoked, or ran successfully.
+    pub fn syscall_errno(&self) -> Option<i32> {
+        self.syscall_errno
+    }
 }
@@ -60,7 +60,14 @@ pub(super) struct MntBuilder {
     ))]
     disable_mount_point_lookup: bool,
 
-    #[builder(setter(strip_bool, doc = "Disables path canonicalization."))]
+    #[builder(setter(
+        strip_bool,
+        doc = "Disables path canonicalization. Source and target paths are used verbatim,
+symbolic links are not resolved.
+
+**Note:** the [`Cache`] set with [`MountBuilder::override_cache`] is not used to canonicalize
+paths when this option is set, but is still used to resolve tags (e.g. `UUID=uuid`)."
+    ))]
     disable_path_canonicalization: bool,
 
     #[builder(setter(
@@ -112,7 +119,10 @@ pub(super) struct MntBuilder {
 faster execution; NFS timeouts proceed also in parallel.
 
 A disadvantage is the order of mount operations is undefined. Thus, you cannot use this
-option if you want to mount both `/usr` and `/usr/spool` which have to be mounted in order."
+option if you want to mount both `/usr` and `/usr/spool` which have to be mounted in order.
+
+**Warning:** child processes are created with `fork`, and must exit as soon as they are done
+mounting their device (see [`Mount::seq_mount`], and [`Mount::wait_on_children`])."
     ))]
     parallel_mount: bool,
 
@@ -123,11 +133,19 @@ pub(super) struct MntBuilder {
 
 **Warning:** This function is designed for cases where you lack `suid` permissions, but still want to directly
 manage a mount through the kernel. Please note that bypassing `libmount`'s safety checks is a very **DANGEROUS**
-exercise. Careful what you wish for!"
+exercise. Careful what you wish for!
+
+**Security:** a process running with reduced privileges (e.g. a `setuid` program run by a regular user)
+is restricted to the mount options an ordinary user may set. Only lift this restriction after checking,
+out-of-band, that the caller is entitled to privileged mounts (see [`Mount::is_user_mount`])."
     ))]
     force_user_mount: bool,
 
-    #[builder(setter(strip_bool, doc = "Enables verbose output."))]
+    #[builder(setter(
+        strip_bool,
+        doc = "Enables verbose output, reported through the `log` crate at the `info` level (see
+[`Mount::enable_verbose_output`])."
+    ))]
     verbose: bool,
 
     #[builder(
@@ -217,8 +235,10 @@ pub(super) struct MntBuilder {
             transform = |options: impl AsRef<str>| Some(options.as_ref().to_string()),
             doc = "Sets a comma-delimited list of mount options to use for
 selecting, and then mounting `/etc/fstab` entries matching any of the elements in the list.
-(see the [`mount` command](https://www.man7.org/linux/man-pages/man8/mount.8.html#COMMAND-LINE_OPTIONS)
-manpage, option `-O, --test-opts` for more information.)"
+Options prefixed with `no` select entries without the option, following the same rules as
+[`optstring::matches_any_option`](crate::core::optstring::matches_any_option). (see the [`mount`
+command](https://www.man7.org/linux/man-pages/man8/mount.8.html#COMMAND-LINE_OPTIONS) manpage,
+option `-O, --test-opts` for more information.)"
         )
     )]
     match_mount_options: Option<String>,
@@ -274,7 +294,10 @@ pub(super) struct MntBuilder {
         default,
         setter(
             transform = |path: impl AsRef<Path>| Some(path.as_ref().to_path_buf()),
-            doc = "Sets the target's Linux namespace to the one provided by `path`."
+            doc = "Sets the target's Linux namespace to the one provided by `path` (e.g.
+`/proc/<pid>/ns/mnt`).
+
+**Note:** switching namespaces requires the `CAP_SYS_ADMIN` capability."
         )
     )]
     target_namespace: Option<PathBuf>,
@@ -386,7 +409,7 @@ pub fn build(self) -> Result<Mount, MountBuilderError> {
         }
 
         if let Some(data) = builder.mount_data {
-            mount.set_mount_data(data)?;
+            mount.set_mount_data_ptr(data)?;
         }
 
         // if let Some(entry) = builder.override_table_entry {
@@ -31,6 +31,10 @@ pub enum MountError {
     #[error("failed to convert value to `CString`: {0}")]
     CStringConversion(#[from] NulError),
 
+    /// Error if no entry in `/etc/fstab` matches a [`Mount`](crate::mount::Mount)'s source or target.
+    #[error("{0}")]
+    FsTabMissingEntry(String),
+
     /// Error if `libmount` was compiled without namespace support.
     #[error("{0}")]
     NoNamespaceSupport(String),
@@ -59,14 +59,18 @@ fn next(&mut self) -> Option<Self::Item> {
                 let ptr = unsafe { entry_ptr.assume_init() };
                 let rc = unsafe { mount_return_code.assume_init() };
                 let skipped = unsafe { ignored.assume_init() };
-                let entry = <FsTabEntry>::from_ptr(ptr);
+                let entry = <FsTabEntry>::borrow_ptr(ptr);
 
-                match (rc, skipped) {
-                    (0, 0) => Some(StepResult::MountSuccess(entry)),
-                    (_, 1) => Some(StepResult::MountSkipped(entry)),
-                    (_, 2) => Some(StepResult::MountAlreadyDone(entry)),
-                    (_, _) => Some(StepResult::MountFail(entry)),
-                }
+                let step = match (rc, skipped) {
+                    (0, 0) => StepResult::MountSuccess(entry),
+                    (_, 1) => StepResult::MountSkipped(entry),
+                    (_, 2) => StepResult::MountAlreadyDone(entry),
+                    (_, _) => StepResult::MountFail(entry),
+                };
+
+                self.mount.log_verbose_step(&step, rc);
+
+                Some(step)
             }
             1 => {
                 log::debug!("MountIter::next reached the end of `MountIter`");
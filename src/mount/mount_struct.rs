@@ -14,11 +14,14 @@
 
 // From this library
 use crate::core::cache::Cache;
+use crate::core::device::MountPoint;
 use crate::core::device::Tag;
 use crate::core::entries::{FsTabEntry, MountInfoEntry};
 use crate::core::flags::MountFlag;
+use crate::core::flags::PropagationMode;
 use crate::core::flags::UserspaceMountFlag;
 use crate::core::fs::{FileLock, FileSystem};
+use crate::core::optstring;
 use crate::tables::{FsTab, GcItem, MountInfo};
 use crate::{owning_mut_from_ptr, owning_ref_from_ptr};
 
@@ -27,6 +30,7 @@
 use crate::mount::ExitStatus;
 use crate::mount::MntBuilder;
 use crate::mount::MountBuilder;
+use crate::mount::MountBuilderError;
 use crate::mount::MountError;
 use crate::mount::MountIter;
 use crate::mount::MountNamespace;
@@ -34,12 +38,15 @@
 use crate::mount::MountSource;
 use crate::mount::ProcessExitStatus;
 use crate::mount::ReMountIter;
+use crate::mount::StepResult;
 
 /// Object to mount/unmount a device.
 #[derive(Debug)]
 pub struct Mount {
     pub(crate) inner: *mut libmount::libmnt_context,
     pub(crate) gc: Vec<GcItem>,
+    // Data passed verbatim to the `mount` syscall, libmount only keeps a pointer to it.
+    pub(crate) mount_data: Option<CString>,
 }
 
 impl Mount {
@@ -50,6 +57,7 @@ pub(crate) fn from_ptr(ptr: *mut libmount::libmnt_context) -> Mount {
         Self {
             inner: ptr,
             gc: vec![],
+            mount_data: None,
         }
     }
 
@@ -84,10 +92,96 @@ pub(crate) fn new() -> Result<Mount, MountError> {
     }
 
     #[doc(hidden)]
-    /// Converts a function's return code to unified `libmount` exit code.
+    /// Reports the outcome of a mount at the `info` log level, the way `mount --verbose` does.
+    fn log_verbose_output(&self, exit_status: &ExitStatus) {
+        let source = self.source().unwrap_or_else(|| String::from("none"));
+        let target = self.target().unwrap_or_default();
+
+        match exit_status.exit_code() {
+            ExitCode::Success => log::info!("{} mounted on {}", source, target.display()),
+            _ => log::info!(
+                "failed to mount {} on {}: {}",
+                source,
+                target.display(),
+                exit_status.error_message()
+            ),
+        }
+    }
+
+    #[doc(hidden)]
+    /// Reports the outcome of a step of [`Mount::seq_mount`] or [`Mount::seq_remount`] at the
+    /// `info` log level, the way `mount --all --verbose` does.
+    pub(crate) fn log_verbose_step(&self, step: &StepResult, return_code: i32) {
+        if !self.is_verbose() {
+            return;
+        }
+
+        let error_message = || {
+            self.exit_status_from(return_code)
+                .map(|exit_status| exit_status.error_message().to_owned())
+                .unwrap_or_default()
+        };
+
+        match step {
+            StepResult::MountSuccess(entry)
+            | StepResult::MountFail(entry)
+            | StepResult::MountSkipped(entry)
+            | StepResult::MountAlreadyDone(entry) => {
+                let source = entry
+                    .source()
+                    .map(|source| source.to_string())
+                    .unwrap_or_else(|| String::from("none"));
+                let target = entry.target().unwrap_or(Path::new("none")).display();
+
+                match step {
+                    StepResult::MountSuccess(_) => log::info!("{} mounted on {}", source, target),
+                    StepResult::MountSkipped(_) => log::info!("{}: ignored", target),
+                    StepResult::MountAlreadyDone(_) => log::info!("{}: already mounted", target),
+                    _ => log::info!(
+                        "failed to mount {} on {}: {}",
+                        source,
+                        target,
+                        error_message()
+                    ),
+                }
+            }
+            StepResult::ReMountSuccess(entry)
+            | StepResult::ReMountFail(entry)
+            | StepResult::ReMountSkipped(entry)
+            | StepResult::ReMountAlreadyDone(entry) => {
+                let target = entry.target().unwrap_or(Path::new("none")).display();
+
+                match step {
+                    StepResult::ReMountSuccess(_) => log::info!("{} remounted", target),
+                    StepResult::ReMountSkipped(_) => log::info!("{}: ignored", target),
+                    StepResult::ReMountAlreadyDone(_) => {
+                        log::info!("{}: already remounted", target)
+                    }
+                    _ => log::info!("failed to remount {}: {}", target, error_message()),
+                }
+            }
+            _unmount_step => {}
+        }
+    }
+
+    #[doc(hidden)]
+    /// Converts a function's return code to unified `libmount` exit code, reporting the outcome
+    /// in verbose mode.
     fn return_code_to_exit_status(&self, return_code: i32) -> Result<ExitStatus, MountError> {
+        let exit_status = self.exit_status_from(return_code)?;
+
+        if self.is_verbose() {
+            self.log_verbose_output(&exit_status);
+        }
+
+        Ok(exit_status)
+    }
+
+    #[doc(hidden)]
+    /// Converts a function's return code to unified `libmount` exit code.
+    fn exit_status_from(&self, return_code: i32) -> Result<ExitStatus, MountError> {
         log::debug!(
-            "Mount::return_code_to_exit_status converting to exit status the return code: {:?}",
+            "Mount::exit_status_from converting to exit status the return code: {:?}",
             return_code
         );
 
@@ -105,10 +199,11 @@ fn return_code_to_exit_status(&self, return_code: i32) -> Result<ExitStatus, Mou
 
         let exit_code = ExitCode::try_from(rc)?;
         let error_message = ffi_utils::c_char_array_to_string(buffer.as_ptr());
-        let exit_status = ExitStatus::new(exit_code, error_message);
+        let syscall_errno = self.mount_syscall_errno();
+        let exit_status = ExitStatus::new(exit_code, error_message, syscall_errno);
 
         log::debug!(
-            "Mount::return_code_to_exit_status converted return code: {:?} to exit status {:?}",
+            "Mount::exit_status_from converted return code: {:?} to exit status {:?}",
             return_code,
             exit_status
         );
@@ -563,17 +658,21 @@ fn enable_verbose(
         }
     }
 
-    #[doc(hidden)]
-    /// Enables `Mount` to check that a device is not already mounted before mounting it.
-    pub(crate) fn enable_verbose_output(&mut self) -> Result<(), MountError> {
+    /// Enables verbose output.
+    ///
+    /// In verbose mode, `Mount` reports the outcome of each mount, including each step of
+    /// [`Mount::seq_mount`] and [`Mount::seq_remount`], through the
+    /// [log](https://crates.io/crates/log) *facade* at the `info` level (e.g. `none mounted on
+    /// /mnt`), and passes the `-v` flag to the mount helpers it runs. See the [`debug`](crate::debug)
+    /// module for a list of loggers to display these messages.
+    pub fn enable_verbose_output(&mut self) -> Result<(), MountError> {
         log::debug!("Mount::enable_verbose_output enabling verbose output");
 
         Self::enable_verbose(self.inner, true)
     }
 
-    #[doc(hidden)]
-    /// Disables `Mount` to check that a device is not already mounted before mounting it.
-    pub(crate) fn disable_verbose_output(&mut self) -> Result<(), MountError> {
+    /// Disables verbose output.
+    pub fn disable_verbose_output(&mut self) -> Result<(), MountError> {
         log::debug!("Mount::disable_verbose_output disabling verbose output");
 
         Self::enable_verbose(self.inner, false)
@@ -772,20 +871,23 @@ pub(crate) fn set_file_systems_filter<T>(&mut self, fs_list: T) -> Result<(), Mo
 
     #[doc(hidden)]
     /// Overrides the data argument of the [`mount` syscall](https://www.man7.org/linux/man-pages/man2/mount.2.html).
-    pub(crate) fn set_mount_data(&mut self, data: NonNull<libc::c_void>) -> Result<(), MountError> {
-        log::debug!("Mount::set_mount_data overriding data argument of mount syscall");
+    pub(crate) fn set_mount_data_ptr(
+        &mut self,
+        data: NonNull<libc::c_void>,
+    ) -> Result<(), MountError> {
+        log::debug!("Mount::set_mount_data_ptr overriding data argument of mount syscall");
 
         let result = unsafe { libmount::mnt_context_set_mountdata(self.inner, data.as_ptr()) };
 
         match result {
             0 => {
-                log::debug!("Mount::set_mount_data overrode data argument of mount syscall");
+                log::debug!("Mount::set_mount_data_ptr overrode data argument of mount syscall");
 
                 Ok(())
             }
             code => {
                 let err_msg = "failed to override data argument of mount syscall".to_owned();
-                log::debug!("Mount::set_mount_data {}. libmount::mnt_context_set_mountdata returned error code: {:?}", err_msg, code);
+                log::debug!("Mount::set_mount_data_ptr {}. libmount::mnt_context_set_mountdata returned error code: {:?}", err_msg, code);
 
                 Err(MountError::Config(err_msg))
             }
@@ -914,7 +1016,6 @@ fn set_source(mount: *mut libmount::libmnt_context, source: CString) -> Result<(
         }
     }
 
-    #[doc(hidden)]
     /// Sets the location/ID of the device to mount.
     ///
     /// A source can take any of the following forms:
@@ -931,7 +1032,7 @@ fn set_source(mount: *mut libmount::libmnt_context, source: CString) -> Result<(
     ///     - `PARTLABEL=label`,
     ///     - `PARTUUID=uuid`,
     ///     - `ID=id`.
-    pub(crate) fn set_mount_source(&mut self, source: MountSource) -> Result<(), MountError> {
+    pub fn set_mount_source(&mut self, source: MountSource) -> Result<(), MountError> {
         let source = source.to_string();
         let source_cstr = ffi_utils::as_ref_path_to_c_string(&source)?;
         log::debug!("Mount::set_mount_source setting mount source: {:?}", source);
@@ -939,9 +1040,8 @@ pub(crate) fn set_mount_source(&mut self, source: MountSource) -> Result<(), Mou
         Self::set_source(self.inner, source_cstr)
     }
 
-    #[doc(hidden)]
     /// Sets this `Mount`'s mount point.
-    pub(crate) fn set_mount_target<T>(&mut self, target: T) -> Result<(), MountError>
+    pub fn set_mount_target<T>(&mut self, target: T) -> Result<(), MountError>
     where
         T: AsRef<Path>,
     {
@@ -999,6 +1099,16 @@ pub(crate) fn set_mount_target_namespace<T>(&mut self, path: T) -> Result<(), Mo
 
                 Err(MountError::NoNamespaceSupport(err_msg))
             }
+            code if code < 0 => {
+                let err_msg = format!(
+                    "failed to set mount target namespace: {:?}. {}",
+                    path,
+                    std::io::Error::from_raw_os_error(-code)
+                );
+                log::debug!("Mount::set_mount_target_namespace {}. libmount::mnt_context_set_target_ns returned error code: {:?}", err_msg, code);
+
+                Err(MountError::Config(err_msg))
+            }
             code => {
                 let err_msg = format!("failed to set mount target namespace: {:?}", path);
                 log::debug!("Mount::set_mount_target_namespace {}. libmount::mnt_context_set_target_ns returned error code: {:?}", err_msg, code);
@@ -1041,73 +1151,166 @@ pub(crate) fn set_mount_target_prefix<T>(&mut self, path: T) -> Result<(), Mount
             }
         }
     }
+    //---- END setters
 
-    #[doc(hidden)]
-    /// Sets userspace mount flags.
-    pub(crate) fn set_userspace_mount_flags(
+    //---- BEGIN mutators
+    /// Creates a [`MountBuilder`] to configure and construct a new `Mount`
+    /// instance.
+    ///
+    /// Call the `MountBuilder`'s [`build()`](MountBuilder::build) method to
+    /// construct a new `Mount` instance.
+    pub fn builder() -> MountBuilder {
+        log::debug!("Mount::builder creating new `MountBuilder` instance");
+        MntBuilder::builder()
+    }
+
+    /// Creates a `Mount` configured to bind mount the directory, or file, at `source` on `target`.
+    ///
+    /// Sets the [`MountFlag::Bind`] flag, and [`MountFlag::Recursive`] when `recursive` is `true`
+    /// (equivalent to `mount --rbind`), in which case the file systems mounted under `source` are
+    /// also accessible from `target`. Use [`Mount::set_mount_flags`] to add other flags before
+    /// calling [`Mount::mount_device`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use std::collections::HashSet;
+    /// use rsmount::flags::MountFlag;
+    /// use rsmount::mount::Mount;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     // Configure a `Mount` to make `/boot` accessible from `/bind/mnt/boot`.
+    ///     let mount = Mount::bind("/boot", "/bind/mnt/boot", false)?;
+    ///
+    ///     let actual = mount.mount_flags();
+    ///     let expected = Some(HashSet::from([MountFlag::Bind]));
+    ///     assert_eq!(actual, expected);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn bind<S, T>(source: S, target: T, recursive: bool) -> Result<Mount, MountBuilderError>
+    where
+        S: AsRef<Path>,
+        T: AsRef<Path>,
+    {
+        let source = source.as_ref();
+        let target = target.as_ref();
+        log::debug!(
+            "Mount::bind creating `Mount` to bind mount {:?} on {:?} (recursive: {:?})",
+            source,
+            target,
+            recursive
+        );
+
+        let flags = if recursive {
+            vec![MountFlag::Bind, MountFlag::Recursive]
+        } else {
+            vec![MountFlag::Bind]
+        };
+
+        Mount::builder()
+            .source(MountPoint::new(source))
+            .target(target)
+            .mount_flags(flags)
+            .build()
+    }
+
+    /// Configures this `Mount` to change the propagation type of the mount point at `target` to
+    /// `mode`, instead of mounting a device. When `recursive` is `true`, the propagation type of
+    /// all the mount points under `target` is changed as well (equivalent to `mount
+    /// --make-rshared`, `--make-rprivate`, etc.).
+    ///
+    /// The change is applied by the next call to [`Mount::mount_device`].
+    ///
+    /// **Note:** this method replaces the mount flags already set.
+    pub fn set_propagation<T>(
         &mut self,
-        flags: Vec<UserspaceMountFlag>,
-    ) -> Result<(), MountError> {
+        target: T,
+        mode: PropagationMode,
+        recursive: bool,
+    ) -> Result<(), MountError>
+    where
+        T: AsRef<Path>,
+    {
+        let target = target.as_ref();
         log::debug!(
-            "Mount::set_userspace_mount_flags setting userspace mount flags: {:?}",
-            flags
+            "Mount::set_propagation setting propagation type of {:?} to {:?} (recursive: {:?})",
+            target,
+            mode,
+            recursive
         );
 
+        let mut flags = vec![MountFlag::from(mode)];
+        if recursive {
+            flags.push(MountFlag::Recursive);
+        }
+
+        self.set_mount_target(target)?;
+        self.set_mount_flags(flags)
+    }
+
+    /// Sets this `Mount`'s mount flags.
+    ///
+    /// Accepts any collection of [`MountFlag`]s (e.g. a `Vec` or a `HashSet`), which are combined
+    /// into a single bitmask.
+    pub fn set_mount_flags<T>(&mut self, flags: T) -> Result<(), MountError>
+    where
+        T: IntoIterator<Item = MountFlag>,
+    {
+        let flags: Vec<_> = flags.into_iter().collect();
+        log::debug!("Mount::set_mount_flags setting mount flags: {:?}", flags);
+
         let bits = flags.iter().fold(0, |acc, &flag| acc | (flag as u64));
 
-        let result = unsafe { libmount::mnt_context_set_user_mflags(self.inner, bits) };
+        let result = unsafe { libmount::mnt_context_set_mflags(self.inner, bits) };
 
         match result {
             0 => {
-                log::debug!(
-                    "Mount::set_userspace_mount_flags set userspace mount flags: {:?}",
-                    flags
-                );
+                log::debug!("Mount::set_mount_flags set mount flags: {:?}", flags);
 
                 Ok(())
             }
             code => {
-                let err_msg = format!("failed to set userspace mount flags: {:?}", flags);
-                log::debug!("Mount::set_userspace_mount_flags {}. libmount::mnt_context_set_user_mflags returned error code: {:?}", err_msg, code);
+                let err_msg = format!("failed to set mount flags: {:?}", flags);
+                log::debug!("Mount::set_mount_flags {}. libmount::mnt_context_set_mflags returned error code: {:?}", err_msg, code);
 
                 Err(MountError::Config(err_msg))
             }
         }
     }
-    //---- END setters
 
-    //---- BEGIN mutators
-    /// Creates a [`MountBuilder`] to configure and construct a new `Mount`
-    /// instance.
+    /// Sets this `Mount`'s userspace mount flags.
     ///
-    /// Call the `MountBuilder`'s [`build()`](MountBuilder::build) method to
-    /// construct a new `Mount` instance.
-    pub fn builder() -> MountBuilder {
-        log::debug!("Mount::builder creating new `MountBuilder` instance");
-        MntBuilder::builder()
-    }
-
-    /// Sets this `Mount`'s mount flags.
-    pub fn set_mount_flags<T>(&mut self, flags: T) -> Result<(), MountError>
+    /// Accepts any collection of [`UserspaceMountFlag`]s (e.g. a `Vec` or a `HashSet`), which are
+    /// combined into a single bitmask.
+    pub fn set_userspace_mount_flags<T>(&mut self, flags: T) -> Result<(), MountError>
     where
-        T: AsRef<[MountFlag]>,
+        T: IntoIterator<Item = UserspaceMountFlag>,
     {
-        let flags = flags.as_ref();
-        log::debug!("Mount::set_mount_flags setting mount flags: {:?}", flags);
+        let flags: Vec<_> = flags.into_iter().collect();
+        log::debug!(
+            "Mount::set_userspace_mount_flags setting userspace mount flags: {:?}",
+            flags
+        );
 
         let bits = flags.iter().fold(0, |acc, &flag| acc | (flag as u64));
 
-        let result = unsafe { libmount::mnt_context_set_mflags(self.inner, bits) };
+        let result = unsafe { libmount::mnt_context_set_user_mflags(self.inner, bits) };
 
         match result {
             0 => {
-                log::debug!("Mount::set_mount_flags set mount flags: {:?}", flags);
+                log::debug!(
+                    "Mount::set_userspace_mount_flags set userspace mount flags: {:?}",
+                    flags
+                );
 
                 Ok(())
             }
             code => {
-                let err_msg = format!("failed to set mount flags: {:?}", flags);
-                log::debug!("Mount::set_mount_flags {}. libmount::mnt_context_set_mflags returned error code: {:?}", err_msg, code);
+                let err_msg = format!("failed to set userspace mount flags: {:?}", flags);
+                log::debug!("Mount::set_userspace_mount_flags {}. libmount::mnt_context_set_user_mflags returned error code: {:?}", err_msg, code);
 
                 Err(MountError::Config(err_msg))
             }
@@ -1176,6 +1379,124 @@ pub fn append_mount_options<T>(&mut self, option_list: T) -> Result<(), MountErr
         }
     }
 
+    /// Sets the raw data passed, as is, to the [`mount`
+    /// syscall](https://www.man7.org/linux/man-pages/man2/mount.2.html) as its *data* argument.
+    ///
+    /// Unlike options set by [`MountBuilder::mount_options`], or [`Mount::append_mount_options`],
+    /// `data` is not parsed as a comma-separated list of options, nor merged with options from
+    /// `fstab`. Use it for file systems expecting data `libmount` would otherwise rewrite (e.g. an
+    /// SELinux `context=` with commas in its value).
+    ///
+    /// **Note:** `data` overrides the *data* argument `libmount` computes from the list of mount
+    /// options.
+    pub fn set_mount_data<T>(&mut self, data: T) -> Result<(), MountError>
+    where
+        T: AsRef<str>,
+    {
+        let data = data.as_ref();
+        log::debug!("Mount::set_mount_data setting mount data: {:?}", data);
+
+        let data_cstr = ffi_utils::as_ref_str_to_c_string(data)?;
+        let ptr = NonNull::from(data_cstr.as_bytes_with_nul()).cast::<libc::c_void>();
+
+        self.set_mount_data_ptr(ptr)?;
+        // Keep the data alive for as long as `libmount` may read it.
+        self.mount_data = Some(data_cstr);
+
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Sets the value of a loop device option (e.g. `offset`), and adds the `loop` option to the
+    /// list of mount options if it is not already present.
+    fn set_loop_device_option(&mut self, option_name: &str, value: u64) -> Result<(), MountError> {
+        let options_list = self.mount_options().unwrap_or_default();
+        if !optstring::matches_any_option(&options_list, "loop") {
+            self.append_mount_options("loop")?;
+        }
+
+        let options_list = self.mount_options().unwrap_or_default();
+        let value = value.to_string();
+
+        // Update the option's value if it is already set, append it otherwise.
+        let options_list = optstring::set_option_value(&options_list, option_name, &value)
+            .or_else(|| optstring::append_option(&options_list, option_name, &value));
+
+        match options_list {
+            Some(options_list) => self.set_mount_options(options_list),
+            None => {
+                let err_msg = format!("failed to set loop device option {}={}", option_name, value);
+                log::debug!("Mount::set_loop_device_option {}", err_msg);
+
+                Err(MountError::Config(err_msg))
+            }
+        }
+    }
+
+    /// Sets the offset, in bytes, at which the loop device set up for mounting an image file
+    /// starts. This allows mounting a partition inside a disk image.
+    ///
+    /// Adds the `loop,offset=<offset>` options (see [`UserspaceMountFlag::LoopDevice`], and
+    /// [`UserspaceMountFlag::LoopDeviceOffset`]) to the list of mount options, or updates the
+    /// value of `offset` if it is already set.
+    pub fn set_loop_offset(&mut self, offset: u64) -> Result<(), MountError> {
+        log::debug!(
+            "Mount::set_loop_offset setting loop device offset: {:?}",
+            offset
+        );
+
+        self.set_loop_device_option("offset", offset)
+    }
+
+    /// Sets the maximum size, in bytes, of the loop device set up for mounting an image file
+    /// (counting from the offset set by [`Mount::set_loop_offset`]).
+    ///
+    /// Adds the `loop,sizelimit=<size>` options (see [`UserspaceMountFlag::LoopDevice`], and
+    /// [`UserspaceMountFlag::LoopDeviceSizeLimit`]) to the list of mount options, or updates the
+    /// value of `sizelimit` if it is already set.
+    pub fn set_loop_size_limit(&mut self, size: u64) -> Result<(), MountError> {
+        log::debug!(
+            "Mount::set_loop_size_limit setting loop device size limit: {:?}",
+            size
+        );
+
+        self.set_loop_device_option("sizelimit", size)
+    }
+
+    /// Enables/disables the deletion of the loop device set up for mounting an image file when
+    /// the image is unmounted.
+    pub fn enable_loop_delete_on_umount(&mut self, enable: bool) -> Result<(), MountError> {
+        let op = if enable { 1 } else { 0 };
+        let op_str = if enable {
+            "enable".to_owned()
+        } else {
+            "disable".to_owned()
+        };
+        log::debug!(
+            "Mount::enable_loop_delete_on_umount setting loop device deletion on unmount: {:?}",
+            enable
+        );
+
+        let result = unsafe { libmount::mnt_context_enable_loopdel(self.inner, op) };
+
+        match result {
+            0 => {
+                log::debug!(
+                    "Mount::enable_loop_delete_on_umount {}d loop device deletion on unmount",
+                    op_str
+                );
+
+                Ok(())
+            }
+            code => {
+                let err_msg = format!("failed to {} loop device deletion on unmount", op_str);
+                log::debug!("Mount::enable_loop_delete_on_umount {}. libmount::mnt_context_enable_loopdel returned error code: {:?}", err_msg, code);
+
+                Err(MountError::Config(err_msg))
+            }
+        }
+    }
+
     /// Mounts a device using the [`mount` syscall](https://www.man7.org/linux/man-pages/man2/mount.2.html) and/or
     /// [`mount` helpers](https://www.man7.org/linux/man-pages/man8/mount.8.html#EXTERNAL_HELPERS).
     ///
@@ -1236,9 +1557,22 @@ pub fn call_mount_syscall(&mut self) -> Result<ExitStatus, MountError> {
     ///
     /// **Note:** you do not need to call this method if you are using [`Mount::mount_device`], it
     /// will take care of finalizing the mount.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MountError::Action`] if neither the `mount` syscall nor a mount helper was
+    /// invoked beforehand (i.e. when calling this method without first going through
+    /// [`Mount::prepare_mount`] and [`Mount::call_mount_syscall`]).
     pub fn finalize_mount(&mut self) -> Result<(), MountError> {
         log::debug!("Mount::finalize_mount finalizing mount");
 
+        if !(self.has_called_mount_syscall() || self.has_run_mount_helper()) {
+            let err_msg = "can not finalize a mount before calling the mount syscall".to_owned();
+            log::debug!("Mount::finalize_mount {}", err_msg);
+
+            return Err(MountError::Action(err_msg));
+        }
+
         let result = unsafe { libmount::mnt_context_finalize_mount(self.inner) };
 
         match result {
@@ -1256,6 +1590,47 @@ pub fn finalize_mount(&mut self) -> Result<(), MountError> {
         }
     }
 
+    /// Completes this `Mount`'s configuration with the matching entry in `/etc/fstab` (or in the
+    /// table set by [`MountBuilder::override_fstab`]).
+    ///
+    /// The entry is looked up by the source and/or target set when creating this `Mount`. Once
+    /// applied, the entry's source, file system type, and mount options are available through
+    /// the usual getters (e.g. [`Mount::source`], [`Mount::file_system_type`],
+    /// [`Mount::mount_options`]).
+    ///
+    /// **Note:** you do not need to call this method if you are using [`Mount::mount_device`] or
+    /// [`Mount::prepare_mount`], they will apply `/etc/fstab` automatically.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MountError::FsTabMissingEntry`] if no entry in the table matches the configured
+    /// source or target.
+    pub fn apply_fstab(&mut self) -> Result<(), MountError> {
+        log::debug!("Mount::apply_fstab applying fstab entry");
+
+        let result = unsafe { libmount::mnt_context_apply_fstab(self.inner) };
+
+        match result {
+            0 => {
+                log::debug!("Mount::apply_fstab applied fstab entry");
+
+                Ok(())
+            }
+            code if code == -libmount::MNT_ERR_NOFSTAB => {
+                let err_msg = "found no matching entry in fstab".to_owned();
+                log::debug!("Mount::apply_fstab {}. libmount::mnt_context_apply_fstab returned error code: {:?}", err_msg, code);
+
+                Err(MountError::FsTabMissingEntry(err_msg))
+            }
+            code => {
+                let err_msg = "failed to apply fstab entry".to_owned();
+                log::debug!("Mount::apply_fstab {}. libmount::mnt_context_apply_fstab returned error code: {:?}", err_msg, code);
+
+                Err(MountError::Action(err_msg))
+            }
+        }
+    }
+
     #[doc(hidden)]
     /// Searches `/proc/self/mountinfo` for an entry with a field matching the given `target`.
     fn find_mounted_entry<'a>(
@@ -1426,6 +1801,43 @@ pub fn reset_syscall_exit_status(&mut self) -> Result<(), MountError> {
         }
     }
 
+    /// Resets this `Mount` so that it can be reconfigured, and reused to mount another device.
+    ///
+    /// Clears the source, target, file system type, mount options, mount flags, and mount data,
+    /// as well as the outcome of the last mount. Flags set when building this `Mount` (e.g.
+    /// [`MountBuilder::dry_run`], [`MountBuilder::disable_helpers`], etc.), the `fstab` table, and
+    /// the cache are preserved.
+    ///
+    /// After a reset, describe the next device to mount with [`Mount::set_mount_source`],
+    /// [`Mount::set_mount_target`], [`Mount::set_file_system_type`], etc.
+    ///
+    /// To only clear the outcome of the last mount, see [`Mount::reset_syscall_exit_status`].
+    pub fn reset(&mut self) -> Result<(), MountError> {
+        log::debug!("Mount::reset resetting `Mount`");
+
+        let result = unsafe { libmount::mnt_reset_context(self.inner) };
+
+        match result {
+            0 => {
+                log::debug!("Mount::reset reset `Mount`");
+                // libmount forgets its pointer to the mount data.
+                self.mount_data = None;
+
+                Ok(())
+            }
+            code => {
+                let err_msg = "failed to reset `Mount`".to_owned();
+                log::debug!(
+                    "Mount::reset {}. libmount::mnt_reset_context returned error code: {:?}",
+                    err_msg,
+                    code
+                );
+
+                Err(MountError::Action(err_msg))
+            }
+        }
+    }
+
     /// Switches to the provided `namespace`, and returns the namespace used previously.
     pub fn switch_to_namespace(&mut self, namespace: MountNamespace) -> Option<MountNamespace> {
         log::debug!("Mount::switch_to_namespace switching namespace");
@@ -1478,6 +1890,8 @@ pub fn switch_to_original_namespace(&mut self) -> Option<MountNamespace> {
     }
 
     /// Switches to the target's namespace, and returns the namespace used previously.
+    ///
+    /// **Note:** switching namespaces requires the `CAP_SYS_ADMIN` capability.
     pub fn switch_to_target_namespace(&mut self) -> Option<MountNamespace> {
         log::debug!("Mount::switch_to_target_namespace switching to target namespace");
 
@@ -1503,7 +1917,11 @@ pub fn switch_to_target_namespace(&mut self) -> Option<MountNamespace> {
         }
     }
 
-    /// Waits on parallel mount child processes.
+    /// Waits on parallel mount child processes (see [`MountBuilder::parallel_mount`]), and returns
+    /// how many ran, and how many exited with an error.
+    ///
+    /// **Note:** only call this method in the parent process, after iterating over all the
+    /// entries to mount with [`Mount::seq_mount`].
     pub fn wait_on_children(&mut self) -> ProcessExitStatus {
         log::debug!("Mount::wait_on_children waiting on child processes");
 
@@ -1608,7 +2026,7 @@ pub fn target_namespace(&self) -> Option<MountNamespace> {
         }
     }
 
-    /// Returns the prefix of the configured device's mount point.
+    /// Returns the prefix of the configured device's mount point, or `None` if it was not set.
     pub fn target_prefix(&self) -> Option<PathBuf> {
         log::debug!("Mount::target_prefix getting mount point prefix");
 
@@ -1755,7 +2173,18 @@ pub fn mount_options(&self) -> Option<String> {
         }
     }
 
+    /// Returns the raw data set by [`Mount::set_mount_data`].
+    pub fn mount_data(&self) -> Option<&str> {
+        let data = self.mount_data.as_ref().and_then(|data| data.to_str().ok());
+        log::debug!("Mount::mount_data value: {:?}", data);
+
+        data
+    }
+
     /// Returns the mode of `fstab` mount options.
+    ///
+    /// **Note:** returns `None` when the mode combines several [`MountOptionsMode`]s not matching a
+    /// predefined combination, see [`Mount::mount_options_modes`] to get them all.
     pub fn mount_options_mode(&self) -> Option<MountOptionsMode> {
         log::debug!("Mount::mount_options_mode getting mount options mode");
 
@@ -1776,6 +2205,36 @@ pub fn mount_options_mode(&self) -> Option<MountOptionsMode> {
         }
     }
 
+    /// Returns the [`MountOptionsMode`]s combined in the mode of `fstab` mount options (see
+    /// [`MountBuilder::mount_options_mode`]), or an empty `Vec` if none is set.
+    ///
+    /// Predefined combinations ([`MountOptionsMode::Auto`], [`MountOptionsMode::NonRootUser`]) are
+    /// reported as their individual components.
+    pub fn mount_options_modes(&self) -> Vec<MountOptionsMode> {
+        log::debug!("Mount::mount_options_modes getting mount options modes");
+
+        const SINGLE_MODES: [MountOptionsMode; 8] = [
+            MountOptionsMode::NoReadFromFstab,
+            MountOptionsMode::ForceFstabOptions,
+            MountOptionsMode::ReadFromFstab,
+            MountOptionsMode::ReadFromMountinfo,
+            MountOptionsMode::IgnoreOptions,
+            MountOptionsMode::AppendOptions,
+            MountOptionsMode::PrependOptions,
+            MountOptionsMode::ReplaceOptions,
+        ];
+
+        let bits = unsafe { libmount::mnt_context_get_optsmode(self.inner) };
+
+        let modes: Vec<_> = SINGLE_MODES
+            .into_iter()
+            .filter(|&mode| bits & (mode as i32) != 0)
+            .collect();
+        log::debug!("Mount::mount_options_modes value: {:?}", modes);
+
+        modes
+    }
+
     /// Returns the set  of mount flags set during configuration, or `None` if they were
     /// not provided.
     pub fn mount_flags(&self) -> Option<HashSet<MountFlag>> {
@@ -1789,8 +2248,10 @@ pub fn mount_flags(&self) -> Option<HashSet<MountFlag>> {
             0 => {
                 log::debug!("Mount::mount_flags got mount flags");
                 let bits = unsafe { bits.assume_init() };
+                // Only keep flags whose bits are all set, so that composite flags (e.g.
+                // `MountFlag::Secure`) are not reported when only one of their bits is present.
                 let flags: HashSet<_> = all::<MountFlag>()
-                    .filter(|&flag| (flag as u64) & bits != 0)
+                    .filter(|&flag| (flag as u64) & bits == flag as u64)
                     .collect();
 
                 Some(flags)
@@ -1838,8 +2299,8 @@ pub fn mount_syscall_errno(&self) -> Option<i32> {
         }
     }
 
-    /// Returns the set of userspace mount flags set during configuration, or `None` if they were
-    /// not provided.
+    /// Returns the set of userspace mount flags set during configuration, or an empty set if none
+    /// were provided. Returns `None` if an error occurred.
     pub fn userspace_mount_flags(&self) -> Option<HashSet<UserspaceMountFlag>> {
         log::debug!("Mount::userspace_mount_flags getting user space mount flags");
 
@@ -1853,7 +2314,7 @@ pub fn userspace_mount_flags(&self) -> Option<HashSet<UserspaceMountFlag>> {
                 log::debug!("Mount::userspace_mount_flags got user space mount flags");
                 let bits = unsafe { bits.assume_init() };
                 let flags: HashSet<_> = all::<UserspaceMountFlag>()
-                    .filter(|&flag| (flag as u64) & bits != 0)
+                    .filter(|&flag| (flag as u64) & bits == flag as u64)
                     .collect();
 
                 Some(flags)
@@ -2076,8 +2537,22 @@ pub fn original_namespace(&self) -> Option<MountNamespace> {
     /// To filter devices to mount by file system type and/or mount options, use the
     /// methods [`MountBuilder::match_file_systems`] and/or [`MountBuilder::match_mount_options`]
     /// when instantiating a new `Mount` object.
-    pub fn seq_mount(&mut self) -> MountIter {
-        MountIter::new(self).unwrap()
+    ///
+    /// # Parallel mounts
+    ///
+    /// When configured with [`MountBuilder::parallel_mount`], each step forks off a child process
+    /// to mount the current entry. In the parent process, the iterator moves on to the next entry
+    /// right away; call [`Mount::wait_on_children`] once it is exhausted to reap all child
+    /// processes.
+    ///
+    /// In a child process, the iterator yields the result of the child's mount attempt. The child
+    /// **MUST** then exit (preferably with [`libc::_exit`], so that it does not run the parent's
+    /// exit handlers, or flush its I/O buffers), otherwise it will go on processing the remaining
+    /// entries. Keep in mind that [`fork`](https://www.man7.org/linux/man-pages/man2/fork.2.html)
+    /// only duplicates the calling thread; avoid parallel mounts in multi-threaded programs, where
+    /// another thread may hold a lock the child needs.
+    pub fn seq_mount(&mut self) -> MountIter {
+        MountIter::new(self).unwrap()
     }
 
     /// Tries to sequentially remount entries in `/proc/self/mountinfo`.
@@ -2093,9 +2568,12 @@ pub fn seq_remount(&mut self) -> ReMountIter {
 
     //---- BEGIN predicates
 
-    /// Returns `true` if this `Mount`'s internal [`FsTab`] has a matching `entry`. This
-    /// function compares the `source`, `target`, and `root` fields of the function parameter
-    /// against those of each entry in the [`FsTab`].
+    /// Returns `true` if the `entry` is already mounted. This function compares the `source`,
+    /// `target`, and `root` fields of the function parameter against those of each entry in
+    /// `/proc/self/mountinfo`.
+    ///
+    /// A device mounted at multiple locations (e.g. with bind mounts) is only reported as
+    /// mounted if one of its mount points matches the `entry`'s target.
     ///
     /// **Note:** the `source`, and `target` fields are canonicalized if a [`Cache`] is set for
     /// this `Mount`.
@@ -2164,6 +2642,18 @@ pub fn is_verbose(&self) -> bool {
     }
 
     /// Returns `true` if this `Mount` is set to perform unprivileged mounts (mon-root mounts).
+    ///
+    /// A `Mount` is restricted when the process runs with reduced privileges, i.e. when its real
+    /// or effective user ID is not `0` (e.g. a `setuid` program run by a regular user). In
+    /// restricted mode, `libmount` only honours the mount options an ordinary user is allowed to
+    /// set (see the `user`/`users` options in
+    /// [`fstab`](https://www.man7.org/linux/man-pages/man5/fstab.5.html)), and ignores the rest.
+    ///
+    /// # Security
+    ///
+    /// [`MountBuilder::force_user_mount`] lifts these restrictions, after which this method
+    /// returns `false` whatever the process' privileges. Only use it once you have verified,
+    /// out-of-band, that the caller is entitled to perform privileged mounts.
     pub fn is_user_mount(&self) -> bool {
         let state = unsafe { libmount::mnt_context_is_restricted(self.inner) == 1 };
         log::debug!("Mount::is_user_mount value: {:?}", state);
@@ -2223,6 +2713,14 @@ pub fn is_mount_successful(&self) -> bool {
     }
 
     /// Returns `true` if a write protected device is mounted in read-only mode.
+    ///
+    /// When a device requested in read-write mode turns out to be write protected (e.g. a USB
+    /// stick with its lock switch on), `Mount` falls back to mounting it in read-only mode. Call
+    /// this method after [`Mount::mount_device`] to detect, and report, this downgrade.
+    ///
+    /// **Note:** `Mount` does not try the read-only fallback when configured with
+    /// [`MountBuilder::force_mount_read_write`](crate::mount::MountBuilder::force_mount_read_write)
+    /// (see [`Mount::forces_mount_read_write`]); the mount attempt fails instead.
     pub fn is_mounted_read_only(&self) -> bool {
         let state = unsafe { libmount::mnt_context_forced_rdonly(self.inner) == 1 };
         log::debug!("Mount::is_mounted_read_only value: {:?}", state);
@@ -2246,6 +2744,15 @@ pub fn does_parallel_mount(&self) -> bool {
         state
     }
 
+    /// Returns `true` if this `Mount` is configured to delete the loop device set up for mounting
+    /// an image file when the image is unmounted.
+    pub fn deletes_loop_device_on_umount(&self) -> bool {
+        let state = unsafe { libmount::mnt_context_is_loopdel(self.inner) == 1 };
+        log::debug!("Mount::deletes_loop_device_on_umount value: {:?}", state);
+
+        state
+    }
+
     /// Returns `true` if this `Mount` is configured to force mount devices in read-write mode.
     pub fn forces_mount_read_write(&self) -> bool {
         let state = unsafe { libmount::mnt_context_is_rwonly_mount(self.inner) == 1 };
@@ -2312,7 +2819,10 @@ mod tests {
 
     use super::*;
     use crate::core::device::BlockDevice;
+    use crate::core::device::Pseudo;
     use crate::mount::ExitCode;
+    use crate::mount::StepResult;
+    use crate::mount::Unmount;
 
     //---- Helper functions
 
@@ -2355,6 +2865,767 @@ fn disk_image(fs_type: &str) -> NamedTempFile {
     //     let _ = Mount::builder().build().unwrap();
     // }
 
+    #[test]
+    fn mount_can_round_trip_mount_flags() -> crate::Result<()> {
+        let mut mount = Mount::builder().build()?;
+
+        let flags = HashSet::from([MountFlag::Bind, MountFlag::ReadOnly]);
+        mount.set_mount_flags(flags.clone())?;
+
+        let actual = mount.mount_flags();
+        let expected = Some(flags);
+        assert_eq!(actual, expected);
+
+        let flags = HashSet::from([MountFlag::NoExecute]);
+        mount.set_mount_flags(flags.clone())?;
+
+        let actual = mount.mount_flags();
+        let expected = Some(flags);
+        assert_eq!(actual, expected);
+
+        let flags = HashSet::from([
+            MountFlag::NoExecute,
+            MountFlag::NoSuid,
+            MountFlag::NoDeviceAccess,
+            MountFlag::OwnerSecure,
+            MountFlag::Secure,
+        ]);
+        mount.set_mount_flags([MountFlag::Secure])?;
+
+        let actual = mount.mount_flags();
+        let expected = Some(flags);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_configure_a_bind_mount() -> crate::Result<()> {
+        let mount = Mount::bind("/boot", "/bind/mnt/boot", false)?;
+
+        let actual = mount.mount_flags();
+        let expected = Some(HashSet::from([MountFlag::Bind]));
+        assert_eq!(actual, expected);
+
+        let actual = mount.source();
+        let expected = Some(String::from("/boot"));
+        assert_eq!(actual, expected);
+
+        let mount = Mount::bind("/boot", "/bind/mnt/boot", true)?;
+
+        let actual = mount.mount_flags();
+        let expected = Some(HashSet::from([MountFlag::Bind, MountFlag::Recursive]));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_bind_mount_a_directory_on_another() -> crate::Result<()> {
+        use std::os::unix::fs::MetadataExt;
+
+        if inside_vm::inside_vm() {
+            let source_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let target_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            File::create(source_dir.path().join("file")).unwrap();
+
+            let mut mount = Mount::bind(source_dir.path(), target_dir.path(), false)?;
+            mount.mount_device()?;
+
+            let source_inode = std::fs::metadata(source_dir.path().join("file"))
+                .unwrap()
+                .ino();
+            let target_inode = std::fs::metadata(target_dir.path().join("file"))
+                .unwrap()
+                .ino();
+
+            let actual = target_inode;
+            let expected = source_inode;
+            assert_eq!(actual, expected);
+
+            let mut unmount = Unmount::builder().target(target_dir.path()).build()?;
+            unmount.unmount_device()?;
+
+            let actual = target_dir.path().join("file").exists();
+            let expected = false;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_change_the_propagation_type_of_a_mount_point() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(tmp_dir.path())
+                .file_system(FileSystem::Tmpfs)
+                .build()?;
+
+            mount.mount_device()?;
+
+            for (mode, is_shared) in [
+                (PropagationMode::Shared, true),
+                (PropagationMode::Private, false),
+            ] {
+                mount.reset()?;
+                mount.set_propagation(tmp_dir.path(), mode, false)?;
+                mount.mount_device()?;
+
+                let mut mountinfo = MountInfo::new()?;
+                mountinfo.import_mountinfo()?;
+                let entry = mountinfo.find_back_target(tmp_dir.path()).unwrap();
+
+                let actual = entry
+                    .propagation_flags()
+                    .unwrap_or_default()
+                    .contains(&MountFlag::Shared);
+                let expected = is_shared;
+                assert_eq!(actual, expected, "propagation type: {}", mode);
+            }
+
+            let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+            unmount.unmount_device()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_round_trip_userspace_mount_flags() -> crate::Result<()> {
+        let mut mount = Mount::builder().build()?;
+
+        let actual = mount.userspace_mount_flags();
+        let expected = Some(HashSet::new());
+        assert_eq!(actual, expected);
+
+        let flags = HashSet::from([UserspaceMountFlag::NoAuto, UserspaceMountFlag::NoFail]);
+        mount.set_userspace_mount_flags(flags.clone())?;
+
+        let actual = mount.userspace_mount_flags();
+        let expected = Some(flags);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_do_a_dry_run() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(tmp_dir.path())
+                .file_system(FileSystem::Tmpfs)
+                .dry_run()
+                .build()?;
+
+            let actual = mount.is_dry_run();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            mount.mount_device()?;
+
+            let actual = mount.source();
+            let expected = Some(String::from("none"));
+            assert_eq!(actual, expected);
+
+            let actual = mount.target();
+            let expected = Some(tmp_dir.path().to_path_buf());
+            assert_eq!(actual, expected);
+
+            let actual = mount.file_system_type();
+            let expected = Some(FileSystem::Tmpfs);
+            assert_eq!(actual, expected);
+
+            let mut mountinfo = MountInfo::new()?;
+            mountinfo.import_mountinfo()?;
+
+            let entry = mountinfo.find_target(tmp_dir.path());
+            assert!(entry.is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_not_finalize_a_mount_before_calling_the_mount_syscall() -> crate::Result<()> {
+        let mut mount = Mount::builder()
+            .source(Pseudo::None)
+            .target("/tmp")
+            .file_system(FileSystem::Tmpfs)
+            .build()?;
+
+        let actual = mount.finalize_mount();
+        assert!(matches!(actual, Err(MountError::Action(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_prepare_and_finalize_a_mount_in_two_phases() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(tmp_dir.path())
+                .file_system(FileSystem::Tmpfs)
+                .build()?;
+
+            mount.prepare_mount()?;
+
+            let actual = mount.source();
+            let expected = Some(String::from("none"));
+            assert_eq!(actual, expected);
+
+            let actual = mount.file_system_type();
+            let expected = Some(FileSystem::Tmpfs);
+            assert_eq!(actual, expected);
+
+            mount.call_mount_syscall()?;
+            mount.finalize_mount()?;
+
+            let actual = mount.is_mount_successful();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+            unmount.unmount_device()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_be_reset_and_reused_to_mount_another_device() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let first_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let second_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(first_dir.path())
+                .file_system(FileSystem::Tmpfs)
+                .build()?;
+
+            mount.mount_device()?;
+
+            mount.reset()?;
+
+            let actual = mount.target();
+            let expected = None;
+            assert_eq!(actual, expected);
+
+            mount.set_mount_source(Pseudo::None.into())?;
+            mount.set_mount_target(second_dir.path())?;
+            mount.set_file_system_type(FileSystem::Tmpfs)?;
+
+            mount.mount_device()?;
+
+            let mut mountinfo = MountInfo::new()?;
+            mountinfo.import_mountinfo()?;
+
+            for dir in [&first_dir, &second_dir] {
+                let actual = mountinfo
+                    .find_target(dir.path())
+                    .and_then(|entry| entry.file_system_type());
+                let expected = Some(FileSystem::Tmpfs);
+                assert_eq!(actual, expected);
+
+                let mut unmount = Unmount::builder().target(dir.path()).build()?;
+                unmount.unmount_device()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_sequentially_mount_entries_in_a_table() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dirs: Vec<_> = (0..3)
+                .map(|_| Builder::new().prefix("rsmount-test-").tempdir().unwrap())
+                .collect();
+
+            let mut fstab = FsTab::new()?;
+            for tmp_dir in tmp_dirs.iter() {
+                let entry = FsTabEntry::builder()
+                    .source(Pseudo::None)
+                    .target(tmp_dir.path())
+                    .file_system_type(FileSystem::Tmpfs)
+                    .mount_options("size=1m")
+                    .build()?;
+
+                fstab.push(entry);
+            }
+
+            let mut mount = Mount::builder().override_fstab(fstab).build()?;
+
+            let targets: Vec<_> = mount
+                .seq_mount()
+                .map(|step| match step {
+                    StepResult::MountSuccess(entry) => {
+                        entry.target().map(|target| target.to_path_buf())
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            let actual = targets;
+            let expected: Vec<_> = tmp_dirs
+                .iter()
+                .map(|tmp_dir| Some(tmp_dir.path().to_path_buf()))
+                .collect();
+            assert_eq!(actual, expected);
+
+            for tmp_dir in tmp_dirs.iter() {
+                let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+                unmount.unmount_device()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_sequentially_mount_only_matching_entries_in_a_table() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dirs: Vec<_> = (0..3)
+                .map(|_| Builder::new().prefix("rsmount-test-").tempdir().unwrap())
+                .collect();
+
+            let entries = [
+                (FileSystem::Tmpfs, "size=1m,nosuid"),
+                (FileSystem::Tmpfs, "size=1m"),
+                (FileSystem::Ramfs, "nosuid"),
+            ];
+
+            let mut fstab = FsTab::new()?;
+            for (tmp_dir, (fs_type, options)) in tmp_dirs.iter().zip(entries) {
+                let entry = FsTabEntry::builder()
+                    .source(Pseudo::None)
+                    .target(tmp_dir.path())
+                    .file_system_type(fs_type)
+                    .mount_options(options)
+                    .build()?;
+
+                fstab.push(entry);
+            }
+
+            // Equivalent to `mount -a -t tmpfs -O nosuid`
+            let mut mount = Mount::builder()
+                .override_fstab(fstab)
+                .match_file_systems("tmpfs")
+                .match_mount_options("nosuid")
+                .build()?;
+
+            let targets: Vec<_> = mount
+                .seq_mount()
+                .filter_map(|step| match step {
+                    StepResult::MountSuccess(entry) => {
+                        entry.target().map(|target| target.to_path_buf())
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            let actual = targets;
+            let expected = vec![tmp_dirs[0].path().to_path_buf()];
+            assert_eq!(actual, expected);
+
+            let mut unmount = Unmount::builder().target(tmp_dirs[0].path()).build()?;
+            unmount.unmount_device()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_apply_options_from_an_fstab_entry() -> crate::Result<()> {
+        let entry = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target("/mnt/rsmount-test")
+            .file_system_type(FileSystem::Tmpfs)
+            .mount_options("noexec,size=1m")
+            .build()?;
+
+        let mut fstab = FsTab::new()?;
+        fstab.push(entry);
+
+        let mut mount = Mount::builder()
+            .target("/mnt/rsmount-test")
+            .override_fstab(fstab)
+            .disable_path_canonicalization()
+            .build()?;
+
+        mount.apply_fstab()?;
+
+        let actual = mount.source();
+        let expected = Some(String::from("none"));
+        assert_eq!(actual, expected);
+
+        let actual = mount.file_system_type();
+        let expected = Some(FileSystem::Tmpfs);
+        assert_eq!(actual, expected);
+
+        let actual = mount.mount_options();
+        let expected = Some(String::from("noexec,size=1m"));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_ignore_options_from_an_fstab_entry() -> crate::Result<()> {
+        let entry = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target("/mnt/rsmount-test")
+            .file_system_type(FileSystem::Tmpfs)
+            .mount_options("noexec,size=1m")
+            .build()?;
+
+        let mut fstab = FsTab::new()?;
+        fstab.push(entry);
+
+        let mut mount = Mount::builder()
+            .target("/mnt/rsmount-test")
+            .override_fstab(fstab)
+            .mount_options("size=2m")
+            .mount_options_mode(vec![
+                MountOptionsMode::ReadFromFstab,
+                MountOptionsMode::IgnoreOptions,
+            ])
+            .disable_path_canonicalization()
+            .build()?;
+
+        let actual = mount.mount_options_modes();
+        let expected = vec![
+            MountOptionsMode::ReadFromFstab,
+            MountOptionsMode::IgnoreOptions,
+        ];
+        assert_eq!(actual, expected);
+
+        mount.apply_fstab()?;
+
+        let actual = mount.source();
+        let expected = Some(String::from("none"));
+        assert_eq!(actual, expected);
+
+        let actual = mount.mount_options();
+        let expected = Some(String::from("size=2m"));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_not_apply_a_missing_fstab_entry() -> crate::Result<()> {
+        let fstab = FsTab::new()?;
+
+        let mut mount = Mount::builder()
+            .target("/mnt/rsmount-test")
+            .override_fstab(fstab)
+            .mount_options_mode(vec![MountOptionsMode::ReadFromFstab])
+            .disable_path_canonicalization()
+            .build()?;
+
+        let actual = mount.apply_fstab();
+        assert!(matches!(actual, Err(MountError::FsTabMissingEntry(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_reports_the_syscall_errno_when_mounting_a_missing_device() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let source: BlockDevice = "/dev/rsmount-test-missing-device".parse()?;
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let mut mount = Mount::builder()
+                .source(source)
+                .target(tmp_dir.path())
+                .file_system(FileSystem::Ext4)
+                .build()?;
+
+            let status = mount.mount_device()?;
+
+            let actual = status.exit_code();
+            let expected = ExitCode::Success;
+            assert_ne!(actual, &expected);
+
+            let actual = status.syscall_errno();
+            let expected = Some(libc::ENOENT);
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_not_set_a_target_namespace_from_a_missing_file() {
+        let result = Mount::builder()
+            .target("/mnt")
+            .target_namespace("/proc/self/ns/rsmount-test-missing-namespace")
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn mount_can_set_a_target_namespace() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let mut mount = Mount::builder()
+                .target("/mnt")
+                .target_namespace("/proc/self/ns/mnt")
+                .build()?;
+
+            let actual = mount.target_namespace().is_some();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            let actual = mount.switch_to_target_namespace().is_some();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            let actual = mount.switch_to_original_namespace().is_some();
+            let expected = true;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_has_no_target_prefix_by_default() -> crate::Result<()> {
+        let mount = Mount::builder().target("/mnt").build()?;
+
+        let actual = mount.target_prefix();
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_set_a_target_prefix() -> crate::Result<()> {
+        let mount = Mount::builder()
+            .target("/mnt")
+            .target_prefix("/mnt/target")
+            .build()?;
+
+        let actual = mount.target_prefix();
+        let expected = Some(PathBuf::from("/mnt/target"));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_mount_table_entries_under_a_target_prefix() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let prefix = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+
+            let root = FsTabEntry::builder()
+                .source(Pseudo::None)
+                .target("/")
+                .file_system_type(FileSystem::Tmpfs)
+                .mount_options("size=1m")
+                .build()?;
+
+            let home = FsTabEntry::builder()
+                .source(Pseudo::None)
+                .target("/home")
+                .file_system_type(FileSystem::Tmpfs)
+                .mount_options("size=1m,X-mount.mkdir")
+                .build()?;
+
+            let mut fstab = FsTab::new()?;
+            fstab.push(root);
+            fstab.push(home);
+
+            let mut mount = Mount::builder()
+                .override_fstab(fstab)
+                .target_prefix(prefix.path())
+                .build()?;
+
+            let actual = mount
+                .seq_mount()
+                .filter(|step| matches!(step, StepResult::MountSuccess(_)))
+                .count();
+            let expected = 2;
+            assert_eq!(actual, expected);
+
+            let mut mountinfo = MountInfo::new()?;
+            mountinfo.import_mountinfo()?;
+
+            let actual = mountinfo.find_target(prefix.path().join("home")).is_some();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            for target in [prefix.path().join("home"), prefix.path().to_path_buf()] {
+                let mut unmount = Unmount::builder().target(target).build()?;
+                unmount.unmount_device()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_disable_mount_helpers() -> crate::Result<()> {
+        let mount = Mount::builder().target("/mnt").build()?;
+
+        let actual = mount.has_disabled_helpers();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        let mount = Mount::builder().target("/mnt").disable_helpers().build()?;
+
+        let actual = mount.has_disabled_helpers();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_does_not_run_a_mount_helper_when_helpers_are_disabled() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(tmp_dir.path())
+                .file_system(FileSystem::Tmpfs)
+                .disable_helpers()
+                .build()?;
+
+            mount.mount_device()?;
+
+            let actual = mount.has_run_mount_helper();
+            let expected = false;
+            assert_eq!(actual, expected);
+
+            let actual = mount.has_called_mount_syscall();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+            unmount.unmount_device()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_uses_paths_verbatim_when_path_canonicalization_is_disabled() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let target = tmp_dir.path().join("target");
+            let link = tmp_dir.path().join("link");
+            std::fs::create_dir(&target).unwrap();
+            std::os::unix::fs::symlink(&target, &link).unwrap();
+
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(&link)
+                .file_system(FileSystem::Tmpfs)
+                .disable_path_canonicalization()
+                .dry_run()
+                .build()?;
+
+            let actual = mount.disabled_path_canonicalization();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            mount.mount_device()?;
+
+            let actual = mount.target();
+            let expected = Some(link.clone());
+            assert_eq!(actual, expected);
+
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(&link)
+                .file_system(FileSystem::Tmpfs)
+                .dry_run()
+                .build()?;
+
+            mount.mount_device()?;
+
+            let actual = mount.target();
+            let expected = Some(target.canonicalize().unwrap());
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_keeps_a_shared_cache_alive() -> crate::Result<()> {
+        let cache = Cache::new()?;
+        let cache_ptr = cache.inner;
+        let mount = Mount::builder()
+            .target("/mnt")
+            .override_cache(cache)
+            .build()?;
+
+        // The `Cache` instance was dropped at the end of the build process, the `Mount` should
+        // still hold a valid reference to the same cache.
+        let actual = mount.cache().map(|cache| cache.inner);
+        let expected = Some(cache_ptr);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_check_whether_an_entry_is_mounted() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dirs: Vec<_> = (0..3)
+                .map(|_| Builder::new().prefix("rsmount-test-").tempdir().unwrap())
+                .collect();
+
+            let entries = tmp_dirs
+                .iter()
+                .map(|tmp_dir| {
+                    FsTabEntry::builder()
+                        .source(Pseudo::None)
+                        .target(tmp_dir.path())
+                        .file_system_type(FileSystem::Tmpfs)
+                        .build()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // Mount the same source at the first two targets.
+            for tmp_dir in tmp_dirs.iter().take(2) {
+                let mut mount = Mount::builder()
+                    .source(Pseudo::None)
+                    .target(tmp_dir.path())
+                    .file_system(FileSystem::Tmpfs)
+                    .build()?;
+
+                mount.mount_device()?;
+            }
+
+            let mount = Mount::builder().build()?;
+
+            let actual: Vec<_> = entries
+                .iter()
+                .map(|entry| mount.is_entry_mounted(entry))
+                .collect();
+            let expected = vec![true, true, false];
+            assert_eq!(actual, expected);
+
+            for tmp_dir in tmp_dirs.iter().take(2) {
+                let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+                unmount.unmount_device()?;
+            }
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn mount_can_mount_an_image_file() -> crate::Result<()> {
         if inside_vm::inside_vm() {
@@ -2379,4 +3650,197 @@ fn mount_can_mount_an_image_file() -> crate::Result<()> {
 
         Ok(())
     }
+
+    #[test]
+    fn mount_can_set_raw_mount_data() -> crate::Result<()> {
+        let mut mount = Mount::builder()
+            .source(Pseudo::None)
+            .file_system(FileSystem::Tmpfs)
+            .mount_options("size=1m")
+            .build()?;
+
+        let data = r#"context="system_u:object_r:tmp_t:s0:c127,c456""#;
+        mount.set_mount_data(data)?;
+
+        let actual = mount.mount_data();
+        let expected = Some(data);
+        assert_eq!(actual, expected);
+
+        // Raw data is not merged with the list of mount options.
+        let actual = mount.mount_options();
+        let expected = Some("size=1m".to_owned());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_passes_raw_mount_data_to_the_mount_syscall() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(tmp_dir.path())
+                .file_system(FileSystem::Tmpfs)
+                .build()?;
+
+            mount.set_mount_data("size=2m")?;
+            mount.mount_device()?;
+
+            let mut mountinfo = MountInfo::new()?;
+            mountinfo.import_mountinfo()?;
+
+            let actual = mountinfo
+                .find_target(tmp_dir.path())
+                .and_then(|entry| entry.fs_specific_options())
+                .unwrap_or_default();
+            assert!(actual.contains("size=2048k"), "options: {:?}", actual);
+
+            let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+            unmount.unmount_device()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_set_loop_device_options() -> crate::Result<()> {
+        let mut mount = Mount::builder().source(Pseudo::None).build()?;
+
+        mount.set_loop_offset(1024)?;
+
+        let actual = mount.mount_options();
+        let expected = Some("loop,offset=1024".to_owned());
+        assert_eq!(actual, expected);
+
+        mount.set_loop_size_limit(4096)?;
+        mount.set_loop_offset(2048)?;
+
+        let actual = mount.mount_options();
+        let expected = Some("loop,offset=2048,sizelimit=4096".to_owned());
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_enable_loop_device_deletion_on_unmount() -> crate::Result<()> {
+        let mut mount = Mount::builder().source(Pseudo::None).build()?;
+
+        mount.enable_loop_delete_on_umount(true)?;
+
+        let actual = mount.deletes_loop_device_on_umount();
+        let expected = true;
+        assert_eq!(actual, expected);
+
+        mount.enable_loop_delete_on_umount(false)?;
+
+        let actual = mount.deletes_loop_device_on_umount();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_mount_an_image_file_at_an_offset() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            // Disk image with 1 MiB of padding before the file system.
+            let offset = 1024 * 1024;
+            let mut image_file = NamedTempFile::new().unwrap();
+            image_file.write_all(&vec![0u8; offset]).unwrap();
+            let size = decode_into(
+                format!("{BASE_DIR_TEST_IMG_FILES}/filesystems/ext3.img.xz"),
+                image_file.as_file_mut(),
+            )
+            .unwrap();
+
+            let source = BlockDevice::from(image_file.path());
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let mut mount = Mount::builder()
+                .source(source)
+                .target(tmp_dir.path())
+                .file_system(FileSystem::Ext3)
+                .build()?;
+
+            mount.set_loop_offset(offset as u64)?;
+            mount.set_loop_size_limit(size)?;
+            mount.enable_loop_delete_on_umount(true)?;
+
+            let status = mount.mount_device()?;
+
+            let actual = status.exit_code();
+            let expected = ExitCode::Success;
+            assert_eq!(actual, &expected);
+
+            // Every ext3 file system has a `lost+found` directory at its root.
+            let actual = tmp_dir.path().join("lost+found").is_dir();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            let mut unmount = Unmount::builder()
+                .target(tmp_dir.path())
+                .detach_loop_device()
+                .build()?;
+            unmount.unmount_device()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_mount_a_tmpfs_file_system() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(tmp_dir.path())
+                .file_system(FileSystem::Tmpfs)
+                .mount_options("size=1m")
+                .build()?;
+
+            let status = mount.mount_device()?;
+
+            let actual = status.exit_code();
+            let expected = ExitCode::Success;
+            assert_eq!(actual, &expected);
+
+            let mut mountinfo = MountInfo::new()?;
+            mountinfo.import_mountinfo()?;
+
+            let entry = mountinfo.find_target(tmp_dir.path());
+            assert!(entry.is_some());
+
+            let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+            unmount.unmount_device()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_is_unrestricted_when_run_by_root() -> crate::Result<()> {
+        let is_root = unsafe { libc::getuid() == 0 && libc::geteuid() == 0 };
+
+        if is_root {
+            let mount = Mount::builder().target("/mnt").build()?;
+
+            let actual = mount.is_user_mount();
+            let expected = false;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_can_force_an_unrestricted_mount() -> crate::Result<()> {
+        let mount = Mount::builder().target("/mnt").force_user_mount().build()?;
+
+        let actual = mount.is_user_mount();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
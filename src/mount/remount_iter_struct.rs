@@ -60,14 +60,18 @@ fn next(&mut self) -> Option<Self::Item> {
                 let ptr = unsafe { entry_ptr.assume_init() };
                 let rc = unsafe { mount_return_code.assume_init() };
                 let skipped = unsafe { ignored.assume_init() };
-                let entry = <MountInfoEntry>::from_ptr(ptr);
+                let entry = <MountInfoEntry>::borrow_ptr(ptr);
 
-                match (rc, skipped) {
-                    (0, 0) => Some(StepResult::ReMountSuccess(entry)),
-                    (_, 1) => Some(StepResult::ReMountSkipped(entry)),
-                    (_, 2) => Some(StepResult::ReMountAlreadyDone(entry)),
-                    (_, _) => Some(StepResult::ReMountFail(entry)),
-                }
+                let step = match (rc, skipped) {
+                    (0, 0) => StepResult::ReMountSuccess(entry),
+                    (_, 1) => StepResult::ReMountSkipped(entry),
+                    (_, 2) => StepResult::ReMountAlreadyDone(entry),
+                    (_, _) => StepResult::ReMountFail(entry),
+                };
+
+                self.mount.log_verbose_step(&step, rc);
+
+                Some(step)
             }
             1 => {
                 log::debug!("ReMountIter::next reached the end of `ReMountIter`");
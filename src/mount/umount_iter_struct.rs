@@ -61,7 +61,7 @@ fn next(&mut self) -> Option<Self::Item> {
                 let ptr = unsafe { entry_ptr.assume_init() };
                 let rc = unsafe { mount_return_code.assume_init() };
                 let skipped = unsafe { ignored.assume_init() };
-                let entry = <MountInfoEntry>::from_ptr(ptr);
+                let entry = <MountInfoEntry>::borrow_ptr(ptr);
 
                 match (rc, skipped) {
                     (0, 0) => Some(StepResult::UMountSuccess(entry)),
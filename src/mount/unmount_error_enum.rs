@@ -15,6 +15,10 @@
 #[derive(Debug, Error)]
 #[non_exhaustive]
 pub enum UnmountError {
+    /// Error while performing an action on a [`Unmount`](crate::mount::Unmount) instance.
+    #[error("{0}")]
+    Action(String),
+
     /// Error while creating a new [`Unmount`](crate::mount::Unmount) instance.
     #[error("{0}")]
     Creation(String),
@@ -31,6 +35,11 @@ pub enum UnmountError {
     #[error(transparent)]
     ExitCodeConversion(#[from] TryFromPrimitiveError<ExitCode>),
 
+    /// Error if no entry in `/proc/self/mountinfo` matches an [`Unmount`](crate::mount::Unmount)'s
+    /// source or target.
+    #[error("{0}")]
+    MountInfoMissingEntry(String),
+
     /// Error if `libmount` was compiled without namespace support.
     #[error("{0}")]
     NoNamespaceSupport(String),
@@ -4,27 +4,33 @@
 // From dependency library
 
 // From standard library
+use std::collections::HashSet;
 use std::ffi::CString;
 use std::mem::MaybeUninit;
 use std::path::{Path, PathBuf};
 
 // From this library
 
+use crate::core::entries::FsTabEntry;
+use crate::core::entries::MountInfoEntry;
 use crate::ffi_utils;
 use crate::mount::ExitCode;
 use crate::mount::ExitStatus;
 use crate::mount::MountSource;
+use crate::mount::StepResult;
 use crate::mount::UMountIter;
 use crate::mount::UMountNamespace;
 use crate::mount::UmntBuilder;
 use crate::mount::UnmountBuilder;
 use crate::mount::UnmountError;
+use crate::owning_ref_from_ptr;
+use crate::tables::{GcItem, MountInfo};
 
 /// Object to unmount a device.
 #[derive(Debug)]
-#[repr(transparent)]
 pub struct Unmount {
     pub(crate) inner: *mut libmount::libmnt_context,
+    pub(crate) gc: Vec<GcItem>,
 }
 
 impl Unmount {
@@ -32,7 +38,10 @@ impl Unmount {
     /// Wraps a raw `libmount::mnt_context` pointer with a safe `Unmount`.
     #[allow(dead_code)]
     pub(crate) fn from_ptr(ptr: *mut libmount::libmnt_context) -> Unmount {
-        Self { inner: ptr }
+        Self {
+            inner: ptr,
+            gc: vec![],
+        }
     }
 
     #[doc(hidden)]
@@ -65,6 +74,29 @@ pub(crate) fn new() -> Result<Unmount, UnmountError> {
         }
     }
 
+    #[doc(hidden)]
+    /// Creates a new `Unmount` with the same flags as this one (dry run, lazy unmount, etc.), but
+    /// without a source, a target, or filters.
+    fn new_with_same_flags(&self) -> Result<Unmount, UnmountError> {
+        log::debug!("Unmount::new_with_same_flags creating a new `Unmount` instance");
+
+        let unmount = Self::new()?;
+        let disable_mtab = unsafe { libmount::mnt_context_is_nomtab(self.inner) == 1 };
+
+        Self::enable_delete_loop_device(unmount.inner, self.detaches_loop_device())?;
+        Self::disable_canonicalize(unmount.inner, self.disabled_path_canonicalization())?;
+        Self::disable_mnt_helpers(unmount.inner, self.has_disabled_helpers())?;
+        Self::disable_swap_match(unmount.inner, self.disabled_mount_point_lookup())?;
+        Self::disable_mtab(unmount.inner, disable_mtab)?;
+        Self::enable_fake(unmount.inner, self.is_dry_run())?;
+        Self::enable_force_device_unmount(unmount.inner, self.forces_unmount())?;
+        Self::enable_lazy_device_unmount(unmount.inner, self.does_lazy_unmount())?;
+        Self::enable_verbose(unmount.inner, self.is_verbose())?;
+        Self::enable_read_only_unmount(unmount.inner, self.on_fail_remounts_read_only())?;
+
+        Ok(unmount)
+    }
+
     #[doc(hidden)]
     /// Converts a function's return code to unified `libmount` exit code.
     fn return_code_to_exit_status(&self, return_code: i32) -> Result<ExitStatus, UnmountError> {
@@ -87,7 +119,8 @@ fn return_code_to_exit_status(&self, return_code: i32) -> Result<ExitStatus, Unm
 
         let exit_code = ExitCode::try_from(rc)?;
         let error_message = ffi_utils::c_char_array_to_string(buffer.as_ptr());
-        let exit_status = ExitStatus::new(exit_code, error_message);
+        let syscall_errno = self.umount_syscall_errno();
+        let exit_status = ExitStatus::new(exit_code, error_message, syscall_errno);
 
         log::debug!(
             "Unmount::return_code_to_exit_status converted return code: {:?} to exit status {:?}",
@@ -818,6 +851,10 @@ pub fn unmount_device(&mut self) -> Result<ExitStatus, UnmountError> {
     ///
     /// **Note:** you do not need to call this method if you are using [`Unmount::unmount_device`], it
     /// will take care of parameter validation.
+    ///
+    /// Together with [`Unmount::call_umount_syscall`] and [`Unmount::finalize_umount`], this method
+    /// lets you split an unmount in phases. Between the preparation and the `umount` syscall, you
+    /// can inspect the file system about to be unmounted with [`Unmount::internal_table_entry`].
     pub fn prepare_unmount(&mut self) -> Result<(), UnmountError> {
         log::debug!("Unmount::prepare_unmount preparing for unmount");
 
@@ -1005,6 +1042,149 @@ pub fn switch_to_target_namespace(&mut self) -> Option<UMountNamespace> {
         }
     }
 
+    /// Searches `/proc/self/mountinfo` for the entry this `Unmount` would unmount, without
+    /// unmounting it.
+    ///
+    /// The entry is looked up by the target set with [`UnmountBuilder::target`], or by the
+    /// source set with [`UnmountBuilder::source`] if no target is set. When several file systems
+    /// are mounted on the same mount point, the entry returned is the most recent one, i.e. the
+    /// one visible to users. This lets you check that you are about to unmount the right file
+    /// system (e.g. a bind mount, or one of many mounts of the same device).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnmountError::MountInfoMissingEntry`] if no mounted file system matches.
+    pub fn find_target(&mut self) -> Result<&MountInfoEntry, UnmountError> {
+        log::debug!("Unmount::find_target finding mounted file system to unmount");
+
+        let target = self
+            .target()
+            .map(|path| path.display().to_string())
+            .or_else(|| self.source())
+            .ok_or_else(|| {
+                let err_msg = "no target or source set to find mounted file system".to_owned();
+                log::debug!("Unmount::find_target {}", err_msg);
+
+                UnmountError::Config(err_msg)
+            })?;
+        let target_cstr = ffi_utils::as_ref_str_to_c_string(&target)?;
+
+        let mut ptr = MaybeUninit::<*mut libmount::libmnt_fs>::zeroed();
+
+        let result = unsafe {
+            libmount::mnt_context_find_umount_fs(self.inner, target_cstr.as_ptr(), ptr.as_mut_ptr())
+        };
+
+        match result {
+            0 => {
+                log::debug!(
+                    "Unmount::find_target found mounted file system matching {:?}",
+                    target
+                );
+                let ptr = unsafe { ptr.assume_init() };
+                let entry = owning_ref_from_ptr!(self, MountInfoEntry, ptr);
+
+                Ok(entry)
+            }
+            1 => {
+                let err_msg = format!("found no mounted file system matching {:?}", target);
+                log::debug!("Unmount::find_target {}. libmount::mnt_context_find_umount_fs returned code: 1", err_msg);
+
+                Err(UnmountError::MountInfoMissingEntry(err_msg))
+            }
+            code => {
+                let err_msg = format!("failed to find mounted file system matching {:?}", target);
+                log::debug!("Unmount::find_target {}. libmount::mnt_context_find_umount_fs returned error code: {:?}", err_msg, code);
+
+                Err(UnmountError::Action(err_msg))
+            }
+        }
+    }
+
+    /// Recursively unmounts the file system designated by [`Unmount::find_target`], and all the
+    /// file systems mounted under it (equivalent to `umount -R`).
+    ///
+    /// File systems are unmounted deepest first, i.e. children are always unmounted before their
+    /// parent. The method stops at the first failure, leaving the file systems it already unmounted
+    /// detached, and the remaining ones mounted.
+    ///
+    /// Returns the outcome of each unmount attempted, in order, as a list of [`StepResult`]. The
+    /// last item is a [`StepResult::UMountFail`] if an unmount failed.
+    ///
+    /// Each file system is unmounted by a new context sharing this `Unmount`'s flags (e.g.
+    /// [`UnmountBuilder::dry_run`], [`UnmountBuilder::lazy_unmount`], etc.), leaving this
+    /// `Unmount`'s configuration untouched.
+    pub fn unmount_recursive(&mut self) -> Result<Vec<StepResult>, UnmountError> {
+        log::debug!("Unmount::unmount_recursive recursively unmounting file systems");
+
+        #[doc(hidden)]
+        /// Collects the entries in the sub-tree rooted at `entry`, children before their parent.
+        fn collect(
+            table: &MountInfo,
+            entry: &MountInfoEntry,
+            visited: &mut HashSet<*mut libmount::libmnt_fs>,
+            entries: &mut Vec<MountInfoEntry>,
+        ) {
+            visited.insert(entry.inner);
+
+            // Most recent mounts first, so that stacked mounts are unmounted top down.
+            for child in table.iter_children(entry).rev() {
+                if !visited.contains(&child.inner) {
+                    collect(table, child, visited, entries);
+                }
+            }
+
+            entries.push(MountInfoEntry::borrow_ptr(entry.inner));
+        }
+
+        let root = self.find_target()?;
+
+        let mut mount_info = MountInfo::new().map_err(|e| {
+            let err_msg = format!("failed to create mount table {}", e);
+
+            UnmountError::Action(err_msg)
+        })?;
+
+        mount_info.import_mountinfo().map_err(|e| {
+            let err_msg = format!("failed to import /proc/self/mountinfo {}", e);
+
+            UnmountError::Action(err_msg)
+        })?;
+
+        let mut entries = Vec::new();
+        collect(&mount_info, root, &mut HashSet::new(), &mut entries);
+
+        let mut results = Vec::with_capacity(entries.len());
+
+        for entry in entries {
+            let step = self.new_with_same_flags()?;
+
+            let result = unsafe { libmount::mnt_context_set_fs(step.inner, entry.inner) };
+            if result != 0 {
+                let err_msg = format!("failed to set file system to unmount {:?}", entry.target());
+                log::debug!("Unmount::unmount_recursive {}. libmount::mnt_context_set_fs returned error code: {:?}", err_msg, result);
+
+                return Err(UnmountError::Config(err_msg));
+            }
+
+            match unsafe { libmount::mnt_context_umount(step.inner) } {
+                0 => {
+                    log::debug!("Unmount::unmount_recursive unmounted {:?}", entry.target());
+
+                    results.push(StepResult::UMountSuccess(entry));
+                }
+                code => {
+                    log::debug!("Unmount::unmount_recursive failed to unmount {:?}. libmount::mnt_context_umount returned error code: {:?}", entry.target(), code);
+                    results.push(StepResult::UMountFail(entry));
+
+                    break;
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
     //---- END mutators
 
     //---- BEGIN getters
@@ -1038,6 +1218,44 @@ pub fn source(&self) -> Option<String> {
         }
     }
 
+    /// Returns a reference to the internal table entry describing the file system this `Unmount`
+    /// operates on, or `None` if it is not set.
+    ///
+    /// After a call to [`Unmount::prepare_unmount`], the entry holds the data `Unmount` resolved
+    /// from `/proc/self/mountinfo` (source, file system type, mount options, etc.), which lets you
+    /// inspect what is about to be unmounted before calling [`Unmount::call_umount_syscall`].
+    pub fn internal_table_entry(&self) -> Option<&FsTabEntry> {
+        log::debug!(
+            "Unmount::internal_table_entry getting reference to internal mount table entry"
+        );
+
+        let mut ptr = MaybeUninit::<*mut libmount::libmnt_fs>::zeroed();
+
+        unsafe {
+            ptr.write(libmount::mnt_context_get_fs(self.inner));
+        }
+
+        match unsafe { ptr.assume_init() } {
+            ptr if ptr.is_null() => {
+                let err_msg = "failed to get reference to internal mount table entry";
+                log::debug!(
+                    "Unmount::internal_table_entry {}. libmount::mnt_context_get_fs returned a NULL pointer",
+                    err_msg
+                );
+
+                None
+            }
+            ptr => {
+                log::debug!(
+                    "Unmount::internal_table_entry got reference to internal mount table entry"
+                );
+                let entry = owning_ref_from_ptr!(self, FsTabEntry, ptr);
+
+                Some(entry)
+            }
+        }
+    }
+
     /// Returns the configured device mount point, or `None` if it was not provided.
     pub fn target(&self) -> Option<PathBuf> {
         log::debug!("Unmount::target getting mount point");
@@ -1134,6 +1352,9 @@ pub fn umount_syscall_errno(&self) -> Option<i32> {
 
     /// Tries to sequentially umount entries in `/proc/self/mountinfo`.
     ///
+    /// To unmount a sub-tree of mount points, children before their parent, see
+    /// [`Unmount::unmount_recursive`].
+    ///
     /// To filter devices to umount by file system type and/or mount options, use the
     /// methods [`UnmountBuilder::match_file_systems`] and/or [`UnmountBuilder::match_mount_options`]
     /// when instantiating a new `Mount` object.
@@ -1258,5 +1479,279 @@ fn drop(&mut self) {
         log::debug!("Unmount::drop deallocating `Unmount` instance");
 
         unsafe { libmount::mnt_free_context(self.inner) }
+
+        // Free objects allocated on the heap for returned references.
+        while let Some(gc_item) = self.gc.pop() {
+            gc_item.destroy();
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use pretty_assertions::{assert_eq, assert_ne};
+    use tempfile::Builder;
+
+    use std::fs::File;
+    use std::io::Write;
+
+    use super::*;
+    use crate::core::device::Pseudo;
+    use crate::core::fs::FileSystem;
+    use crate::mount::Mount;
+    use crate::tables::MountInfo;
+
+    #[test]
+    fn unmount_can_lazy_unmount_a_busy_mount_point() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(tmp_dir.path())
+                .file_system(FileSystem::Tmpfs)
+                .build()?;
+
+            mount.mount_device()?;
+
+            // Keep a file open on the mounted file system to make it busy.
+            let mut busy_file = File::create(tmp_dir.path().join("busy.txt")).unwrap();
+            busy_file.write_all(b"busy").unwrap();
+
+            let mut unmount = Unmount::builder()
+                .target(tmp_dir.path())
+                .lazy_unmount()
+                .build()?;
+
+            let status = unmount.unmount_device()?;
+
+            let actual = status.exit_code();
+            let expected = ExitCode::Success;
+            assert_eq!(actual, &expected);
+
+            let mut mountinfo = MountInfo::new()?;
+            mountinfo.import_mountinfo()?;
+
+            let actual = mountinfo.find_target(tmp_dir.path());
+            assert!(actual.is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn unmount_can_prepare_inspect_and_finalize_an_unmount_in_two_phases() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(tmp_dir.path())
+                .file_system(FileSystem::Tmpfs)
+                .build()?;
+
+            mount.mount_device()?;
+
+            let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+
+            // Phase 1: resolve the file system to unmount.
+            unmount.prepare_unmount()?;
+
+            let entry = unmount.internal_table_entry().unwrap();
+
+            let actual = entry.file_system_type();
+            let expected = Some(FileSystem::Tmpfs);
+            assert_eq!(actual, expected);
+
+            let actual = entry.target();
+            let expected = Some(tmp_dir.path());
+            assert_eq!(actual, expected);
+
+            let actual = unmount.has_called_umount_syscall();
+            let expected = false;
+            assert_eq!(actual, expected);
+
+            // Phase 2: unmount, then update the mount tables.
+            let status = unmount.call_umount_syscall()?;
+
+            let actual = status.exit_code();
+            let expected = ExitCode::Success;
+            assert_eq!(actual, &expected);
+
+            unmount.finalize_umount()?;
+
+            let mut mountinfo = MountInfo::new()?;
+            mountinfo.import_mountinfo()?;
+
+            let actual = mountinfo.find_target(tmp_dir.path());
+            assert!(actual.is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn unmount_can_recursively_unmount_a_nested_tree_of_mount_points() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let parent = tmp_dir.path().to_path_buf();
+            let child = parent.join("child");
+
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(&parent)
+                .file_system(FileSystem::Tmpfs)
+                .build()?;
+            mount.mount_device()?;
+
+            std::fs::create_dir(&child).unwrap();
+
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(&child)
+                .file_system(FileSystem::Tmpfs)
+                .build()?;
+            mount.mount_device()?;
+
+            let mut unmount = Unmount::builder().target(&parent).build()?;
+            let results = unmount.unmount_recursive()?;
+
+            // Children are unmounted before their parent.
+            let actual: Vec<_> = results
+                .iter()
+                .map(|result| match result {
+                    StepResult::UMountSuccess(entry) => entry.target().map(Path::to_path_buf),
+                    _ => None,
+                })
+                .collect();
+            let expected = vec![Some(child), Some(parent.clone())];
+            assert_eq!(actual, expected);
+
+            let mut mountinfo = MountInfo::new()?;
+            mountinfo.import_mountinfo()?;
+
+            let actual = mountinfo.find_target(&parent);
+            assert!(actual.is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn unmount_stops_recursive_unmount_at_the_first_failure() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+            let parent = tmp_dir.path().to_path_buf();
+            let child = parent.join("child");
+
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(&parent)
+                .file_system(FileSystem::Tmpfs)
+                .build()?;
+            mount.mount_device()?;
+
+            std::fs::create_dir(&child).unwrap();
+
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(&child)
+                .file_system(FileSystem::Tmpfs)
+                .build()?;
+            mount.mount_device()?;
+
+            // An open file keeps the parent busy, but not the child.
+            let busy_file = File::create(parent.join("busy")).unwrap();
+
+            let mut unmount = Unmount::builder().target(&parent).build()?;
+            let results = unmount.unmount_recursive()?;
+
+            let actual: Vec<_> = results
+                .iter()
+                .map(|result| match result {
+                    StepResult::UMountSuccess(entry) => {
+                        (true, entry.target().map(Path::to_path_buf))
+                    }
+                    StepResult::UMountFail(entry) => (false, entry.target().map(Path::to_path_buf)),
+                    _ => (false, None),
+                })
+                .collect();
+            let expected = vec![(true, Some(child.clone())), (false, Some(parent.clone()))];
+            assert_eq!(actual, expected);
+
+            let mut mountinfo = MountInfo::new()?;
+            mountinfo.import_mountinfo()?;
+
+            // The child stays detached, the parent stays mounted.
+            let actual = mountinfo.find_target(&child);
+            assert!(actual.is_none());
+
+            let actual = mountinfo
+                .find_target(&parent)
+                .and_then(|entry| entry.target());
+            let expected = Some(parent.as_path());
+            assert_eq!(actual, expected);
+
+            // The `Unmount` still targets the parent.
+            drop(busy_file);
+            unmount.unmount_device()?;
+
+            let mut mountinfo = MountInfo::new()?;
+            mountinfo.import_mountinfo()?;
+
+            let actual = mountinfo.find_target(&parent);
+            assert!(actual.is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn unmount_can_find_the_most_recent_mount_on_a_target() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+
+            // Mount two file systems on the same mount point.
+            for size in ["size=1m", "size=2m"] {
+                let mut mount = Mount::builder()
+                    .source(Pseudo::None)
+                    .target(tmp_dir.path())
+                    .file_system(FileSystem::Tmpfs)
+                    .mount_options(size)
+                    .build()?;
+
+                mount.mount_device()?;
+            }
+
+            let mut mountinfo = MountInfo::new()?;
+            mountinfo.import_mountinfo()?;
+
+            let most_recent = mountinfo
+                .iter()
+                .filter(|entry| entry.target() == Some(tmp_dir.path()))
+                .filter_map(|entry| entry.mount_id())
+                .max();
+
+            let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+            let entry = unmount.find_target()?;
+
+            let actual = entry.mount_id();
+            let expected = most_recent;
+            assert_eq!(actual, expected);
+
+            let actual = entry.fs_specific_options().unwrap_or_default();
+            assert!(actual.contains("size=2048k"), "options: {:?}", actual);
+
+            // Nothing was unmounted.
+            let actual = unmount.has_called_umount_syscall();
+            let expected = false;
+            assert_eq!(actual, expected);
+
+            unmount.unmount_device()?;
+
+            let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+            unmount.unmount_device()?;
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,47 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Sample tables shared by unit tests.
+
+// From dependency library
+use tempfile::NamedTempFile;
+
+// From standard library
+use std::io::Write;
+
+// From this library
+use crate::core::errors::MountInfoError;
+use crate::tables::MountInfo;
+
+/// Sample content of `/proc/self/mountinfo`.
+pub(crate) static MOUNTINFO: &str = r#"21 1 8:3 / / rw,relatime shared:1 - ext4 /dev/sda3 rw
+22 21 0:20 / /sys rw,nosuid,nodev,noexec,relatime shared:2 - sysfs sysfs rw
+23 21 0:21 / /proc rw,nosuid,nodev,noexec,relatime shared:3 - proc proc rw
+24 21 0:5 / /dev rw,nosuid shared:4 - devtmpfs udev rw,mode=755
+25 24 0:22 / /dev/pts rw,nosuid,noexec,relatime shared:5 - devpts devpts rw,gid=5,mode=620
+26 22 0:23 / /sys/fs/cgroup rw,nosuid,nodev,noexec,relatime shared:6 - cgroup2 cgroup2 rw
+27 26 0:24 / /sys/fs/cgroup/unified rw,nosuid,nodev,noexec,relatime shared:7 - cgroup2 cgroup2 rw
+"#;
+
+/// Writes `content` to a temporary file, deleted when the returned value goes out of scope.
+pub(crate) fn temp_file(content: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+
+    file
+}
+
+/// Returns a table of mounted file systems imported from `mountinfo`-formatted `content`.
+pub(crate) fn mount_info_from(content: &str) -> Result<MountInfo, MountInfoError> {
+    let file = temp_file(content);
+
+    let mut mount_info = MountInfo::new()?;
+    mount_info.import_file(file.path())?;
+
+    Ok(mount_info)
+}
+
+/// Returns a table of mounted file systems imported from [`MOUNTINFO`].
+pub(crate) fn mount_info() -> Result<MountInfo, MountInfoError> {
+    mount_info_from(MOUNTINFO)
+}
@@ -5,13 +5,25 @@
 
 // From standard library
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
 use std::fmt;
+use std::fs;
 use std::fs::File;
+use std::fs::OpenOptions;
 use std::io;
+use std::io::Seek;
 use std::mem::MaybeUninit;
 use std::ops::Index;
 use std::ops::IndexMut;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::ffi::OsStringExt;
+use std::os::unix::fs::MetadataExt;
+use std::os::unix::fs::OpenOptionsExt;
 use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 // From this library
 use crate::core::cache::Cache;
@@ -20,18 +32,24 @@
 use crate::core::device::Tag;
 
 use crate::core::entries::FsTabEntry;
+use crate::core::entries::ValidationIssue;
 use crate::core::errors::FsTabError;
 use crate::core::errors::FsTabIterError;
+use crate::core::fs::FileLock;
 
 use crate::core::iter::Direction;
+use crate::core::iter::FsTabIntoIter;
 use crate::core::iter::FsTabIter;
 use crate::core::iter::FsTabIterMut;
 use crate::core::iter::GenIterator;
+use crate::core::utils;
 
 use crate::owning_ref_from_ptr;
 
 use crate::tables::GcItem;
+use crate::tables::MergePolicy;
 use crate::tables::MountOption;
+use crate::tables::ParseError;
 use crate::tables::ParserFlow;
 
 use crate::ffi_utils;
@@ -203,6 +221,8 @@
 pub struct FsTab {
     pub(crate) inner: *mut libmount::libmnt_table,
     pub(crate) gc: Vec<GcItem>,
+    has_parser_error_handler: bool,
+    abort_on_syntax_error: bool,
 }
 
 impl Drop for FsTab {
@@ -221,6 +241,53 @@ fn as_ref(&self) -> &FsTab {
     }
 }
 
+impl IntoIterator for FsTab {
+    type Item = FsTabEntry;
+    type IntoIter = FsTabIntoIter;
+
+    /// Creates a consuming iterator over the entries of this table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it fails to create a [`FsTabIntoIter`].
+    fn into_iter(self) -> Self::IntoIter {
+        log::debug!("FsTab::into_iter creating a new `FsTabIntoIter`");
+
+        FsTabIntoIter::new(self).unwrap()
+    }
+}
+
+impl FromIterator<FsTabEntry> for FsTab {
+    /// Creates a new `FsTab` from the entries yielded by `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if memory allocation fails. See [`FsTab::try_from_iter`] for a fallible
+    /// alternative.
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = FsTabEntry>,
+    {
+        Self::try_from_iter(iter).unwrap()
+    }
+}
+
+impl Extend<FsTabEntry> for FsTab {
+    /// Appends the entries yielded by `iter` to this `FsTab`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if memory allocation for extending capacity fails.
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = FsTabEntry>,
+    {
+        for entry in iter {
+            self.push(entry);
+        }
+    }
+}
+
 impl Index<usize> for FsTab {
     type Output = FsTabEntry;
 
@@ -286,6 +353,8 @@ pub(crate) fn from_ptr(ptr: *mut libmount::libmnt_table) -> FsTab {
         Self {
             inner: ptr,
             gc: vec![],
+            has_parser_error_handler: false,
+            abort_on_syntax_error: false,
         }
     }
 
@@ -328,6 +397,57 @@ pub fn new() -> Result<FsTab, FsTabError> {
         }
     }
 
+    /// Creates a new `FsTab`, and fills it with the entries yielded by `iter`.
+    ///
+    /// Unlike [`FsTab::from_iter`](FromIterator::from_iter), this function returns an error
+    /// instead of panicking if it fails to allocate the table, or to add an entry to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use rsmount::device::Pseudo;
+    /// use rsmount::entries::FsTabEntry;
+    /// use rsmount::fs::FileSystem;
+    /// use rsmount::tables::FsTab;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let targets = ["/tmp", "/var/tmp"];
+    ///
+    ///     let entries = targets
+    ///         .into_iter()
+    ///         .map(|target| {
+    ///             FsTabEntry::builder()
+    ///                 .source(Pseudo::None)
+    ///                 .target(target)
+    ///                 .file_system_type(FileSystem::Tmpfs)
+    ///                 .build()
+    ///         })
+    ///         .collect::<Result<Vec<_>, _>>()?;
+    ///
+    ///     let fstab = FsTab::try_from_iter(entries)?;
+    ///
+    ///     let actual = fstab.len();
+    ///     let expected = 2;
+    ///     assert_eq!(actual, expected);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Result<FsTab, FsTabError>
+    where
+        I: IntoIterator<Item = FsTabEntry>,
+    {
+        log::debug!("FsTab::try_from_iter creating a new `FsTab` from an iterator");
+
+        let mut table = Self::new()?;
+        for entry in iter {
+            table.try_push(entry)?;
+        }
+
+        Ok(table)
+    }
+
     /// Creates a new `FsTab`, and fills it with entries parsed from the given `file`.
     pub fn new_from_file<T>(file: T) -> Result<FsTab, FsTabError>
     where
@@ -477,10 +597,11 @@ pub fn position(&self, entry: &FsTabEntry) -> Option<usize> {
             index if index > 0 => {
                 log::debug!(
                     "FsTab::position mount table contains entry at index: {:?}",
-                    index
+                    index - 1
                 );
 
-                Some(index as usize)
+                // libmount::mnt_table_find_fs returns a 1-based index
+                Some((index - 1) as usize)
             }
             code => {
                 log::debug!( "FsTab::position no matching entry in table: libmount::mnt_table_find_fs returned error code: {code:?}");
@@ -1014,7 +1135,13 @@ fn lookup_target<'a>(
         direction: Direction,
         path: &Path,
     ) -> Option<&'a FsTabEntry> {
-        let path_cstr = ffi_utils::as_ref_path_to_c_string(path).ok()?;
+        // Lexically normalize the path like `utils::normalize_target`, without following
+        // symlinks, to strip trailing slashes and collapse repeated separators (e.g. `//home/`
+        // becomes `/home`).
+        let path = PathBuf::from(OsString::from_vec(utils::collapse_slashes(
+            path.as_os_str().as_bytes(),
+        )));
+        let path_cstr = ffi_utils::as_ref_path_to_c_string(&path).ok()?;
         log::debug!(
             "FsTab::lookup_target searching {:?} for entry matching target {:?}",
             direction,
@@ -1061,6 +1188,9 @@ fn lookup_target<'a>(
     /// By default, a `FsTab` will perform a cursory search, looking for an entry with an exact `path`
     /// match. To perform a deep search, which implies following symlinks, canonicalizing paths, etc.,
     /// set up a [`Cache`] with [`FsTab::set_cache`].
+    ///
+    /// **Note:** `path` is lexically normalized before the search, i.e. trailing slashes are
+    /// stripped and repeated separators collapsed (`/home/` and `//home` both match `/home`).
     pub fn find_target<T>(&mut self, path: T) -> Option<&FsTabEntry>
     where
         T: AsRef<Path>,
@@ -1082,6 +1212,9 @@ pub fn find_target<T>(&mut self, path: T) -> Option<&FsTabEntry>
     /// By default, a `FsTab` will perform a cursory search, looking for an entry with an exact `path`
     /// match. To perform a deep search, which implies following symlinks, canonicalizing paths, etc.,
     /// set up a [`Cache`] with [`FsTab::set_cache`].
+    ///
+    /// **Note:** `path` is lexically normalized before the search, i.e. trailing slashes are
+    /// stripped and repeated separators collapsed (`/home/` and `//home` both match `/home`).
     pub fn find_back_target<T>(&mut self, path: T) -> Option<&FsTabEntry>
     where
         T: AsRef<Path>,
@@ -1425,6 +1558,7 @@ pub fn set_parser_error_handler<F>(&mut self, err_handler: F) -> Result<(), FsTa
                 match result {
                     0 => {
                         log::debug!("FsTab::set_parser_error_handler set up parser error handler");
+                        self.has_parser_error_handler = true;
                         // FIXME the callback function is long lived. If the function is called
                         // several times, we risk a substantial memory leak until the end of the program,
                         // since `user_data` is never released between calls.
@@ -1461,11 +1595,6 @@ pub fn set_parser_error_handler<F>(&mut self, err_handler: F) -> Result<(), FsTa
     pub fn set_cache(&mut self, cache: Cache) -> Result<(), FsTabError> {
         log::debug!("FsTab::set_cache setting up a cache of paths and tags");
 
-        // Increment cache's reference counter to avoid a premature deallocation leading to a SIGSEV.
-        unsafe {
-            libmount::mnt_ref_cache(cache.inner);
-        }
-
         let result = unsafe { libmount::mnt_table_set_cache(self.inner, cache.inner) };
         match result {
             0 => {
@@ -1593,6 +1722,12 @@ fn enable_comments(ptr: *mut libmount::libmnt_table, enable: bool) {
     }
 
     /// Keeps comment lines when importing table entries from files.
+    ///
+    /// Comment lines, and empty lines, are attached either to the table, as intro/trailing
+    /// comments, or to the [`FsTabEntry`] they precede (see [`FsTabEntry::comment`]). Combined
+    /// with [`FsTab::export_with_comments`], a file read then written back by an `FsTab` is
+    /// preserved byte for byte, provided its entries' fields are separated by a single space.
+    /// Comments at the end of an entry's line are not supported.
     pub fn import_with_comments(&mut self) {
         log::debug!("FsTab::import_with_comments enabling comment parsing");
 
@@ -1606,6 +1741,24 @@ pub fn import_without_comments(&mut self) {
         Self::enable_comments(self.inner, false)
     }
 
+    /// Stops importing table entries at the first line with a syntax error, making import
+    /// methods return a [`FsTabError::Parse`] holding the line's number.
+    ///
+    /// **Note:** this setting has no effect if you set up a handler with
+    /// [`FsTab::set_parser_error_handler`].
+    pub fn abort_on_syntax_error(&mut self) {
+        log::debug!("FsTab::abort_on_syntax_error enabling abort on syntax error");
+
+        self.abort_on_syntax_error = true;
+    }
+
+    /// Skips lines with a syntax error when importing table entries (default).
+    pub fn skip_syntax_errors(&mut self) {
+        log::debug!("FsTab::skip_syntax_errors disabling abort on syntax error");
+
+        self.abort_on_syntax_error = false;
+    }
+
     /// Imports entries from files with a `.fstab` extension in a given directory. File names are
     /// ordered by [strverscmp(3)](https://www.man7.org/linux/man-pages/man3/strverscmp.3.html)
     /// before their contents are added to the table.
@@ -1614,6 +1767,7 @@ pub fn import_without_comments(&mut self) {
     /// - this method ignores any dotfile in the directory.
     /// - by default, comment lines are ignored during importation. If you want them included, call
     ///   [`FsTab::import_with_comments`] **before** invoking this method.
+    /// - by default, lines with a syntax error are skipped. See [`FsTab::abort_on_syntax_error`].
     pub fn import_directory<T>(&mut self, dir_path: T) -> Result<(), FsTabError>
     where
         T: AsRef<Path>,
@@ -1625,10 +1779,18 @@ pub fn import_directory<T>(&mut self, dir_path: T) -> Result<(), FsTabError>
             dir_path
         );
 
-        let result = unsafe { libmount::mnt_table_parse_dir(self.inner, dir_path_cstr.as_ptr()) };
+        let result = self.import_with(
+            "mnt_table_parse_dir",
+            dir_path,
+            // The parser names files relative to the directory.
+            |file_name, line| {
+                file_name.and_then(|name| ffi_utils::read_line(dir_path.join(name), line))
+            },
+            |table| unsafe { libmount::mnt_table_parse_dir(table, dir_path_cstr.as_ptr()) },
+        );
 
         match result {
-            0 => {
+            Ok(()) => {
                 log::debug!(
                     "FsTab::import_directory imported table entries from files in {:?}",
                     dir_path
@@ -1636,14 +1798,14 @@ pub fn import_directory<T>(&mut self, dir_path: T) -> Result<(), FsTabError>
 
                 Ok(())
             }
-            code => {
-                let err_msg = format!(
-                    "failed to import table entries from files in {:?}",
-                    dir_path
+            Err(e) => {
+                log::debug!(
+                    "FsTab::import_directory failed to import table entries from files in {:?}. {}",
+                    dir_path,
+                    e
                 );
-                log::debug!("FsTab::import_directory {}. libmount::mnt_table_parse_dir returned error code: {:?}", err_msg, code);
 
-                Err(FsTabError::Import(err_msg))
+                Err(e)
             }
         }
     }
@@ -1651,24 +1813,33 @@ pub fn import_directory<T>(&mut self, dir_path: T) -> Result<(), FsTabError>
     /// Parses `/etc/fstab` or the file specified by the environment variable `LIBMOUNT_FSTAB`,
     /// then appends the entries it collects to this `FsTab`.
     ///
-    /// **Note:** by default, comment lines are ignored during importation. If you want them
-    /// included, call [`FsTab::import_with_comments`] **before** invoking this method.
+    /// **Note:**
+    /// - by default, comment lines are ignored during importation. If you want them included, call
+    ///   [`FsTab::import_with_comments`] **before** invoking this method.
+    /// - by default, lines with a syntax error are skipped. See [`FsTab::abort_on_syntax_error`].
     pub fn import_etc_fstab(&mut self) -> Result<(), FsTabError> {
         log::debug!("FsTab::import_etc_fstab import entries from /etc/fstab");
 
-        let result = unsafe { libmount::mnt_table_parse_fstab(self.inner, std::ptr::null()) };
+        let result = self.import_with(
+            "mnt_table_parse_fstab",
+            "/etc/fstab",
+            |file_name, line| file_name.and_then(|path| ffi_utils::read_line(path, line)),
+            |table| unsafe { libmount::mnt_table_parse_fstab(table, std::ptr::null()) },
+        );
 
         match result {
-            0 => {
+            Ok(()) => {
                 log::debug!("FsTab::import_etc_fstab imported entries from /etc/fstab");
 
                 Ok(())
             }
-            code => {
-                let err_msg = "failed to import entries from /etc/fstab".to_owned();
-                log::debug!("FsTab::import_etc_fstab {}. libmount::mnt_table_parse_fstab returned error code: {:?}", err_msg, code);
+            Err(e) => {
+                log::debug!(
+                    "FsTab::import_etc_fstab failed to import entries from /etc/fstab. {}",
+                    e
+                );
 
-                Err(FsTabError::Import(err_msg))
+                Err(e)
             }
         }
     }
@@ -1678,11 +1849,32 @@ pub fn import_etc_fstab(&mut self) -> Result<(), FsTabError> {
     /// **Note:**
     /// - by default, comment lines are ignored during import. If you want them included, call
     ///   [`FsTab::import_with_comments`] **before** invoking this method.
-    /// - the parser ignores lines with syntax errors. It will report defective lines to the caller
-    ///   through an error callback function.
+    /// - by default, the parser skips lines with a syntax error. After a call to
+    ///   [`FsTab::abort_on_syntax_error`], it stops at the first one instead, and this method
+    ///   returns a [`FsTabError::Parse`] holding its line number, and why it is invalid (e.g.
+    ///   `invalid fsck order`). If you set up a handler with
+    ///   [`FsTab::set_parser_error_handler`], the handler decides whether to skip defective lines,
+    ///   or to abort.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsmount::tables::FsTab;
+    /// use rsmount::RsMountError;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let mut fstab = FsTab::new()?;
+    ///     fstab.abort_on_syntax_error();
     ///
-    // FIXME Defective lines are reported to the caller by the errcb() function (see mnt_table_set_parser_errcb()).
-    // can not currently wrap the function `mnt_table_set_parser_errcb`
+    ///     match fstab.import_file("/etc/fstab").map_err(RsMountError::from) {
+    ///         Err(RsMountError::Parse { line, reason }) => eprintln!("fstab line {line}: {reason}"),
+    ///         Err(e) => eprintln!("{e}"),
+    ///         Ok(()) => {}
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
     pub fn import_file<T>(&mut self, file_path: T) -> Result<(), FsTabError>
     where
         T: AsRef<Path>,
@@ -1694,10 +1886,15 @@ pub fn import_file<T>(&mut self, file_path: T) -> Result<(), FsTabError>
             file_path
         );
 
-        let result = unsafe { libmount::mnt_table_parse_file(self.inner, file_path_cstr.as_ptr()) };
+        let result = self.import_with(
+            "mnt_table_parse_file",
+            file_path,
+            |_, line| ffi_utils::read_line(file_path, line),
+            |table| unsafe { libmount::mnt_table_parse_file(table, file_path_cstr.as_ptr()) },
+        );
 
         match result {
-            0 => {
+            Ok(()) => {
                 log::debug!(
                     "FsTab::import_file imported table entries from file {:?}",
                     file_path
@@ -1705,12 +1902,175 @@ pub fn import_file<T>(&mut self, file_path: T) -> Result<(), FsTabError>
 
                 Ok(())
             }
+            Err(e) => {
+                log::debug!(
+                    "FsTab::import_file failed to import table entries from file {:?}. {}",
+                    file_path,
+                    e
+                );
+
+                Err(e)
+            }
+        }
+    }
+
+    /// Creates a new `FsTab`, and fills it with the entries parsed from `file_path`, skipping
+    /// defective lines instead of aborting.
+    ///
+    /// Returns the table, and a [`ParseError`] for each skipped line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsmount::tables::FsTab;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let (fstab, errors) = FsTab::import_file_collecting_errors("/etc/fstab")?;
+    ///
+    ///     for error in errors.iter() {
+    ///         eprintln!("{}", error);
+    ///     }
+    ///     println!("imported {} entries, {} errors", fstab.len(), errors.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn import_file_collecting_errors<T>(
+        file_path: T,
+    ) -> Result<(FsTab, Vec<ParseError>), FsTabError>
+    where
+        T: AsRef<Path>,
+    {
+        let file_path = file_path.as_ref();
+        let file_path_cstr = ffi_utils::as_ref_path_to_c_string(file_path)?;
+        log::debug!(
+            "FsTab::import_file_collecting_errors importing table entries from file {:?}",
+            file_path
+        );
+
+        let mut table = Self::new()?;
+
+        let (result, error_lines) =
+            ffi_utils::parse_recording_syntax_errors(table.inner, false, |table| unsafe {
+                libmount::mnt_table_parse_file(table, file_path_cstr.as_ptr())
+            });
+
+        match result {
+            0 => {
+                // Read the file once to quote the defective lines.
+                let lines: Vec<String> = if error_lines.is_empty() {
+                    vec![]
+                } else {
+                    fs::read(file_path)
+                        .map(|content| {
+                            String::from_utf8_lossy(&content)
+                                .lines()
+                                .map(str::to_owned)
+                                .collect()
+                        })
+                        .unwrap_or_default()
+                };
+
+                let errors: Vec<ParseError> = error_lines
+                    .into_iter()
+                    .map(|(line, _)| {
+                        let text = line
+                            .checked_sub(1)
+                            .and_then(|index| lines.get(index))
+                            .cloned()
+                            .unwrap_or_default();
+
+                        ParseError::new(line, text)
+                    })
+                    .collect();
+
+                log::debug!(
+                    "FsTab::import_file_collecting_errors imported {} table entries from file {:?}, skipped {} defective lines",
+                    table.len(),
+                    file_path,
+                    errors.len()
+                );
+
+                Ok((table, errors))
+            }
             code => {
                 let err_msg = format!("failed to import table entries from file {:?}", file_path);
-                log::debug!("FsTab::import_file {}. libmount::mnt_table_parse_file returned error code: {:?}", err_msg, code);
+                log::debug!("FsTab::import_file_collecting_errors {}. libmount::mnt_table_parse_file returned error code: {:?}", err_msg, code);
+
+                Err(FsTabError::Ffi {
+                    function: "mnt_table_parse_file",
+                    code,
+                })
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Explains why the parser rejected `line`, a line from an fstab file.
+    fn syntax_error_reason(line: &str) -> String {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let is_number = |field: &str| field.parse::<i32>().is_ok();
+
+        match fields.as_slice() {
+            [_] => "missing mount point".to_owned(),
+            [_, _] => "missing file system type".to_owned(),
+            [_, _, _, _, freq, ..] if !is_number(freq) => "invalid dump frequency".to_owned(),
+            [_, _, _, _, _, passno, ..] if !is_number(passno) => "invalid fsck order".to_owned(),
+            _ => format!("invalid entry {:?}", line.trim()),
+        }
+    }
+
+    #[doc(hidden)]
+    /// Runs the `parse` function on this table, then converts its outcome to a `Result`.
+    ///
+    /// Unless the user set up a parser error handler, records the line numbers of syntax errors,
+    /// and reports the line the parser stopped at as a [`FsTabError::Parse`]. The `read_line`
+    /// function gets the text of that line, given the file name reported by the parser, to
+    /// explain why the line is invalid.
+    fn import_with<T, R, F>(
+        &mut self,
+        function: &'static str,
+        source: T,
+        read_line: R,
+        parse: F,
+    ) -> Result<(), FsTabError>
+    where
+        T: AsRef<Path>,
+        R: FnOnce(Option<&Path>, usize) -> Option<String>,
+        F: FnOnce(*mut libmount::libmnt_table) -> libc::c_int,
+    {
+        let source = source.as_ref();
+
+        let (result, error_lines) = if self.has_parser_error_handler {
+            (parse(self.inner), vec![])
+        } else {
+            ffi_utils::parse_recording_syntax_errors(self.inner, self.abort_on_syntax_error, parse)
+        };
+
+        match (result, error_lines.last()) {
+            (0, _) => {
+                if !error_lines.is_empty() {
+                    log::debug!(
+                        "FsTab::import_with skipped lines with a syntax error in {:?}: {:?}",
+                        source,
+                        error_lines
+                    );
+                }
+
+                Ok(())
+            }
+            (_, Some((line, file_name))) => {
+                let reason = match read_line(file_name.as_deref(), *line) {
+                    Some(text) => Self::syntax_error_reason(&text),
+                    None => format!("syntax error in {:?}", source),
+                };
 
-                Err(FsTabError::Import(err_msg))
+                Err(FsTabError::Parse {
+                    line: *line,
+                    reason,
+                })
             }
+            (code, None) => Err(FsTabError::Ffi { function, code }),
         }
     }
 
@@ -1719,6 +2079,11 @@ pub fn import_file<T>(&mut self, file_path: T) -> Result<(), FsTabError>
     ///
     /// **Note:** by default, comment lines are ignored during importation. If you want them
     /// included, call [`FsTab::import_with_comments`] **before** invoking this method.
+    ///
+    /// By default, the parser skips lines with a syntax error. After a call to
+    /// [`FsTab::abort_on_syntax_error`], it stops at the first one instead, and this method returns
+    /// an [`io::Error`] of kind [`InvalidData`](io::ErrorKind::InvalidData) wrapping a
+    /// [`FsTabError::Parse`] with the number of the defective line.
     pub fn import_from_stream<T>(&mut self, file: &File, parsing_errors: T) -> io::Result<()>
     where
         T: AsRef<Path>,
@@ -1733,21 +2098,31 @@ pub fn import_from_stream<T>(&mut self, file: &File, parsing_errors: T) -> io::R
             parsing_errors
         );
 
-            let result = unsafe {
-                libmount::mnt_table_parse_stream(
-                    self.inner,
-                    file_stream as *mut _,
-                    path_cstr.as_ptr(),
-                )
-            };
+            // Where the parser starts reading, to find defective lines after it is done.
+            let mut reader = file;
+            let offset = reader.stream_position()?;
+
+            let result = self.import_with(
+                "mnt_table_parse_stream",
+                parsing_errors,
+                // The parser reports `parsing_errors` as the file name, read the stream instead.
+                |_, line| ffi_utils::read_line_at(file, offset, line),
+                |table| unsafe {
+                    libmount::mnt_table_parse_stream(
+                        table,
+                        file_stream as *mut _,
+                        path_cstr.as_ptr(),
+                    )
+                },
+            );
 
             match result {
-                0 => {
+                Ok(()) => {
                     log::debug!("FsTab::import_from_stream imported entries from file stream, saving parsing errors to {:?}", parsing_errors);
 
                     Ok(())
                 }
-                code => {
+                Err(FsTabError::Ffi { code, .. }) => {
                     let err_msg = format!(
                         "failed to import entries from file stream, saving parsing errors to {:?}",
                         parsing_errors
@@ -1756,6 +2131,11 @@ pub fn import_from_stream<T>(&mut self, file: &File, parsing_errors: T) -> io::R
 
                     Err(io::Error::from_raw_os_error(code))
                 }
+                Err(e) => {
+                    log::debug!("FsTab::import_from_stream {}", e);
+
+                    Err(io::Error::new(io::ErrorKind::InvalidData, e))
+                }
             }
         } else {
             let err_msg = "missing read permission for given file stream".to_owned();
@@ -1981,6 +2361,59 @@ pub fn dedup_last_by<F>(&mut self, cmp: F) -> Result<(), FsTabError>
         Self::filter_by(self, MNT_UNIQ_BACKWARD, cmp)
     }
 
+    /// Sorts the entries in this table with the comparison function `cmp`.
+    ///
+    /// This sort is stable (i.e. it does not reorder equal entries).
+    pub fn sort_by<F>(&mut self, cmp: F) -> Result<(), FsTabError>
+    where
+        F: FnMut(&FsTabEntry, &FsTabEntry) -> Ordering,
+    {
+        log::debug!("FsTab::sort_by sorting table entries");
+
+        // Temporarily increments each entry's reference counter, to keep them alive while they
+        // are moved around. Counters are decremented when `entries` goes out of scope.
+        let mut entries: Vec<FsTabEntry> = self
+            .iter()
+            .map(|entry| FsTabEntry::borrow_ptr(entry.inner))
+            .collect();
+
+        entries.sort_by(cmp);
+
+        // Moving each entry to the end of the table, in sorted order, leaves the table sorted.
+        for entry in entries.iter() {
+            Self::move_entry(
+                false,
+                self.inner,
+                entry.inner,
+                self.inner,
+                std::ptr::null_mut(),
+            )?;
+        }
+
+        log::debug!("FsTab::sort_by sorted table entries");
+
+        Ok(())
+    }
+
+    /// Sorts the entries in this table by the depth of their mount point, so that a mount point
+    /// always comes before the mount points nested under it (e.g. `/` before `/home` before
+    /// `/home/user`).
+    ///
+    /// Entries without a mount point are placed at the end of the table. This sort is stable
+    /// (i.e. it does not reorder entries of equal depth).
+    pub fn sort_by_target_depth(&mut self) -> Result<(), FsTabError> {
+        log::debug!("FsTab::sort_by_target_depth sorting table entries by mount point depth");
+
+        let depth = |entry: &FsTabEntry| {
+            entry
+                .target()
+                .map(|target| target.components().count())
+                .unwrap_or(usize::MAX)
+        };
+
+        self.sort_by(|this, other| depth(this).cmp(&depth(other)))
+    }
+
     /// Appends a [`FsTabEntry`] to this `FsTab`.
     ///
     /// # Panics
@@ -2013,78 +2446,236 @@ pub fn try_push(&mut self, element: FsTabEntry) -> Result<(), FsTabError> {
         }
     }
 
-    #[doc(hidden)]
-    /// Adds a new entry to the table before or after a specific table entry `pos`.
+    /// Appends a [`FsTabEntry`] to this `FsTab`, unless the table already has an entry with the
+    /// same `source`/`target` pair (see [`FsTab::find_pair`]). Returns `true` if the entry was
+    /// added, `false` otherwise.
     ///
-    /// - If `pos` is NULL and `before` is set to `true`, adds the new entry to the beginning of the table
-    /// - If `pos` is NULL and `before` is set to `false`, adds the new entry to the end of the table
-    fn insert_entry(
-        table: &mut Self,
-        after: bool,
-        pos: *mut libmount::libmnt_fs,
-        entry: *mut libmount::libmnt_fs,
-    ) -> Result<(), FsTabError> {
-        let op = if after { 1 } else { 0 };
-        let op_str = if after {
-            "after".to_owned()
-        } else {
-            "before".to_owned()
-        };
+    /// **Note:** entries with the same `target` but different `source`s are considered distinct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if memory allocation for extending capacity fails.
+    pub fn push_if_absent(&mut self, element: FsTabEntry) -> bool {
+        log::debug!("FsTab::push_if_absent adding a new table entry if absent");
 
-        let result = unsafe { libmount::mnt_table_insert_fs(table.inner, op, pos, entry) };
+        let is_present = match (element.source(), element.target()) {
+            (Some(source), Some(target)) => self.find_pair(&source, target).is_some(),
+            _ => false,
+        };
 
-        match result {
-            0 => {
-                log::debug!(
-                    "FsTab::insert_entry inserted new entry {} reference",
-                    op_str
-                );
+        if is_present {
+            log::debug!(
+                "FsTab::push_if_absent table already has an entry with the same source/target pair"
+            );
 
-                Ok(())
-            }
-            code => {
-                let err_msg = format!("failed to insert new entry {} reference", op_str);
-                log::debug!( "FsTab::insert_entry {err_msg}. libmount::mnt_table_insert_fs returned error code: {code:?}");
+            false
+        } else {
+            self.push(element);
 
-                Err(FsTabError::Action(err_msg))
-            }
+            true
         }
     }
 
-    /// Prepends a new element to the `FsTab`.
+    /// Adds copies of the entries in `other` to this `FsTab`, resolving conflicts between entries
+    /// with the same `source`/`target` pair (see [`FsTab::find_pair`]) according to `policy`:
+    /// - [`MergePolicy::KeepExisting`]: keeps the entry in this table, and skips the one in
+    ///   `other`,
+    /// - [`MergePolicy::Overwrite`]: replaces the entry in this table, in place, with the one in
+    ///   `other`,
+    /// - [`MergePolicy::AppendAll`]: appends every entry in `other`, even conflicting ones.
     ///
-    /// # Panics
+    /// Entries without a match in this table are appended at the end in all cases.
     ///
-    /// Panics if memory allocation fails.
-    pub fn push_front(&mut self, element: FsTabEntry) {
-        log::debug!("FsTab::push_front prepending new entry");
-
-        Self::insert_entry(self, true, std::ptr::null_mut(), element.inner).unwrap()
-    }
-
-    /// Tries to prepend a new element to the `FsTab`.
-    pub fn try_push_front(&mut self, element: FsTabEntry) -> Result<(), FsTabError> {
-        log::debug!("FsTab::try_push_front prepending new entry");
-
-        Self::insert_entry(self, true, std::ptr::null_mut(), element.inner)
-    }
-
-    /// Inserts an element at position `index` within the table, shifting all elements after it to
-    /// the bottom.
+    /// If `keep_comments` is `true`, the comment lines of the entries copied from `other` are
+    /// preserved, and `other`'s intro/trailing comments are appended to this table's. Otherwise,
+    /// they are all discarded.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Panics if `index` is out of bounds.
-    pub fn insert(&mut self, index: usize, element: FsTabEntry) {
-        self.try_insert(index, element).unwrap()
-    }
-
-    /// Tries to insert an element at position `index` within the table, shifting all elements
-    /// after it to the bottom.
-    pub fn try_insert(&mut self, index: usize, element: FsTabEntry) -> Result<(), FsTabError> {
-        log::debug!(
-            "FsTab::try_insert inserting new entry at index: {:?}",
-            index
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use rsmount::device::Tag;
+    /// use rsmount::entries::FsTabEntry;
+    /// use rsmount::fs::FileSystem;
+    /// use rsmount::tables::FsTab;
+    /// use rsmount::tables::MergePolicy;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let label: Tag = "LABEL=data".parse()?;
+    ///
+    ///     let mut fstab = FsTab::new()?;
+    ///     let entry = FsTabEntry::builder()
+    ///         .source(label.clone())
+    ///         .target("/data")
+    ///         .file_system_type(FileSystem::Ext4)
+    ///         .mount_options("rw")
+    ///         .build()?;
+    ///     fstab.push(entry);
+    ///
+    ///     // Entries from a drop-in file.
+    ///     let mut drop_in = FsTab::new()?;
+    ///     let entry = FsTabEntry::builder()
+    ///         .source(label)
+    ///         .target("/data")
+    ///         .file_system_type(FileSystem::Ext4)
+    ///         .mount_options("ro")
+    ///         .build()?;
+    ///     drop_in.push(entry);
+    ///
+    ///     fstab.merge(&drop_in, MergePolicy::Overwrite, false)?;
+    ///
+    ///     let actual = fstab.len();
+    ///     let expected = 1;
+    ///     assert_eq!(actual, expected);
+    ///
+    ///     let actual = fstab[0].mount_options();
+    ///     let expected = Some("ro");
+    ///     assert_eq!(actual, expected);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn merge(
+        &mut self,
+        other: &FsTab,
+        policy: MergePolicy,
+        keep_comments: bool,
+    ) -> Result<(), FsTabError> {
+        log::debug!(
+            "FsTab::merge merging tables with policy: {:?}, keeping comments: {:?}",
+            policy,
+            keep_comments
+        );
+
+        for entry in other.iter() {
+            let mut entry = entry.copy().map_err(|e| {
+                let err_msg = format!("failed to copy entry to merge. {}", e);
+                log::debug!("FsTab::merge {}", err_msg);
+
+                FsTabError::Action(err_msg)
+            })?;
+
+            if !keep_comments {
+                entry.remove_comment().map_err(|e| {
+                    let err_msg = format!("failed to remove comment from entry to merge. {}", e);
+                    log::debug!("FsTab::merge {}", err_msg);
+
+                    FsTabError::Action(err_msg)
+                })?;
+            }
+
+            let existing = match (entry.source(), entry.target()) {
+                (Some(source), Some(target)) => self.find_pair(&source, target).map(|e| e.inner),
+                _ => None,
+            };
+
+            match (policy, existing) {
+                (MergePolicy::KeepExisting, Some(_)) => {
+                    log::debug!("FsTab::merge keeping existing entry");
+                }
+                (MergePolicy::Overwrite, Some(existing)) => {
+                    log::debug!("FsTab::merge overwriting existing entry");
+
+                    // Insert the new entry right after the existing one, then remove the latter.
+                    Self::insert_entry(self, false, existing, entry.inner)?;
+
+                    let result = unsafe { libmount::mnt_table_remove_fs(self.inner, existing) };
+                    if result != 0 {
+                        let err_msg = "failed to remove overwritten entry from table".to_owned();
+                        log::debug!("FsTab::merge {}. libmount::mnt_table_remove_fs returned error code: {:?}", err_msg, result);
+
+                        return Err(FsTabError::Action(err_msg));
+                    }
+                }
+                _ => self.try_push(entry)?,
+            }
+        }
+
+        if keep_comments {
+            if let Some(comments) = other.intro_comments() {
+                self.append_to_intro_comments(comments)?;
+            }
+
+            if let Some(comments) = other.trailing_comments() {
+                self.append_to_trailing_comments(comments)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[doc(hidden)]
+    /// Adds a new entry to the table before or after a specific table entry `pos`.
+    ///
+    /// - If `pos` is NULL and `before` is set to `true`, adds the new entry to the beginning of the table
+    /// - If `pos` is NULL and `before` is set to `false`, adds the new entry to the end of the table
+    fn insert_entry(
+        table: &mut Self,
+        after: bool,
+        pos: *mut libmount::libmnt_fs,
+        entry: *mut libmount::libmnt_fs,
+    ) -> Result<(), FsTabError> {
+        let op = if after { 1 } else { 0 };
+        let op_str = if after {
+            "after".to_owned()
+        } else {
+            "before".to_owned()
+        };
+
+        let result = unsafe { libmount::mnt_table_insert_fs(table.inner, op, pos, entry) };
+
+        match result {
+            0 => {
+                log::debug!(
+                    "FsTab::insert_entry inserted new entry {} reference",
+                    op_str
+                );
+
+                Ok(())
+            }
+            code => {
+                let err_msg = format!("failed to insert new entry {} reference", op_str);
+                log::debug!( "FsTab::insert_entry {err_msg}. libmount::mnt_table_insert_fs returned error code: {code:?}");
+
+                Err(FsTabError::Action(err_msg))
+            }
+        }
+    }
+
+    /// Prepends a new element to the `FsTab`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if memory allocation fails.
+    pub fn push_front(&mut self, element: FsTabEntry) {
+        log::debug!("FsTab::push_front prepending new entry");
+
+        Self::insert_entry(self, true, std::ptr::null_mut(), element.inner).unwrap()
+    }
+
+    /// Tries to prepend a new element to the `FsTab`.
+    pub fn try_push_front(&mut self, element: FsTabEntry) -> Result<(), FsTabError> {
+        log::debug!("FsTab::try_push_front prepending new entry");
+
+        Self::insert_entry(self, true, std::ptr::null_mut(), element.inner)
+    }
+
+    /// Inserts an element at position `index` within the table, shifting all elements after it to
+    /// the bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn insert(&mut self, index: usize, element: FsTabEntry) {
+        self.try_insert(index, element).unwrap()
+    }
+
+    /// Tries to insert an element at position `index` within the table, shifting all elements
+    /// after it to the bottom.
+    pub fn try_insert(&mut self, index: usize, element: FsTabEntry) -> Result<(), FsTabError> {
+        log::debug!(
+            "FsTab::try_insert inserting new entry at index: {:?}",
+            index
         );
 
         let mut iter = FsTabIter::new(self)?;
@@ -2289,6 +2880,68 @@ pub fn transfer(
         }
     }
 
+    /// Moves the element at `index` to `dest_index` within this table, shifting the elements in
+    /// between.
+    ///
+    /// **Note:** `libmount::mnt_table_move_fs` keeps a reference to the element while it is
+    /// moved, so the element is neither deallocated nor duplicated in the process.
+    pub fn transfer_within(&mut self, index: usize, dest_index: usize) -> Result<(), FsTabError> {
+        let len = self.len();
+
+        if index >= len || dest_index >= len {
+            let err_msg = format!(
+                "failed to transfer element at index {:?} to index {:?}. Index out of bounds.",
+                index, dest_index
+            );
+            log::debug!("FsTab::transfer_within {err_msg}");
+
+            return Err(FsTabError::IndexOutOfBounds(err_msg));
+        }
+
+        if index == dest_index {
+            log::debug!(
+                "FsTab::transfer_within element already at index: {:?}",
+                index
+            );
+
+            return Ok(());
+        }
+
+        let entry = self[index].inner;
+
+        if dest_index == 0 {
+            log::debug!(
+                "FsTab::transfer_within transferring element at index: {:?} to start of table",
+                index
+            );
+
+            Self::move_entry(true, self.inner, entry, self.inner, std::ptr::null_mut())
+        } else if dest_index == len - 1 {
+            log::debug!(
+                "FsTab::transfer_within transferring element at index: {:?} to end of table",
+                index
+            );
+
+            Self::move_entry(false, self.inner, entry, self.inner, std::ptr::null_mut())
+        } else {
+            log::debug!(
+                "FsTab::transfer_within transferring element at index {:?} to index {:?}",
+                index,
+                dest_index
+            );
+
+            // Once the element is removed from the table, the entry currently at `dest_index`
+            // (or the one after it if the element moves down) will follow it.
+            let position = if dest_index < index {
+                self[dest_index].inner
+            } else {
+                self[dest_index + 1].inner
+            };
+
+            Self::move_entry(false, self.inner, entry, self.inner, position)
+        }
+    }
+
     /// Removes the given `element` from the table.
     ///
     /// # Panics
@@ -2340,6 +2993,50 @@ fn assert_failed() -> ! {
         }
     }
 
+    /// Removes the entry at `index` from the table, and returns it.
+    ///
+    /// Unlike [`FsTab::remove`], this method does not panic if `index` is out of bounds; it
+    /// returns an [`FsTabError::IndexOutOfBounds`] instead.
+    pub fn try_remove(&mut self, index: usize) -> Result<FsTabEntry, FsTabError> {
+        log::debug!("FsTab::try_remove removing entry at index: {:?}", index);
+
+        let inner = match self.get(index) {
+            Some(element) => element.inner,
+            None => {
+                let err_msg = format!("failed to find entry at index: {:?}", index);
+                log::debug!("FsTab::try_remove {err_msg}");
+
+                return Err(FsTabError::IndexOutOfBounds(err_msg));
+            }
+        };
+
+        // increment reference counter to prevent mnt_table_remove from deallocating the underlying
+        // table entry
+        let borrowed = FsTabEntry::borrow_ptr(inner);
+
+        let result = unsafe { libmount::mnt_table_remove_fs(self.inner, inner) };
+
+        match result {
+            0 => {
+                log::debug!("FsTab::try_remove removed entry from table");
+
+                Ok(borrowed)
+            }
+            code => {
+                let err_msg = format!("failed to remove entry at index: {:?}", index);
+                log::debug!(
+                    "FsTab::try_remove {err_msg}. libmount::mnt_table_remove_fs returned error code: {code:?}"
+                );
+
+                // the element is not in the table, so we decrement its reference counter by
+                // dropping it to cancel out the increment performed by FsTabEntry::borrow_ptr
+                drop(borrowed);
+
+                Err(FsTabError::Action(err_msg))
+            }
+        }
+    }
+
     /// Removes all table entries.
     pub fn clear(&mut self) -> Result<(), FsTabError> {
         log::debug!("FsTab::clear removing all table entries");
@@ -2391,74 +3088,431 @@ pub fn write_file<T>(&mut self, file_path: T) -> Result<(), FsTabError>
         }
     }
 
-    /// Writes this table's entries to a file stream.
-    pub fn write_stream(&mut self, file_stream: &mut File) -> io::Result<()> {
-        log::debug!("FsTab::write_stream writing mount table content to file stream");
-
-        if ffi_utils::is_open_write_only(file_stream)?
-            || ffi_utils::is_open_read_write(file_stream)?
-        {
-            let file = ffi_utils::write_only_c_file_stream_from(file_stream)?;
+    #[doc(hidden)]
+    /// Writes this table's entries to a new temporary file, in the same directory as `file_path`,
+    /// with the same mode and ownership as `file_path` if it exists. Returns the path to the
+    /// temporary file.
+    fn write_sibling_temp_file(&mut self, file_path: &Path) -> Result<PathBuf, FsTabError> {
+        let file_name = file_path.file_name().ok_or_else(|| {
+            let err_msg = format!("invalid file path: {:?}", file_path);
+            log::debug!("FsTab::write_sibling_temp_file {}", err_msg);
+
+            FsTabError::Export(err_msg)
+        })?;
+
+        let dir = match file_path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
 
-            let result = unsafe { libmount::mnt_table_write_file(self.inner, file as *mut _) };
-            match result {
-                0 => {
-                    log::debug!("FsTab::write_stream wrote mount table content to file stream");
+        // Hidden file with a unique name, e.g. `.fstab.1234.1700000000000000000.tmp`.
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or_default();
+        let mut tmp_file_name = OsString::from(".");
+        tmp_file_name.push(file_name);
+        tmp_file_name.push(format!(".{}.{}.tmp", std::process::id(), nanos));
+        let tmp_path = dir.join(tmp_file_name);
+        log::debug!(
+            "FsTab::write_sibling_temp_file writing table content to {:?}",
+            tmp_path
+        );
 
-                    Ok(())
+        let mut tmp_file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o644)
+            .open(&tmp_path)?;
+
+        let mut write_tmp_file = || -> io::Result<()> {
+            // Preserve the original file's mode and ownership.
+            if let Ok(metadata) = fs::metadata(file_path) {
+                tmp_file.set_permissions(metadata.permissions())?;
+
+                let tmp_metadata = tmp_file.metadata()?;
+                if (tmp_metadata.uid(), tmp_metadata.gid()) != (metadata.uid(), metadata.gid()) {
+                    std::os::unix::fs::fchown(
+                        &tmp_file,
+                        Some(metadata.uid()),
+                        Some(metadata.gid()),
+                    )?;
                 }
-                code => {
-                    let err_msg = "failed to write mount table content to file stream".to_owned();
-                    log::debug!( "FsTab::write_stream {err_msg}. libmount::mnt_table_write_file  returned error code: {code:?}");
+            }
 
-                    Err(io::Error::from_raw_os_error(code))
-                }
+            self.write_stream(&mut tmp_file)?;
+            tmp_file.sync_all()
+        };
+
+        match write_tmp_file() {
+            Ok(()) => {
+                log::debug!(
+                    "FsTab::write_sibling_temp_file wrote table content to {:?}",
+                    tmp_path
+                );
+
+                Ok(tmp_path)
             }
-        } else {
-            let err_msg = "you do not have permission to write in this file stream".to_owned();
-            log::debug!("FsTab::write_stream {err_msg}");
+            Err(e) => {
+                let err_msg = format!("failed to write table content to {:?}. {}", tmp_path, e);
+                log::debug!("FsTab::write_sibling_temp_file {}", err_msg);
 
-            Err(io::Error::from(io::ErrorKind::PermissionDenied))
+                let _ = fs::remove_file(&tmp_path);
+
+                Err(FsTabError::Export(err_msg))
+            }
         }
     }
 
-    //---- END mutators
+    /// Saves this table's entries to a file, atomically.
+    ///
+    /// This method writes the table to a temporary file in the same directory as `file_path`,
+    /// flushes it to disk, then renames it to `file_path`. If the process is interrupted midway,
+    /// `file_path` is either left untouched, or holds the new content in full; it never holds a
+    /// partially written table.
+    ///
+    /// If `file_path` already exists, the new file keeps its mode and ownership.
+    pub fn write_atomic<T>(&mut self, file_path: T) -> Result<(), FsTabError>
+    where
+        T: AsRef<Path>,
+    {
+        let file_path = file_path.as_ref();
+        log::debug!(
+            "FsTab::write_atomic saving table content to {:?}",
+            file_path
+        );
 
-    //---- BEGIN predicates
+        let tmp_path = self.write_sibling_temp_file(file_path)?;
 
-    /// Returns `true` if this `FsTab` contains a element matching **exactly** the given `element`.
-    pub fn contains(&self, element: &FsTabEntry) -> bool {
-        let state = unsafe { libmount::mnt_table_find_fs(self.inner, element.inner) > 0 };
-        log::debug!("FsTab::contains value: {:?}", state);
+        if let Err(e) = fs::rename(&tmp_path, file_path) {
+            let err_msg = format!("failed to replace {:?} by {:?}. {}", file_path, tmp_path, e);
+            log::debug!("FsTab::write_atomic {}", err_msg);
 
-        state
-    }
+            let _ = fs::remove_file(&tmp_path);
 
-    /// Returns `true` if the table has length of 0.
-    pub fn is_empty(&self) -> bool {
-        let state = unsafe { libmount::mnt_table_is_empty(self.inner) == 1 };
-        log::debug!("FsTab::is_empty value: {:?}", state);
+            return Err(FsTabError::Export(err_msg));
+        }
 
-        state
-    }
+        // Flush the parent directory to make the rename durable.
+        if let Some(dir) = file_path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            File::open(dir)?.sync_all()?;
+        }
 
-    /// Returns `true` if this `FsTab` is set to import table entries with their comments.
-    pub fn is_importing_comments(&self) -> bool {
-        let state = unsafe { libmount::mnt_table_with_comments(self.inner) == 1 };
-        log::debug!("FsTab::is_importing_comments value: {:?}", state);
+        log::debug!("FsTab::write_atomic saved table content to {:?}", file_path);
 
-        state
+        Ok(())
     }
 
-    /// Returns `true` if this `FsTab` is set to export intro/trailing comments.
-    pub fn is_exporting_comments(&self) -> bool {
-        let state = unsafe { libmount::mnt_table_with_comments(self.inner) == 1 };
-        log::debug!("FsTab::is_exporting_comments value: {:?}", state);
+    /// Saves this table's entries to a file, atomically (see [`FsTab::write_atomic`]), while
+    /// holding the given `lock`.
+    pub fn write_atomic_with_lock<T>(
+        &mut self,
+        file_path: T,
+        lock: &mut FileLock,
+    ) -> Result<(), FsTabError>
+    where
+        T: AsRef<Path>,
+    {
+        let file_path = file_path.as_ref();
+        log::debug!(
+            "FsTab::write_atomic_with_lock saving table content to {:?}",
+            file_path
+        );
 
-        state
+        let _guard = lock.lock().map_err(|e| {
+            let err_msg = format!(
+                "failed to lock file before saving to {:?}. {}",
+                file_path, e
+            );
+            log::debug!("FsTab::write_atomic_with_lock {}", err_msg);
+
+            FsTabError::Export(err_msg)
+        })?;
+
+        self.write_atomic(file_path)
+    }
+
+    /// Saves this table's entries to a file, atomically (see [`FsTab::write_atomic`]), after
+    /// copying the file's current content to a backup file named `file_path` followed by
+    /// `backup_suffix` (e.g. `/etc/fstab.bak` for a `.bak` suffix).
+    ///
+    /// No backup is made if `file_path` does not exist. This method returns an error if
+    /// `backup_suffix` is empty, since the backup would overwrite `file_path`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rsmount::tables::FsTab;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let mut fstab = FsTab::new()?;
+    ///     fstab.import_etc_fstab()?;
+    ///
+    ///     // Edit the table...
+    ///
+    ///     // Save the previous version of `/etc/fstab` to `/etc/fstab.bak` before replacing it.
+    ///     fstab.write_file_with_backup("/etc/fstab", ".bak")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn write_file_with_backup<T>(
+        &mut self,
+        file_path: T,
+        backup_suffix: &str,
+    ) -> Result<(), FsTabError>
+    where
+        T: AsRef<Path>,
+    {
+        let file_path = file_path.as_ref();
+
+        if backup_suffix.is_empty() {
+            let err_msg = format!("empty backup suffix, can not back up {:?}", file_path);
+            log::debug!("FsTab::write_file_with_backup {}", err_msg);
+
+            return Err(FsTabError::Export(err_msg));
+        }
+
+        let mut backup_path = file_path.as_os_str().to_owned();
+        backup_path.push(backup_suffix);
+        let backup_path = PathBuf::from(backup_path);
+        log::debug!(
+            "FsTab::write_file_with_backup backing up {:?} to {:?}",
+            file_path,
+            backup_path
+        );
+
+        match fs::copy(file_path, &backup_path) {
+            Ok(_) => {
+                File::open(&backup_path)?.sync_all()?;
+                log::debug!(
+                    "FsTab::write_file_with_backup backed up {:?} to {:?}",
+                    file_path,
+                    backup_path
+                );
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound && !file_path.exists() => {
+                log::debug!(
+                    "FsTab::write_file_with_backup no file to back up at {:?}",
+                    file_path
+                );
+            }
+            Err(e) => {
+                let err_msg = format!(
+                    "failed to back up {:?} to {:?}. {}",
+                    file_path, backup_path, e
+                );
+                log::debug!("FsTab::write_file_with_backup {}", err_msg);
+
+                return Err(FsTabError::Export(err_msg));
+            }
+        }
+
+        self.write_atomic(file_path)
+    }
+
+    /// Writes this table's entries to a file stream.
+    pub fn write_stream(&mut self, file_stream: &mut File) -> io::Result<()> {
+        log::debug!("FsTab::write_stream writing mount table content to file stream");
+
+        if ffi_utils::is_open_write_only(file_stream)?
+            || ffi_utils::is_open_read_write(file_stream)?
+        {
+            let file = ffi_utils::write_only_c_file_stream_from(file_stream)?;
+
+            let result = unsafe { libmount::mnt_table_write_file(self.inner, file as *mut _) };
+            match result {
+                0 => {
+                    log::debug!("FsTab::write_stream wrote mount table content to file stream");
+
+                    Ok(())
+                }
+                code => {
+                    let err_msg = "failed to write mount table content to file stream".to_owned();
+                    log::debug!( "FsTab::write_stream {err_msg}. libmount::mnt_table_write_file  returned error code: {code:?}");
+
+                    Err(io::Error::from_raw_os_error(code))
+                }
+            }
+        } else {
+            let err_msg = "you do not have permission to write in this file stream".to_owned();
+            log::debug!("FsTab::write_stream {err_msg}");
+
+            Err(io::Error::from(io::ErrorKind::PermissionDenied))
+        }
+    }
+
+    #[cfg(test)]
+    #[doc(hidden)]
+    /// Imports the `fstab` file at `file_path` with its comments, then saves it, comments
+    /// included, with [`FsTab::write_file`] to a temporary file. Returns the saved file's content.
+    pub(crate) fn round_trip<T>(file_path: T) -> Result<String, FsTabError>
+    where
+        T: AsRef<Path>,
+    {
+        let mut fs_tab = FsTab::new()?;
+        fs_tab.import_with_comments();
+        fs_tab.import_file(file_path)?;
+
+        let tmp_dir = tempfile::tempdir()?;
+        let saved_path = tmp_dir.path().join("fstab");
+        fs_tab.export_with_comments();
+        fs_tab.write_file(&saved_path)?;
+
+        let content = fs::read_to_string(&saved_path)?;
+
+        Ok(content)
+    }
+
+    //---- END mutators
+
+    //---- BEGIN predicates
+
+    /// Returns `true` if this `FsTab` contains a element matching **exactly** the given `element`.
+    pub fn contains(&self, element: &FsTabEntry) -> bool {
+        let state = unsafe { libmount::mnt_table_find_fs(self.inner, element.inner) > 0 };
+        log::debug!("FsTab::contains value: {:?}", state);
+
+        state
+    }
+
+    /// Returns `true` if the table has length of 0.
+    pub fn is_empty(&self) -> bool {
+        let state = unsafe { libmount::mnt_table_is_empty(self.inner) == 1 };
+        log::debug!("FsTab::is_empty value: {:?}", state);
+
+        state
+    }
+
+    /// Returns `true` if this `FsTab` is set to import table entries with their comments.
+    pub fn is_importing_comments(&self) -> bool {
+        let state = unsafe { libmount::mnt_table_with_comments(self.inner) == 1 };
+        log::debug!("FsTab::is_importing_comments value: {:?}", state);
+
+        state
+    }
+
+    /// Returns `true` if this `FsTab` is set to stop importing table entries at the first line
+    /// with a syntax error.
+    pub fn is_aborting_on_syntax_error(&self) -> bool {
+        let state = self.abort_on_syntax_error;
+        log::debug!("FsTab::is_aborting_on_syntax_error value: {:?}", state);
+
+        state
+    }
+
+    /// Returns `true` if this `FsTab` is set to export intro/trailing comments.
+    pub fn is_exporting_comments(&self) -> bool {
+        let state = unsafe { libmount::mnt_table_with_comments(self.inner) == 1 };
+        log::debug!("FsTab::is_exporting_comments value: {:?}", state);
+
+        state
     }
 
     //---- END predicates
+
+    /// Checks every entry in this `FsTab` for semantic mistakes with [`FsTabEntry::validate`],
+    /// then checks entries against each other for:
+    /// - duplicate mount points,
+    /// - entries listed before the entry of their parent mount point (except `/`),
+    /// - multiple entries mounted at `/`.
+    ///
+    /// Swap entries, and entries with a relative mount point are excluded from cross-entry checks.
+    ///
+    /// Returns a map from the index of each entry with issues to the list of its issues; the map
+    /// is empty if no issue was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use rsmount::device::Pseudo;
+    /// use rsmount::entries::{FsTabEntry, ValidationIssue};
+    /// use rsmount::fs::FileSystem;
+    /// use rsmount::tables::FsTab;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let mut fstab = FsTab::new()?;
+    ///
+    ///     // none /tmp tmpfs
+    ///     for _ in 0..2 {
+    ///         let entry = FsTabEntry::builder()
+    ///             .source(Pseudo::None)
+    ///             .target("/tmp")
+    ///             .file_system_type(FileSystem::Tmpfs)
+    ///             .build()?;
+    ///
+    ///         fstab.push(entry);
+    ///     }
+    ///
+    ///     // The second entry has the same mount point as the first one.
+    ///     let issues = fstab.validate_all();
+    ///     let expected = BTreeMap::from([(
+    ///         1,
+    ///         vec![ValidationIssue::DuplicateTarget {
+    ///             target: "/tmp".into(),
+    ///             first: 0,
+    ///         }],
+    ///     )]);
+    ///
+    ///     assert_eq!(issues, expected);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn validate_all(&self) -> BTreeMap<usize, Vec<ValidationIssue>> {
+        log::debug!("FsTab::validate_all checking table entries for semantic mistakes");
+
+        let mut issues: BTreeMap<usize, Vec<ValidationIssue>> = BTreeMap::new();
+        // Normalized mount points of the entries taking part in cross-entry checks.
+        let mut targets: Vec<(usize, PathBuf)> = Vec::new();
+        let root = Path::new("/");
+
+        for (index, entry) in self.iter().enumerate() {
+            if let Err(entry_issues) = entry.validate() {
+                issues.entry(index).or_default().extend(entry_issues);
+            }
+
+            let target: PathBuf = match entry.target() {
+                Some(target) if !entry.is_swap() && target.is_absolute() => {
+                    target.components().collect()
+                }
+                _otherwise => continue,
+            };
+
+            if let Some((first, _)) = targets.iter().find(|(_, other)| *other == target) {
+                let issue = if target == root {
+                    ValidationIssue::MultipleRoots { first: *first }
+                } else {
+                    ValidationIssue::DuplicateTarget {
+                        target: target.clone(),
+                        first: *first,
+                    }
+                };
+
+                issues.entry(index).or_default().push(issue);
+            }
+
+            if target != root {
+                for (child, child_target) in targets.iter() {
+                    if *child_target != target && child_target.starts_with(&target) {
+                        let issue = ValidationIssue::MountedBeforeParent {
+                            target: child_target.clone(),
+                            parent: index,
+                        };
+
+                        issues.entry(*child).or_default().push(issue);
+                    }
+                }
+            }
+
+            targets.push((index, target));
+        }
+
+        log::debug!("FsTab::validate_all found issues: {:?}", issues);
+
+        issues
+    }
 }
 
 impl fmt::Display for FsTab {
@@ -2492,6 +3546,7 @@ mod tests {
     use pretty_assertions::{assert_eq, assert_ne};
     use std::fs::{File, OpenOptions};
     use std::io::{Read, Seek, SeekFrom};
+    use std::os::unix::fs::PermissionsExt;
     use std::str::FromStr;
     use tempfile::{tempdir, tempfile};
 
@@ -3247,78 +4302,70 @@ fn fs_tab_can_remove_an_element_from_a_table() -> crate::Result<()> {
     }
 
     #[test]
-    fn fs_tab_can_transfer_an_element_between_tables_to_destination_start() -> crate::Result<()> {
+    fn fs_tab_can_try_to_remove_an_element_from_a_table() -> crate::Result<()> {
         // UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f / ext4 rw,relatime 0 1
         let uuid = Tag::from_str("UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f")?;
         let entry1 = FsTabEntry::builder()
             .source(uuid)
             .target("/")
             .file_system_type(FileSystem::Ext4)
-            // Comma-separated list of mount options.
             .mount_options("rw,relatime")
-            // Interval, in days, between file system backups by the dump command on ext2/3/4
-            // file systems.
             .backup_frequency(0)
-            // Order in which file systems are checked by the `fsck` command.
             .fsck_checking_order(1)
             .build()?;
 
-        // /dev/usbdisk /media/usb vfat noauto 0 0
-        let block_device = BlockDevice::from_str("/dev/usbdisk")?;
+        // UUID=ac4f36bf-191b-4fb0-b808-6d7fc9fc88be none swap sw 0 0
+        let uuid = Tag::from_str("UUID=ac4f36bf-191b-4fb0-b808-6d7fc9fc88be")?;
         let entry2 = FsTabEntry::builder()
-            .source(block_device)
-            .target("/media/usb")
-            .file_system_type(FileSystem::VFAT)
-            .mount_options("noauto")
+            .source(uuid)
+            .target("none")
+            .file_system_type(FileSystem::Swap)
+            .mount_options("sw")
             .backup_frequency(0)
             .fsck_checking_order(0)
             .build()?;
 
-        // tmpfs /tmp tmpfs nosuid,nodev 0 0
+        // /dev/usbdisk /media/usb vfat noauto 0 0
+        let block_device = BlockDevice::from_str("/dev/usbdisk")?;
         let entry3 = FsTabEntry::builder()
-            .source(Pseudo::None)
-            .target("/tmp")
-            .file_system_type(FileSystem::Tmpfs)
-            .mount_options("nosuid,nodev")
+            .source(block_device)
+            .target("/media/usb")
+            .file_system_type(FileSystem::VFAT)
+            .mount_options("noauto")
             .backup_frequency(0)
             .fsck_checking_order(0)
             .build()?;
 
-        let entry1_inner = entry1.inner;
-        let entry2_inner = entry2.inner;
-        let entry3_inner = entry3.inner;
-
-        let mut source_table = FsTab::new()?;
-        source_table.push(entry1);
-
-        let mut dest_table = FsTab::new()?;
-        dest_table.push(entry2);
-        dest_table.push(entry3);
+        let mut fs_tab = FsTab::new()?;
+        fs_tab.push(entry1);
+        fs_tab.push(entry2);
+        fs_tab.push(entry3);
 
-        // Before transfer
-        assert_eq!(source_table.len(), 1);
-        assert_eq!(dest_table.len(), 2);
+        assert_eq!(fs_tab.len(), 3);
 
-        source_table.transfer(0, &mut dest_table, 0)?;
+        let index = fs_tab.iter().position(|e| e.is_swap()).unwrap();
+        let item = fs_tab.try_remove(index)?;
 
-        // After transfer
-        assert_eq!(source_table.is_empty(), true);
+        let actual = item.file_system_type().unwrap();
+        let expected = FileSystem::Swap;
+        assert_eq!(actual, expected);
 
-        assert_eq!(dest_table.len(), 3);
+        let actual = fs_tab.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
 
-        let first_inner = dest_table[0].inner;
-        let second_inner = dest_table[1].inner;
-        let third_inner = dest_table[2].inner;
+        let actual: Vec<_> = fs_tab.iter().map(|e| e.target().unwrap()).collect();
+        let expected = vec![Path::new("/"), Path::new("/media/usb")];
+        assert_eq!(actual, expected);
 
-        assert_eq!(first_inner, entry1_inner);
-        assert_eq!(second_inner, entry2_inner);
-        assert_eq!(third_inner, entry3_inner);
+        let actual = fs_tab.try_remove(2);
+        assert!(matches!(actual, Err(FsTabError::IndexOutOfBounds(_))));
 
         Ok(())
     }
 
     #[test]
-    fn fs_tab_can_transfer_an_element_between_tables_to_destination_middle() -> crate::Result<()> {
+    fn fs_tab_can_transfer_an_element_between_tables_to_destination_start() -> crate::Result<()> {
         // UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f / ext4 rw,relatime 0 1
         let uuid = Tag::from_str("UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f")?;
         let entry1 = FsTabEntry::builder()
@@ -3370,7 +4417,7 @@ fn fs_tab_can_transfer_an_element_between_tables_to_destination_middle() -> crat
         assert_eq!(source_table.len(), 1);
         assert_eq!(dest_table.len(), 2);
 
-        source_table.transfer(0, &mut dest_table, 1)?;
+        source_table.transfer(0, &mut dest_table, 0)?;
 
         // After transfer
         assert_eq!(source_table.is_empty(), true);
@@ -3381,15 +4428,15 @@ fn fs_tab_can_transfer_an_element_between_tables_to_destination_middle() -> crat
         let second_inner = dest_table[1].inner;
         let third_inner = dest_table[2].inner;
 
-        assert_eq!(first_inner, entry2_inner);
-        assert_eq!(second_inner, entry1_inner);
+        assert_eq!(first_inner, entry1_inner);
+        assert_eq!(second_inner, entry2_inner);
         assert_eq!(third_inner, entry3_inner);
 
         Ok(())
     }
 
     #[test]
-    fn fs_tab_can_transfer_an_element_between_tables_to_destination_end() -> crate::Result<()> {
+    fn fs_tab_can_transfer_an_element_between_tables_to_destination_middle() -> crate::Result<()> {
         // UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f / ext4 rw,relatime 0 1
         let uuid = Tag::from_str("UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f")?;
         let entry1 = FsTabEntry::builder()
@@ -3441,7 +4488,7 @@ fn fs_tab_can_transfer_an_element_between_tables_to_destination_end() -> crate::
         assert_eq!(source_table.len(), 1);
         assert_eq!(dest_table.len(), 2);
 
-        source_table.transfer(0, &mut dest_table, 2)?;
+        source_table.transfer(0, &mut dest_table, 1)?;
 
         // After transfer
         assert_eq!(source_table.is_empty(), true);
@@ -3453,36 +4500,993 @@ fn fs_tab_can_transfer_an_element_between_tables_to_destination_end() -> crate::
         let third_inner = dest_table[2].inner;
 
         assert_eq!(first_inner, entry2_inner);
-        assert_eq!(second_inner, entry3_inner);
-        assert_eq!(third_inner, entry1_inner);
+        assert_eq!(second_inner, entry1_inner);
+        assert_eq!(third_inner, entry3_inner);
 
         Ok(())
     }
 
     #[test]
-    fn fs_tab_writes_to_a_file_stream() -> crate::Result<()> {
+    fn fs_tab_can_transfer_an_element_within_a_table() -> crate::Result<()> {
         let mut fs_tab = FsTab::new()?;
 
-        // # /etc/fstab
-        fs_tab.set_intro_comments("# /etc/fstab\n")?;
-        // # Example from scratch
-        fs_tab.append_to_intro_comments("# Example from scratch\n")?;
-        //
-        fs_tab.append_to_intro_comments("\n")?;
+        for target in ["/", "/home", "/tmp", "/var"] {
+            let entry = FsTabEntry::builder()
+                .source(Pseudo::None)
+                .target(target)
+                .file_system_type(FileSystem::Tmpfs)
+                .build()?;
 
-        // UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f / ext4 rw,relatime 0 1
-        let uuid = Tag::from_str("UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f")?;
-        let entry1 = FsTabEntry::builder()
-            .source(uuid)
-            .target("/")
-            .file_system_type(FileSystem::Ext4)
-            // Comma-separated list of mount options.
-            .mount_options("rw,relatime")
-            // Interval, in days, between file system backups by the dump command on ext2/3/4
-            // file systems.
-            .backup_frequency(0)
-            // Order in which file systems are checked by the `fsck` command.
-            .fsck_checking_order(1)
+            fs_tab.push(entry);
+        }
+
+        // Move down
+        fs_tab.transfer_within(0, 2)?;
+
+        let actual: Vec<_> = fs_tab.iter().map(|e| e.target().unwrap()).collect();
+        let expected = vec![
+            Path::new("/home"),
+            Path::new("/tmp"),
+            Path::new("/"),
+            Path::new("/var"),
+        ];
+        assert_eq!(actual, expected);
+
+        // Move up
+        fs_tab.transfer_within(3, 1)?;
+
+        let actual: Vec<_> = fs_tab.iter().map(|e| e.target().unwrap()).collect();
+        let expected = vec![
+            Path::new("/home"),
+            Path::new("/var"),
+            Path::new("/tmp"),
+            Path::new("/"),
+        ];
+        assert_eq!(actual, expected);
+
+        // Move to start, then to end
+        fs_tab.transfer_within(3, 0)?;
+        fs_tab.transfer_within(1, 3)?;
+
+        let actual: Vec<_> = fs_tab.iter().map(|e| e.target().unwrap()).collect();
+        let expected = vec![
+            Path::new("/"),
+            Path::new("/var"),
+            Path::new("/tmp"),
+            Path::new("/home"),
+        ];
+        assert_eq!(actual, expected);
+
+        assert_eq!(fs_tab.len(), 4);
+
+        let actual = fs_tab.transfer_within(0, 4);
+        assert!(matches!(actual, Err(FsTabError::IndexOutOfBounds(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_sort_entries_by_target_depth() -> crate::Result<()> {
+        let mut fs_tab = FsTab::new()?;
+
+        for target in ["/home/user", "/var/log", "/", "/home", "/var"] {
+            let entry = FsTabEntry::builder()
+                .source(Pseudo::None)
+                .target(target)
+                .file_system_type(FileSystem::Tmpfs)
+                .build()?;
+
+            fs_tab.push(entry);
+        }
+
+        fs_tab.sort_by_target_depth()?;
+
+        let actual: Vec<_> = fs_tab.iter().map(|e| e.target().unwrap()).collect();
+        let expected = vec![
+            Path::new("/"),
+            Path::new("/home"),
+            Path::new("/var"),
+            Path::new("/home/user"),
+            Path::new("/var/log"),
+        ];
+        assert_eq!(actual, expected);
+
+        // Parents come before their children.
+        for (i, entry) in fs_tab.iter().enumerate() {
+            let target = entry.target().unwrap();
+
+            for child in fs_tab.iter().skip(i + 1) {
+                let child_target = child.target().unwrap();
+                assert!(!target.starts_with(child_target));
+            }
+        }
+
+        assert_eq!(fs_tab.len(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_sort_entries_by_target() -> crate::Result<()> {
+        let mut fs_tab = FsTab::new()?;
+
+        for target in ["/var", "/", "/tmp", "/home"] {
+            let entry = FsTabEntry::builder()
+                .source(Pseudo::None)
+                .target(target)
+                .file_system_type(FileSystem::Tmpfs)
+                .build()?;
+
+            fs_tab.push(entry);
+        }
+
+        fs_tab.sort_by(|this, other| this.target().cmp(&other.target()))?;
+
+        let actual: Vec<_> = fs_tab.iter().map(|e| e.target().unwrap()).collect();
+        let expected = vec![
+            Path::new("/"),
+            Path::new("/home"),
+            Path::new("/tmp"),
+            Path::new("/var"),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_find_a_target_regardless_of_trailing_or_repeated_slashes() -> crate::Result<()> {
+        let mut fs_tab = FsTab::new()?;
+
+        for target in ["/", "/home", "/var"] {
+            let entry = FsTabEntry::builder()
+                .source(Pseudo::None)
+                .target(target)
+                .file_system_type(FileSystem::Tmpfs)
+                .build()?;
+
+            fs_tab.push(entry);
+        }
+
+        for path in ["/home", "/home/", "//home", "//home//"] {
+            let actual = fs_tab.find_target(path).and_then(|e| e.target());
+            let expected = Some(Path::new("/home"));
+            assert_eq!(actual, expected, "find_target({:?})", path);
+
+            let actual = fs_tab.find_back_target(path).and_then(|e| e.target());
+            let expected = Some(Path::new("/home"));
+            assert_eq!(actual, expected, "find_back_target({:?})", path);
+        }
+
+        let actual = fs_tab.find_target("/").and_then(|e| e.target());
+        let expected = Some(Path::new("/"));
+        assert_eq!(actual, expected);
+
+        let actual = fs_tab.find_target("/home/user");
+        assert!(actual.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_find_target_normalizes_paths_like_normalize_target() -> crate::Result<()> {
+        let mut fs_tab = FsTab::new()?;
+
+        for target in ["/mnt/data", "/mnt/./data"] {
+            let entry = FsTabEntry::builder()
+                .source(Pseudo::None)
+                .target(target)
+                .file_system_type(FileSystem::Tmpfs)
+                .build()?;
+
+            fs_tab.push(entry);
+        }
+
+        for path in ["/mnt//data/", "/mnt/./data//"] {
+            let normalized = utils::normalize_target(path);
+
+            let actual = fs_tab.find_target(path).and_then(|e| e.target());
+            let expected = Some(Path::new(&normalized));
+            assert_eq!(actual, expected, "find_target({:?})", path);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_push_if_absent_skips_entries_with_the_same_source_target_pair() -> crate::Result<()> {
+        let mut fs_tab = FsTab::new()?;
+
+        let uuid: Tag = "UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f".parse()?;
+        let entry = FsTabEntry::builder()
+            .source(uuid.clone())
+            .target("/")
+            .file_system_type(FileSystem::Ext4)
+            .build()?;
+
+        let duplicate = FsTabEntry::builder()
+            .source(uuid)
+            .target("/")
+            .file_system_type(FileSystem::Ext4)
+            .mount_options("ro")
+            .build()?;
+
+        assert!(fs_tab.push_if_absent(entry));
+        assert!(!fs_tab.push_if_absent(duplicate));
+
+        let actual = fs_tab.len();
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        let actual = fs_tab[0].mount_options();
+        assert!(actual.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_push_if_absent_keeps_entries_with_the_same_target_and_different_sources(
+    ) -> crate::Result<()> {
+        let mut fs_tab = FsTab::new()?;
+
+        let block_device = BlockDevice::from_str("/dev/usbdisk")?;
+        let entry = FsTabEntry::builder()
+            .source(block_device)
+            .target("/media/usb")
+            .file_system_type(FileSystem::VFAT)
+            .build()?;
+
+        let label: Tag = "LABEL=backup".parse()?;
+        let other = FsTabEntry::builder()
+            .source(label)
+            .target("/media/usb")
+            .file_system_type(FileSystem::Ext4)
+            .build()?;
+
+        assert!(fs_tab.push_if_absent(entry));
+        assert!(fs_tab.push_if_absent(other));
+
+        let actual = fs_tab.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    static MALFORMED_FSTAB: &str = r#"# /etc/fstab
+LABEL=root / ext4 rw,relatime 0 1
+/dev/sdb1
+LABEL=data /data ext4 rw,relatime 0 2
+"#;
+
+    #[test]
+    fn fs_tab_import_file_skips_lines_with_a_syntax_error_by_default() -> crate::Result<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        fs::write(&path, MALFORMED_FSTAB).unwrap();
+
+        let mut fs_tab = FsTab::new()?;
+        assert!(!fs_tab.is_aborting_on_syntax_error());
+
+        fs_tab.import_file(&path)?;
+
+        let actual = fs_tab.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_import_file_reports_the_line_of_a_syntax_error() -> crate::Result<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        fs::write(&path, MALFORMED_FSTAB).unwrap();
+
+        let mut fs_tab = FsTab::new()?;
+        fs_tab.abort_on_syntax_error();
+        let err = fs_tab.import_file(&path).unwrap_err();
+
+        assert!(matches!(err, FsTabError::Parse { line: 3, .. }));
+
+        let err = crate::RsMountError::from(err);
+        match err {
+            crate::RsMountError::Parse { line, reason } => {
+                let actual = line;
+                let expected = 3;
+                assert_eq!(actual, expected);
+
+                let actual = reason;
+                let expected = "missing mount point";
+                assert_eq!(actual, expected);
+            }
+            err => panic!("expected a parsing error, got: {:?}", err),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_import_file_reports_why_a_line_is_invalid() -> crate::Result<()> {
+        let content = r#"LABEL=root / ext4 rw,relatime 0 1
+LABEL=data /data ext4 rw,relatime 0 x
+"#;
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        fs::write(&path, content).unwrap();
+
+        let mut fs_tab = FsTab::new()?;
+        fs_tab.abort_on_syntax_error();
+        let err = fs_tab.import_file(&path).unwrap_err();
+
+        let actual = err.to_string();
+        let expected = "line 2: invalid fsck order";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_import_from_stream_reports_the_line_of_a_syntax_error() -> crate::Result<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        fs::write(&path, MALFORMED_FSTAB).unwrap();
+        let file = File::open(&path).unwrap();
+
+        let mut fs_tab = FsTab::new()?;
+        fs_tab.abort_on_syntax_error();
+        let err = fs_tab
+            .import_from_stream(&file, dir.path().join("errors"))
+            .unwrap_err();
+
+        let actual = err.kind();
+        let expected = io::ErrorKind::InvalidData;
+        assert_eq!(actual, expected);
+
+        let inner = err.get_ref().and_then(|e| e.downcast_ref::<FsTabError>());
+        assert!(matches!(inner, Some(FsTabError::Parse { line: 3, .. })));
+
+        let actual = inner.unwrap().to_string();
+        let expected = "line 3: missing mount point";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_import_file_lets_a_parser_error_handler_skip_defective_lines() -> crate::Result<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        fs::write(&path, MALFORMED_FSTAB).unwrap();
+
+        let mut fs_tab = FsTab::new()?;
+        fs_tab.set_parser_error_handler(|_file_name, _line| ParserFlow::Ignore)?;
+        fs_tab.import_file(&path)?;
+
+        let actual = fs_tab.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_import_a_file_collecting_syntax_errors() -> crate::Result<()> {
+        let content = r#"# /etc/fstab
+LABEL=root / ext4 rw,relatime 0 1
+/dev/sdb1
+LABEL=data /data ext4 rw,relatime 0 2
+LABEL=home
+LABEL=backup /backup ext4 rw,relatime 0 2
+"#;
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        fs::write(&path, content).unwrap();
+
+        let (fs_tab, errors) = FsTab::import_file_collecting_errors(&path)?;
+
+        let actual = fs_tab.len();
+        let expected = 3;
+        assert_eq!(actual, expected);
+
+        let actual = errors;
+        let expected = vec![
+            ParseError::new(3, "/dev/sdb1".to_owned()),
+            ParseError::new(5, "LABEL=home".to_owned()),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_validate_all_reports_cross_entry_issues() -> crate::Result<()> {
+        let content = r#"LABEL=root / ext4 rw,relatime 0 1
+LABEL=user /home/user ext4 rw,relatime 0 2
+LABEL=home /home ext4 rw,relatime 0 2
+LABEL=backup /home ext4 rw,relatime 0 2
+LABEL=scratch /scratch ext4 ro,rw 0 2
+"#;
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        fs::write(&path, content).unwrap();
+
+        let fs_tab = FsTab::new_from_file(&path)?;
+
+        let actual = fs_tab.validate_all();
+        let expected = BTreeMap::from([
+            (
+                1,
+                vec![
+                    ValidationIssue::MountedBeforeParent {
+                        target: "/home/user".into(),
+                        parent: 2,
+                    },
+                    ValidationIssue::MountedBeforeParent {
+                        target: "/home/user".into(),
+                        parent: 3,
+                    },
+                ],
+            ),
+            (
+                3,
+                vec![ValidationIssue::DuplicateTarget {
+                    target: "/home".into(),
+                    first: 2,
+                }],
+            ),
+            (
+                4,
+                vec![ValidationIssue::ContradictoryOptions(
+                    "ro".to_owned(),
+                    "rw".to_owned(),
+                )],
+            ),
+        ]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_validate_all_reports_multiple_roots() -> crate::Result<()> {
+        let content = r#"LABEL=root / ext4 rw,relatime 0 1
+/dev/sda2 none swap sw 0 0
+/dev/sda3 none swap sw 0 0
+LABEL=rescue / ext4 rw,relatime 0 1
+"#;
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        fs::write(&path, content).unwrap();
+
+        let fs_tab = FsTab::new_from_file(&path)?;
+
+        // Swap entries sharing the `none` mount point are not duplicates.
+        let actual = fs_tab.validate_all();
+        let expected = BTreeMap::from([(3, vec![ValidationIssue::MultipleRoots { first: 0 }])]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    fn merge_fixtures() -> crate::Result<(FsTab, FsTab)> {
+        let mut fs_tab = FsTab::new()?;
+        let entries = [("LABEL=root", "/", "rw"), ("LABEL=data", "/data", "rw")];
+        for (label, target, options) in entries {
+            let label: Tag = label.parse()?;
+            let entry = FsTabEntry::builder()
+                .source(label)
+                .target(target)
+                .file_system_type(FileSystem::Ext4)
+                .mount_options(options)
+                .build()?;
+
+            fs_tab.push(entry);
+        }
+
+        let mut other = FsTab::new()?;
+        let entries = [("LABEL=data", "/data", "ro"), ("LABEL=home", "/home", "rw")];
+        for (label, target, options) in entries {
+            let label: Tag = label.parse()?;
+            let mut entry = FsTabEntry::builder()
+                .source(label)
+                .target(target)
+                .file_system_type(FileSystem::Ext4)
+                .mount_options(options)
+                .build()?;
+            entry.set_comment(format!("# {}", target))?;
+
+            other.push(entry);
+        }
+        other.set_intro_comments("# Drop-in\n")?;
+
+        Ok((fs_tab, other))
+    }
+
+    #[test]
+    fn fs_tab_can_merge_tables_keeping_existing_entries() -> crate::Result<()> {
+        let (mut fs_tab, other) = merge_fixtures()?;
+
+        fs_tab.merge(&other, MergePolicy::KeepExisting, false)?;
+
+        let actual: Vec<_> = fs_tab
+            .iter()
+            .map(|e| (e.target().unwrap(), e.mount_options().unwrap()))
+            .collect();
+        let expected = vec![
+            (Path::new("/"), "rw"),
+            (Path::new("/data"), "rw"),
+            (Path::new("/home"), "rw"),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_merge_tables_overwriting_existing_entries() -> crate::Result<()> {
+        let (mut fs_tab, other) = merge_fixtures()?;
+
+        fs_tab.merge(&other, MergePolicy::Overwrite, false)?;
+
+        let actual: Vec<_> = fs_tab
+            .iter()
+            .map(|e| (e.target().unwrap(), e.mount_options().unwrap()))
+            .collect();
+        let expected = vec![
+            (Path::new("/"), "rw"),
+            (Path::new("/data"), "ro"),
+            (Path::new("/home"), "rw"),
+        ];
+        assert_eq!(actual, expected);
+
+        // The source table is left untouched.
+        let actual = other.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_merge_tables_appending_all_entries() -> crate::Result<()> {
+        let (mut fs_tab, other) = merge_fixtures()?;
+
+        fs_tab.merge(&other, MergePolicy::AppendAll, false)?;
+
+        let actual: Vec<_> = fs_tab
+            .iter()
+            .map(|e| (e.target().unwrap(), e.mount_options().unwrap()))
+            .collect();
+        let expected = vec![
+            (Path::new("/"), "rw"),
+            (Path::new("/data"), "rw"),
+            (Path::new("/data"), "ro"),
+            (Path::new("/home"), "rw"),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_merge_tables_with_or_without_comments() -> crate::Result<()> {
+        let (mut fs_tab, other) = merge_fixtures()?;
+
+        fs_tab.merge(&other, MergePolicy::AppendAll, false)?;
+
+        assert!(fs_tab.iter().all(|e| e.comment().is_none()));
+        assert!(fs_tab.intro_comments().is_none());
+
+        let (mut fs_tab, other) = merge_fixtures()?;
+
+        fs_tab.merge(&other, MergePolicy::AppendAll, true)?;
+
+        let actual: Vec<_> = fs_tab.iter().map(|e| e.comment()).collect();
+        let expected = vec![None, None, Some("# /data\n"), Some("# /home\n")];
+        assert_eq!(actual, expected);
+
+        let actual = fs_tab.intro_comments();
+        let expected = Some("# Drop-in\n");
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_iterates_in_reverse_over_the_same_entries_in_opposite_order() -> crate::Result<()> {
+        let mut fs_tab = FsTab::new()?;
+
+        for target in ["/", "/home", "/var", "/tmp"] {
+            let entry = FsTabEntry::builder()
+                .source(Pseudo::None)
+                .target(target)
+                .file_system_type(FileSystem::Tmpfs)
+                .build()?;
+
+            fs_tab.push(entry);
+        }
+
+        let mut forward: Vec<_> = fs_tab.iter().map(|e| e.target().unwrap()).collect();
+        let backward: Vec<_> = fs_tab.iter().rev().map(|e| e.target().unwrap()).collect();
+        forward.reverse();
+        assert_eq!(backward, forward);
+
+        let actual = fs_tab
+            .iter()
+            .rev()
+            .filter(|e| e.target() != Some(Path::new("/")))
+            .map(|e| e.target().unwrap())
+            .collect::<Vec<_>>();
+        let expected = vec![Path::new("/tmp"), Path::new("/var"), Path::new("/home")];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_iterator_knows_how_many_entries_are_left() -> crate::Result<()> {
+        let mut fs_tab = FsTab::new()?;
+
+        for target in ["/", "/home", "/var"] {
+            let entry = FsTabEntry::builder()
+                .source(Pseudo::None)
+                .target(target)
+                .file_system_type(FileSystem::Tmpfs)
+                .build()?;
+
+            fs_tab.push(entry);
+        }
+
+        let mut iter = fs_tab.iter();
+
+        let actual = iter.len();
+        let expected = fs_tab.len();
+        assert_eq!(actual, expected);
+
+        iter.next();
+        let actual = iter.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        iter.next_back();
+        let actual = iter.size_hint();
+        let expected = (1, Some(1));
+        assert_eq!(actual, expected);
+
+        iter.next();
+        let actual = iter.len();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        let actual = iter.next();
+        assert!(actual.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_be_consumed_by_an_iterator() -> crate::Result<()> {
+        let mut fs_tab = FsTab::new()?;
+
+        for target in ["/", "/home", "/var"] {
+            let entry = FsTabEntry::builder()
+                .source(Pseudo::None)
+                .target(target)
+                .file_system_type(FileSystem::Tmpfs)
+                .build()?;
+
+            fs_tab.push(entry);
+        }
+
+        let iter = fs_tab.into_iter();
+        assert_eq!(iter.len(), 3);
+
+        let mut entries = vec![];
+        for entry in iter {
+            entries.push(entry);
+        }
+
+        // Entries outlive the table they were taken from.
+        let actual: Vec<_> = entries.iter().map(|e| e.target().unwrap()).collect();
+        let expected = vec![Path::new("/"), Path::new("/home"), Path::new("/var")];
+        assert_eq!(actual, expected);
+
+        entries[1].set_mount_options("ro")?;
+
+        let actual = entries[1].mount_options();
+        let expected = Some("ro");
+        assert_eq!(actual, expected);
+
+        let actual = entries[0].mount_options();
+        assert!(actual.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_be_collected_from_an_empty_iterator() -> crate::Result<()> {
+        let fs_tab: FsTab = std::iter::empty::<FsTabEntry>().collect();
+        assert!(fs_tab.is_empty());
+
+        let fs_tab = FsTab::try_from_iter(Vec::<FsTabEntry>::new())?;
+        assert!(fs_tab.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_be_collected_from_an_iterator() -> crate::Result<()> {
+        let targets = ["/", "/home", "/var"];
+        let entries = targets
+            .into_iter()
+            .map(|target| {
+                FsTabEntry::builder()
+                    .source(Pseudo::None)
+                    .target(target)
+                    .file_system_type(FileSystem::Tmpfs)
+                    .build()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut fs_tab: FsTab = entries.into_iter().collect();
+
+        let actual: Vec<_> = fs_tab.iter().map(|e| e.target().unwrap()).collect();
+        let expected = vec![Path::new("/"), Path::new("/home"), Path::new("/var")];
+        assert_eq!(actual, expected);
+
+        let entry = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target("/tmp")
+            .file_system_type(FileSystem::Tmpfs)
+            .build()?;
+        fs_tab.extend(vec![entry]);
+
+        let actual = fs_tab.len();
+        let expected = 4;
+        assert_eq!(actual, expected);
+
+        let actual = fs_tab.last().and_then(|e| e.target());
+        let expected = Some(Path::new("/tmp"));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_write_a_table_to_a_file_atomically() -> crate::Result<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        std::fs::write(&path, "LABEL=old / ext4 rw 0 1\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).unwrap();
+
+        let mut fs_tab = FsTab::new()?;
+        let entry = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target("/tmp")
+            .file_system_type(FileSystem::Tmpfs)
+            .build()?;
+        fs_tab.push(entry);
+
+        fs_tab.write_atomic(&path)?;
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("/tmp"));
+        assert!(!content.contains("LABEL=old"));
+
+        // The file keeps its original mode.
+        let actual = std::fs::metadata(&path).unwrap().permissions().mode() & 0o777;
+        let expected = 0o600;
+        assert_eq!(actual, expected);
+
+        // No temporary file is left behind.
+        let actual = std::fs::read_dir(dir.path()).unwrap().count();
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_interrupted_atomic_write_leaves_the_original_file_untouched() -> crate::Result<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        let original = "LABEL=old / ext4 rw 0 1\n";
+        std::fs::write(&path, original).unwrap();
+
+        let mut fs_tab = FsTab::new()?;
+        let entry = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target("/tmp")
+            .file_system_type(FileSystem::Tmpfs)
+            .build()?;
+        fs_tab.push(entry);
+
+        // Simulate a crash between writing the temporary file, and renaming it.
+        let tmp_path = fs_tab.write_sibling_temp_file(&path)?;
+
+        let actual = std::fs::read_to_string(&path).unwrap();
+        let expected = original;
+        assert_eq!(actual, expected);
+
+        let actual = tmp_path.parent();
+        let expected = Some(dir.path());
+        assert_eq!(actual, expected);
+
+        let content = std::fs::read_to_string(&tmp_path).unwrap();
+        assert!(content.contains("/tmp"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_back_up_a_file_before_writing_to_it() -> crate::Result<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        let original = b"# Original\nLABEL=old / ext4 rw 0 1\n";
+        std::fs::write(&path, original).unwrap();
+
+        let mut fs_tab = FsTab::new()?;
+        let entry = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target("/tmp")
+            .file_system_type(FileSystem::Tmpfs)
+            .build()?;
+        fs_tab.push(entry);
+
+        fs_tab.write_file_with_backup(&path, ".bak")?;
+
+        let actual = std::fs::read(dir.path().join("fstab.bak")).unwrap();
+        let expected = original;
+        assert_eq!(actual, expected);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("/tmp"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_skips_the_backup_of_a_missing_file() -> crate::Result<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+
+        let mut fs_tab = FsTab::new()?;
+        let entry = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target("/tmp")
+            .file_system_type(FileSystem::Tmpfs)
+            .build()?;
+        fs_tab.push(entry);
+
+        fs_tab.write_file_with_backup(&path, ".bak")?;
+
+        assert!(path.exists());
+        assert!(!dir.path().join("fstab.bak").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_refuses_to_back_up_a_file_with_an_empty_suffix() -> crate::Result<()> {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("fstab");
+        let original = b"# Original\nLABEL=old / ext4 rw 0 1\n";
+        std::fs::write(&path, original).unwrap();
+
+        let mut fs_tab = FsTab::new()?;
+        let entry = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target("/tmp")
+            .file_system_type(FileSystem::Tmpfs)
+            .build()?;
+        fs_tab.push(entry);
+
+        let result = fs_tab.write_file_with_backup(&path, "");
+        assert!(matches!(result, Err(FsTabError::Export(_))));
+
+        let actual = std::fs::read(&path).unwrap();
+        let expected = original;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_can_transfer_an_element_between_tables_to_destination_end() -> crate::Result<()> {
+        // UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f / ext4 rw,relatime 0 1
+        let uuid = Tag::from_str("UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f")?;
+        let entry1 = FsTabEntry::builder()
+            .source(uuid)
+            .target("/")
+            .file_system_type(FileSystem::Ext4)
+            // Comma-separated list of mount options.
+            .mount_options("rw,relatime")
+            // Interval, in days, between file system backups by the dump command on ext2/3/4
+            // file systems.
+            .backup_frequency(0)
+            // Order in which file systems are checked by the `fsck` command.
+            .fsck_checking_order(1)
+            .build()?;
+
+        // /dev/usbdisk /media/usb vfat noauto 0 0
+        let block_device = BlockDevice::from_str("/dev/usbdisk")?;
+        let entry2 = FsTabEntry::builder()
+            .source(block_device)
+            .target("/media/usb")
+            .file_system_type(FileSystem::VFAT)
+            .mount_options("noauto")
+            .backup_frequency(0)
+            .fsck_checking_order(0)
+            .build()?;
+
+        // tmpfs /tmp tmpfs nosuid,nodev 0 0
+        let entry3 = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target("/tmp")
+            .file_system_type(FileSystem::Tmpfs)
+            .mount_options("nosuid,nodev")
+            .backup_frequency(0)
+            .fsck_checking_order(0)
+            .build()?;
+
+        let entry1_inner = entry1.inner;
+        let entry2_inner = entry2.inner;
+        let entry3_inner = entry3.inner;
+
+        let mut source_table = FsTab::new()?;
+        source_table.push(entry1);
+
+        let mut dest_table = FsTab::new()?;
+        dest_table.push(entry2);
+        dest_table.push(entry3);
+
+        // Before transfer
+        assert_eq!(source_table.len(), 1);
+        assert_eq!(dest_table.len(), 2);
+
+        source_table.transfer(0, &mut dest_table, 2)?;
+
+        // After transfer
+        assert_eq!(source_table.is_empty(), true);
+
+        assert_eq!(dest_table.len(), 3);
+
+        let first_inner = dest_table[0].inner;
+        let second_inner = dest_table[1].inner;
+        let third_inner = dest_table[2].inner;
+
+        assert_eq!(first_inner, entry2_inner);
+        assert_eq!(second_inner, entry3_inner);
+        assert_eq!(third_inner, entry1_inner);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fs_tab_writes_to_a_file_stream() -> crate::Result<()> {
+        let mut fs_tab = FsTab::new()?;
+
+        // # /etc/fstab
+        fs_tab.set_intro_comments("# /etc/fstab\n")?;
+        // # Example from scratch
+        fs_tab.append_to_intro_comments("# Example from scratch\n")?;
+        //
+        fs_tab.append_to_intro_comments("\n")?;
+
+        // UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f / ext4 rw,relatime 0 1
+        let uuid = Tag::from_str("UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f")?;
+        let entry1 = FsTabEntry::builder()
+            .source(uuid)
+            .target("/")
+            .file_system_type(FileSystem::Ext4)
+            // Comma-separated list of mount options.
+            .mount_options("rw,relatime")
+            // Interval, in days, between file system backups by the dump command on ext2/3/4
+            // file systems.
+            .backup_frequency(0)
+            // Order in which file systems are checked by the `fsck` command.
+            .fsck_checking_order(1)
             .build()?;
 
         // /dev/usbdisk /media/usb vfat noauto 0 0
@@ -3531,6 +5535,51 @@ fn fs_tab_writes_to_a_file_stream() -> crate::Result<()> {
         Ok(())
     }
 
+    #[test]
+    fn fs_tab_round_trips_a_commented_fstab_byte_for_byte() -> crate::Result<()> {
+        let content = r#"# /etc/fstab: static file system information.
+#
+# Use 'blkid' to print the universally unique identifier for a
+# device; this may be used with UUID= as a more robust way to name devices
+# that works even if disks are added and removed. See fstab(5).
+#
+# <file system> <mount point> <type> <options> <dump> <pass>
+
+# / was on /dev/sda2 during installation
+UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f / ext4 errors=remount-ro 0 1
+# /boot/efi was on /dev/sda1 during installation
+UUID=0F0A-7A3B /boot/efi vfat umask=0077 0 1
+/swapfile none swap sw 0 0
+
+# USB stick, mounted on demand
+/dev/sdb1 /media/USB\040Stick vfat noauto,user,x-systemd.automount 0 0
+tmpfs /tmp tmpfs nosuid,nodev,size=2g 0 0
+
+# Network shares are mounted by autofs.
+# Keep this comment at the end of the file.
+"#;
+
+        let tmp_dir = tempdir().unwrap();
+        let file_path = tmp_dir.path().join("fstab");
+        std::fs::write(&file_path, content).unwrap();
+
+        let actual = FsTab::round_trip(&file_path)?;
+        let expected = content;
+        assert_eq!(actual, expected);
+
+        let mut fs_tab = FsTab::new()?;
+        fs_tab.import_with_comments();
+        fs_tab.import_file(&file_path)?;
+
+        let entry = fs_tab.find_target("/media/USB Stick").unwrap();
+
+        let actual = entry.comment();
+        let expected = Some("\n# USB stick, mounted on demand\n");
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     #[should_panic]
     fn fs_tab_does_not_write_to_read_only_file_stream() {
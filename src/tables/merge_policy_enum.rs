@@ -0,0 +1,21 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+
+/// Conflict resolution policies applied by [`FsTab::merge`](crate::tables::FsTab::merge) when
+/// both tables have an entry with the same `source`/`target` pair.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum MergePolicy {
+    /// Keep the entry already in the table, and discard the incoming one.
+    KeepExisting,
+    /// Replace the entry already in the table with the incoming one.
+    Overwrite,
+    /// Add every incoming entry, whether or not the table already has a matching one.
+    AppendAll,
+}
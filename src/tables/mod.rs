@@ -220,9 +220,12 @@
 pub use fs_tab_diff_struct::FsTabDiff;
 pub use fs_tab_struct::FsTab;
 pub(crate) use gc_item_enum::GcItem;
+pub use merge_policy_enum::MergePolicy;
 pub use mount_info_diff_struct::MountInfoDiff;
 pub use mount_info_struct::MountInfo;
+pub use mount_node_struct::MountNode;
 pub use mount_option_struct::MountOption;
+pub use parse_error_struct::ParseError;
 pub use parser_flow_enum::ParserFlow;
 pub use swaps_diff_struct::SwapsDiff;
 pub use swaps_struct::Swaps;
@@ -232,12 +235,17 @@
 pub use utab_struct::UTab;
 
 mod comparison_enum;
+#[cfg(test)]
+pub(crate) mod fixtures;
 mod fs_tab_diff_struct;
 mod fs_tab_struct;
 mod gc_item_enum;
+mod merge_policy_enum;
 mod mount_info_diff_struct;
 mod mount_info_struct;
+mod mount_node_struct;
 mod mount_option_struct;
+mod parse_error_struct;
 mod parser_flow_enum;
 mod swaps_diff_struct;
 mod swaps_struct;
@@ -7,6 +7,7 @@
 use std::mem::MaybeUninit;
 
 // From this library
+use crate::core::entries::MountInfoChange;
 use crate::core::errors::MountInfoDiffError;
 use crate::core::errors::MountInfoDiffIterError;
 use crate::core::iter::MountInfoDiffIter;
@@ -28,7 +29,7 @@ pub fn new(
         source: &'source MountInfo,
         other: &'other MountInfo,
     ) -> Result<MountInfoDiff<'source, 'other>, MountInfoDiffError> {
-        log::debug!("TableDiff::new creating a new `TableDiff` instance");
+        log::debug!("MountInfoDiff::new creating a new `MountInfoDiff` instance");
 
         let mut inner = MaybeUninit::<*mut libmount::libmnt_tabdiff>::zeroed();
         unsafe { inner.write(libmount::mnt_new_tabdiff()) };
@@ -44,7 +45,7 @@ pub fn new(
                 Err(MountInfoDiffError::Creation(err_msg))
             }
             inner => {
-                log::debug!("MountInfo::Diff::new created a new `MountInfoDiff` instance");
+                log::debug!("MountInfoDiff::new created a new `MountInfoDiff` instance");
                 let table_diff = Self {
                     inner,
                     source,
@@ -107,6 +108,17 @@ pub fn diff(&self) -> Result<usize, MountInfoDiffError> {
         }
     }
 
+    /// Returns a list of owned [`MountInfoChange`]s, one for each difference found by
+    /// [`MountInfoDiff::diff`]. Unlike the items returned by [`MountInfoDiff::iter`], the
+    /// elements of the list can outlive this `MountInfoDiff`.
+    pub fn collect_changes(&self) -> Vec<MountInfoChange> {
+        log::debug!("MountInfoDiff::collect_changes collecting table changes");
+
+        self.iter()
+            .map(|entry_diff| MountInfoChange::from_entry_diff(&entry_diff))
+            .collect()
+    }
+
     /// Returns an iterator over `MountInfoDiff` items comparing two [`MountInfo`]s.
     ///
     /// # Panics
@@ -145,3 +157,139 @@ fn drop(&mut self) {
         }
     }
 }
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use tempfile::Builder;
+
+    use crate::core::device::Pseudo;
+    use crate::core::fs::FileSystem;
+    use crate::mount::Mount;
+    use crate::mount::Unmount;
+    use crate::tables::Comparison;
+
+    fn mountinfo_snapshot() -> crate::Result<MountInfo> {
+        let mut mountinfo = MountInfo::new()?;
+        mountinfo.import_mountinfo()?;
+
+        Ok(mountinfo)
+    }
+
+    #[test]
+    fn mount_info_diff_can_detect_mounts_and_unmounts_between_snapshots() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+
+            let before_mount = mountinfo_snapshot()?;
+
+            let mut mount = Mount::builder()
+                .source(Pseudo::None)
+                .target(tmp_dir.path())
+                .file_system(FileSystem::Tmpfs)
+                .build()?;
+            mount.mount_device()?;
+
+            let after_mount = mountinfo_snapshot()?;
+
+            let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+            unmount.unmount_device()?;
+
+            let after_unmount = mountinfo_snapshot()?;
+
+            // New mount
+            let table_diff = MountInfoDiff::new(&before_mount, &after_mount)?;
+            let nb_changes = table_diff.diff()?;
+
+            let actual = nb_changes;
+            let expected = 1;
+            assert_eq!(actual, expected);
+
+            let change = table_diff.iter().next().unwrap();
+
+            let actual = change.comparisons();
+            let expected = &[Comparison::Mount];
+            assert_eq!(actual, expected);
+
+            let actual = change.source();
+            assert!(actual.is_none());
+
+            let actual = change.other().and_then(|entry| entry.target());
+            let expected = Some(tmp_dir.path());
+            assert_eq!(actual, expected);
+
+            // Unmount
+            let table_diff = MountInfoDiff::new(&after_mount, &after_unmount)?;
+            let nb_changes = table_diff.diff()?;
+
+            let actual = nb_changes;
+            let expected = 1;
+            assert_eq!(actual, expected);
+
+            let change = table_diff.iter().next().unwrap();
+
+            let actual = change.comparisons();
+            let expected = &[Comparison::UnMount];
+            assert_eq!(actual, expected);
+
+            let actual = change.source().and_then(|entry| entry.target());
+            let expected = Some(tmp_dir.path());
+            assert_eq!(actual, expected);
+
+            let actual = change.other();
+            assert!(actual.is_none());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_diff_can_collect_owned_changes() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+
+            let changes = {
+                let before_mount = mountinfo_snapshot()?;
+
+                let mut mount = Mount::builder()
+                    .source(Pseudo::None)
+                    .target(tmp_dir.path())
+                    .file_system(FileSystem::Tmpfs)
+                    .build()?;
+                mount.mount_device()?;
+
+                let after_mount = mountinfo_snapshot()?;
+
+                let table_diff = MountInfoDiff::new(&before_mount, &after_mount)?;
+                table_diff.diff()?;
+
+                table_diff.collect_changes()
+            };
+
+            let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+            unmount.unmount_device()?;
+
+            // Changes outlive the diff and the compared tables.
+            let actual = changes.len();
+            let expected = 1;
+            assert_eq!(actual, expected);
+
+            let change = changes[0].clone();
+
+            let actual = change.source();
+            assert!(actual.is_none());
+
+            let actual = change.other().and_then(|entry| entry.target());
+            let expected = Some(tmp_dir.path());
+            assert_eq!(actual, expected);
+
+            let actual = change.comparisons();
+            let expected = &[Comparison::Mount];
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+}
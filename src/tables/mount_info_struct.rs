@@ -5,6 +5,7 @@
 
 // From standard library
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fmt;
 use std::mem::MaybeUninit;
 use std::ops::Index;
@@ -21,15 +22,21 @@
 use crate::core::errors::MountInfoError;
 use crate::core::errors::MountInfoIterError;
 
+use crate::core::fs::FileSystem;
+
 use crate::core::iter::Direction;
 use crate::core::iter::GenIterator;
 use crate::core::iter::MountInfoChildIter;
+use crate::core::iter::MountInfoIntoIter;
 use crate::core::iter::MountInfoIter;
 use crate::core::iter::MountInfoOvermountIter;
 
+use crate::core::utils;
+
 use crate::owning_ref_from_ptr;
 
 use crate::tables::GcItem;
+use crate::tables::MountNode;
 use crate::tables::MountOption;
 use crate::tables::ParserFlow;
 
@@ -157,6 +164,8 @@
 pub struct MountInfo {
     pub(crate) inner: *mut libmount::libmnt_table,
     pub(crate) gc: Vec<GcItem>,
+    has_parser_error_handler: bool,
+    abort_on_syntax_error: bool,
 }
 
 impl Drop for MountInfo {
@@ -175,6 +184,22 @@ fn as_ref(&self) -> &MountInfo {
     }
 }
 
+impl IntoIterator for MountInfo {
+    type Item = MountInfoEntry;
+    type IntoIter = MountInfoIntoIter;
+
+    /// Creates a consuming iterator over the entries of this table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it fails to create a [`MountInfoIntoIter`].
+    fn into_iter(self) -> Self::IntoIter {
+        log::debug!("MountInfo::into_iter creating a new `MountInfoIntoIter`");
+
+        MountInfoIntoIter::new(self).unwrap()
+    }
+}
+
 impl Index<usize> for MountInfo {
     type Output = MountInfoEntry;
 
@@ -219,6 +244,8 @@ pub(crate) fn from_ptr(ptr: *mut libmount::libmnt_table) -> MountInfo {
         Self {
             inner: ptr,
             gc: vec![],
+            has_parser_error_handler: false,
+            abort_on_syntax_error: false,
         }
     }
 
@@ -265,6 +292,76 @@ pub fn new() -> Result<MountInfo, MountInfoError> {
 
     //---- END constructors
 
+    /// Stops importing table entries at the first line with a syntax error, making import
+    /// methods return a [`MountInfoError::Parse`] holding the line's number.
+    ///
+    /// **Note:** this setting has no effect if you set up a handler with
+    /// [`MountInfo::set_parser_error_handler`].
+    pub fn abort_on_syntax_error(&mut self) {
+        log::debug!("MountInfo::abort_on_syntax_error enabling abort on syntax error");
+
+        self.abort_on_syntax_error = true;
+    }
+
+    /// Skips lines with a syntax error when importing table entries (default).
+    pub fn skip_syntax_errors(&mut self) {
+        log::debug!("MountInfo::skip_syntax_errors disabling abort on syntax error");
+
+        self.abort_on_syntax_error = false;
+    }
+
+    #[doc(hidden)]
+    /// Runs the `parse` function on this table, then converts its outcome to a `Result`.
+    ///
+    /// Unless the user set up a parser error handler, records the line numbers of syntax errors,
+    /// and reports the line the parser stopped at as a [`MountInfoError::Parse`], quoting
+    /// the line when its file is readable.
+    fn import_with<T, F>(
+        &mut self,
+        function: &'static str,
+        source: T,
+        parse: F,
+    ) -> Result<(), MountInfoError>
+    where
+        T: AsRef<Path>,
+        F: FnOnce(*mut libmount::libmnt_table) -> libc::c_int,
+    {
+        let source = source.as_ref();
+
+        let (result, error_lines) = if self.has_parser_error_handler {
+            (parse(self.inner), vec![])
+        } else {
+            ffi_utils::parse_recording_syntax_errors(self.inner, self.abort_on_syntax_error, parse)
+        };
+
+        match (result, error_lines.last()) {
+            (0, _) => {
+                if !error_lines.is_empty() {
+                    log::debug!(
+                        "MountInfo::import_with skipped lines with a syntax error in {:?}: {:?}",
+                        source,
+                        error_lines
+                    );
+                }
+
+                Ok(())
+            }
+            (_, Some((line, file_name))) => {
+                let file_path = file_name.as_deref().unwrap_or(source);
+                let reason = match ffi_utils::read_line(file_path, *line) {
+                    Some(text) => format!("malformed entry {:?}", text.trim()),
+                    None => format!("syntax error in {:?}", file_path),
+                };
+
+                Err(MountInfoError::Parse {
+                    line: *line,
+                    reason,
+                })
+            }
+            (code, None) => Err(MountInfoError::Ffi { function, code }),
+        }
+    }
+
     //---- BEGIN getters
 
     /// Returns a reference to the [`Cache`] instance associated with this `MountInfo`.
@@ -319,6 +416,51 @@ pub fn root(&self) -> Option<&MountInfoEntry> {
         }
     }
 
+    /// Returns the tree of mount points rooted at the entry returned by [`MountInfo::root`], or an
+    /// empty `Vec` if the table has no root.
+    ///
+    /// Each [`MountNode`] holds a [`MountInfoEntry`] that is independent from this table, and the
+    /// nodes of its [children](MountInfo::iter_children).
+    pub fn tree(&self) -> Vec<MountNode> {
+        log::debug!("MountInfo::tree building tree of mount points");
+
+        #[doc(hidden)]
+        /// Recursively builds the sub-tree rooted at `entry`, skipping entries already visited to
+        /// guard against cycles in the parent/child relationships.
+        fn build(
+            table: &MountInfo,
+            entry: &MountInfoEntry,
+            depth: usize,
+            visited: &mut HashSet<*mut libmount::libmnt_fs>,
+        ) -> MountNode {
+            visited.insert(entry.inner);
+
+            let mut children = Vec::new();
+            for child in table.iter_children(entry) {
+                if !visited.contains(&child.inner) {
+                    children.push(build(table, child, depth + 1, visited));
+                }
+            }
+
+            MountNode::new(MountInfoEntry::borrow_ptr(entry.inner), children, depth)
+        }
+
+        match self.root() {
+            Some(root) => {
+                let mut visited = HashSet::new();
+                let tree = vec![build(self, root, 0, &mut visited)];
+                log::debug!("MountInfo::tree built tree of mount points");
+
+                tree
+            }
+            None => {
+                log::debug!("MountInfo::tree no root entry in table");
+
+                vec![]
+            }
+        }
+    }
+
     /// Returns a reference to the first element of the `MountInfo`, or `None` if it is empty.
     pub fn first(&self) -> Option<&MountInfoEntry> {
         log::debug!("MountInfo::first getting reference to first table entry");
@@ -367,6 +509,40 @@ pub fn last(&self) -> Option<&MountInfoEntry> {
         }
     }
 
+    /// Returns the entry mounted over `entry` on the same mount point (i.e. the file system
+    /// hiding `entry`), or `None` if `entry` is not over mounted.
+    ///
+    /// To get the whole stack of file systems sharing the same mount point, see
+    /// [`MountInfo::iter_overmounts`].
+    pub fn overmount(&self, entry: &MountInfoEntry) -> Option<&MountInfoEntry> {
+        log::debug!("MountInfo::overmount getting entry mounted over given entry");
+
+        let mut ptr = MaybeUninit::<*mut libmount::libmnt_fs>::zeroed();
+        let result =
+            unsafe { libmount::mnt_table_over_fs(self.inner, entry.inner, ptr.as_mut_ptr()) };
+
+        match result {
+            0 => {
+                log::debug!("MountInfo::overmount got entry mounted over given entry");
+
+                let ptr = unsafe { ptr.assume_init() };
+                let overmount = owning_ref_from_ptr!(self, MountInfoEntry, ptr);
+
+                Some(overmount)
+            }
+            1 => {
+                log::debug!("MountInfo::overmount found no entry mounted over given entry");
+
+                None
+            }
+            code => {
+                log::debug!("MountInfo::overmount failed to get entry mounted over given entry. libmount::mnt_table_over_fs returned error code: {:?}", code);
+
+                None
+            }
+        }
+    }
+
     /// Returns the index of a table entry.
     pub fn position(&self, entry: &MountInfoEntry) -> Option<usize> {
         log::debug!("MountInfo::position searching for an entry in the table");
@@ -377,10 +553,11 @@ pub fn position(&self, entry: &MountInfoEntry) -> Option<usize> {
             index if index > 0 => {
                 log::debug!(
                     "MountInfo::position mount table contains entry at index: {:?}",
-                    index
+                    index - 1
                 );
 
-                Some(index as usize)
+                // libmount::mnt_table_find_fs returns a 1-based index
+                Some((index - 1) as usize)
             }
             code => {
                 log::debug!( "MountInfo::position no matching entry in table: libmount::mnt_table_find_fs returned error code: {code:?}");
@@ -793,6 +970,28 @@ pub fn find_back_device(&mut self, device_number: u64) -> Option<&MountInfoEntry
         Self::lookup_device(self, Direction::Backward, device_number)
     }
 
+    /// Searches the table from **top** to **bottom**, and returns the first [`MountInfoEntry`]
+    /// with a device matching the given `major`:`minor` numbers (e.g. as reported in
+    /// `/sys/dev/block/<major>:<minor>`).
+    pub fn find_by_device_number(&mut self, major: u32, minor: u32) -> Option<&MountInfoEntry> {
+        log::debug!("MountInfo::find_by_device_number searching from top to bottom for entry matching device {}:{}", major, minor);
+
+        Self::lookup_device(self, Direction::Forward, utils::makedev(major, minor))
+    }
+
+    /// Searches the table from **bottom** to **top**, and returns the first [`MountInfoEntry`]
+    /// with a device matching the given `major`:`minor` numbers (e.g. as reported in
+    /// `/sys/dev/block/<major>:<minor>`).
+    pub fn find_back_by_device_number(
+        &mut self,
+        major: u32,
+        minor: u32,
+    ) -> Option<&MountInfoEntry> {
+        log::debug!("MountInfo::find_back_by_device_number searching from bottom to top for entry matching device {}:{}", major, minor);
+
+        Self::lookup_device(self, Direction::Backward, utils::makedev(major, minor))
+    }
+
     /// Removes the duplicate entries in this table keeping the first occurrence of an entry for
     /// which the `cmp` function returns [`Ordering::Equal`].
     ///
@@ -1216,6 +1415,243 @@ pub fn find_back_pair<T>(&mut self, source: &Source, target: T) -> Option<&Mount
         Self::lookup_pair(self, Direction::Backward, source, target)
     }
 
+    /// Returns the entry of the file system holding the file at `path`, i.e. the entry whose
+    /// `target` is the longest prefix of `path`.
+    ///
+    /// Paths are compared component by component (e.g. `/home` is a prefix of `/home/user`, but
+    /// not of `/homes`), without accessing the file system; for accurate results `path` should be
+    /// absolute, and in canonical form. When a mountpoint is overmounted, the entry of the top
+    /// most file system wins. If no entry's target is a prefix of `path`, this method falls back
+    /// on the entry mounted at `/`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use rsmount::tables::MountInfo;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let mut mount_info = MountInfo::new()?;
+    ///     mount_info.import_mountinfo()?;
+    ///
+    ///     let entry = mount_info.find_mount_point_of("/proc/self/mountinfo").unwrap();
+    ///
+    ///     assert_eq!(entry.target(), Some(Path::new("/proc")));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn find_mount_point_of<T>(&self, path: T) -> Option<MountInfoEntry>
+    where
+        T: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        log::debug!(
+            "MountInfo::find_mount_point_of searching mountpoint of path {:?}",
+            path
+        );
+
+        let root = Path::new("/");
+
+        let entry = self
+            .iter()
+            .filter(|entry| {
+                entry
+                    .target()
+                    .is_some_and(|target| path.starts_with(target))
+            })
+            .max_by_key(|entry| entry.target().map(|target| target.components().count()))
+            .or_else(|| self.iter().rev().find(|entry| entry.target() == Some(root)))?;
+
+        log::debug!(
+            "MountInfo::find_mount_point_of path {:?} is on file system mounted at {:?}",
+            path,
+            entry.target()
+        );
+
+        Some(MountInfoEntry::borrow_ptr(entry.inner))
+    }
+
+    #[doc(hidden)]
+    /// Returns the target, source, file system type, and mount options of each entry, as
+    /// displayed by `findmnt`.
+    fn rows(&self) -> Vec<[String; 4]> {
+        self.iter()
+            .map(|entry| {
+                let target = entry
+                    .target()
+                    .map(|path| path.display().to_string())
+                    .unwrap_or_default();
+                let source = entry
+                    .source_path()
+                    .map(|path| path.display().to_string())
+                    .unwrap_or_default();
+                let fs_type = entry
+                    .file_system_type()
+                    .map(|fs_type| fs_type.to_string())
+                    .unwrap_or_default();
+
+                let options = entry.merged_options();
+
+                [target, source, fs_type, options]
+            })
+            .collect()
+    }
+
+    /// Returns the target, source, file system type, and mount options of each entry in this
+    /// table, as lines of tab-separated values.
+    ///
+    /// Space, tab, new line, and backslash characters inside values are escaped in the same way
+    /// as in `fstab` (e.g. a space becomes `\040`), keeping the output easy to split.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsmount::tables::MountInfo;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let mut mount_info = MountInfo::new()?;
+    ///     mount_info.import_mountinfo()?;
+    ///
+    ///     for line in mount_info.to_tsv().lines() {
+    ///         let columns: Vec<&str> = line.split('\t').collect();
+    ///         assert_eq!(columns.len(), 4);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_tsv(&self) -> String {
+        log::debug!("MountInfo::to_tsv converting table to tab-separated values");
+
+        self.rows()
+            .into_iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| utils::fstab_encode(cell).unwrap_or_else(|| cell.to_owned()))
+                    .collect::<Vec<_>>()
+                    .join("\t")
+            })
+            .map(|line| line + "\n")
+            .collect()
+    }
+
+    #[doc(hidden)]
+    /// Returns a new table holding copies of the entries matching the `predicate`.
+    fn try_filter<P>(&self, mut predicate: P) -> Result<MountInfo, MountInfoError>
+    where
+        P: FnMut(&MountInfoEntry) -> bool,
+    {
+        let table = Self::new()?;
+
+        for entry in self.iter().filter(|entry| predicate(entry)) {
+            let entry = entry.copy().map_err(|e| {
+                let err_msg = format!("failed to copy table entry. {}", e);
+                log::debug!("MountInfo::try_filter {}", err_msg);
+
+                MountInfoError::Action(err_msg)
+            })?;
+
+            let result = unsafe { libmount::mnt_table_add_fs(table.inner, entry.inner) };
+            if result != 0 {
+                let err_msg = "failed to add entry to filtered table".to_owned();
+                log::debug!("MountInfo::try_filter {}. libmount::mnt_table_add_fs returned error code: {:?}", err_msg, result);
+
+                return Err(MountInfoError::Action(err_msg));
+            }
+        }
+
+        Ok(table)
+    }
+
+    /// Returns a new table with copies of the entries whose file system type matches the
+    /// comma-separated list of file system names in `pattern` (see
+    /// [`MountInfoEntry::has_any_fs_type`] for the pattern syntax, including `no`-prefixed
+    /// negations).
+    ///
+    /// # Panics
+    ///
+    /// Panics if memory allocation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rsmount::tables::MountInfo;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let mut mount_info = MountInfo::new()?;
+    ///     mount_info.import_mountinfo()?;
+    ///
+    ///     // Entries of all file system types except `tmpfs`.
+    ///     let filtered = mount_info.filter_by_fs_pattern("notmpfs");
+    ///
+    ///     assert!(filtered.len() <= mount_info.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn filter_by_fs_pattern<T>(&self, pattern: T) -> MountInfo
+    where
+        T: AsRef<str>,
+    {
+        let pattern = pattern.as_ref();
+        log::debug!(
+            "MountInfo::filter_by_fs_pattern keeping entries matching pattern {:?}",
+            pattern
+        );
+
+        self.try_filter(|entry| entry.has_any_fs_type(pattern))
+            .unwrap()
+    }
+
+    /// Returns a new table with copies of the entries whose file system type is one of `types`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if memory allocation fails.
+    pub fn filter_by_fs_type(&self, types: &[FileSystem]) -> MountInfo {
+        log::debug!(
+            "MountInfo::filter_by_fs_type keeping entries with file system types {:?}",
+            types
+        );
+
+        if types.is_empty() {
+            return Self::new().unwrap();
+        }
+
+        let pattern = types
+            .iter()
+            .map(|fs_type| fs_type.as_str())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.filter_by_fs_pattern(pattern)
+    }
+
+    /// Returns a new table with copies of the entries of network file systems (e.g. `nfs`,
+    /// `cifs`, etc.).
+    ///
+    /// # Panics
+    ///
+    /// Panics if memory allocation fails.
+    pub fn filter_network_fs(&self) -> MountInfo {
+        log::debug!("MountInfo::filter_network_fs keeping network file system entries");
+
+        self.try_filter(|entry| entry.is_net_fs()).unwrap()
+    }
+
+    /// Returns a new table with copies of the entries of pseudo file systems (e.g. `proc`,
+    /// `sysfs`, etc.).
+    ///
+    /// # Panics
+    ///
+    /// Panics if memory allocation fails.
+    pub fn filter_pseudo_fs(&self) -> MountInfo {
+        log::debug!("MountInfo::filter_pseudo_fs keeping pseudo file system entries");
+
+        self.try_filter(|entry| entry.is_pseudo_fs()).unwrap()
+    }
+
     //---- END getters
 
     //---- BEGIN iterators
@@ -1240,6 +1676,11 @@ pub fn try_iter(&self) -> Result<MountInfoIter, MountInfoIterError> {
 
     /// Returns an iterator over the children in the file system sub-tree of [`MountInfo`] entries.
     ///
+    /// Children are the entries whose parent ID matches the mount ID of `parent`, yielded in
+    /// ascending mount ID order. An entry listing itself as its own parent (e.g. the root of a
+    /// mount namespace) is never returned as its own child, so walking the tree recursively always
+    /// terminates.
+    ///
     /// # Panics
     ///
     /// Panics if it fails to create a [`MountInfoChildIter`] iterator.
@@ -1340,6 +1781,7 @@ pub fn set_parser_error_handler<F>(&mut self, err_handler: F) -> Result<(), Moun
                         log::debug!(
                             "MountInfo::set_parser_error_handler set up parser error handler"
                         );
+                        self.has_parser_error_handler = true;
                         // FIXME the callback function is long lived. If the function is called
                         // several times, we risk a substantial memory leak until the end of the program,
                         // since `user_data` is never released between calls.
@@ -1376,11 +1818,6 @@ pub fn set_parser_error_handler<F>(&mut self, err_handler: F) -> Result<(), Moun
     pub fn set_cache(&mut self, cache: Cache) -> Result<(), MountInfoError> {
         log::debug!("MountInfo::set_cache setting up a cache of paths and tags");
 
-        // Increment cache's reference counter to avoid a premature deallocation leading to a SIGSEV.
-        unsafe {
-            libmount::mnt_ref_cache(cache.inner);
-        }
-
         let result = unsafe { libmount::mnt_table_set_cache(self.inner, cache.inner) };
         match result {
             0 => {
@@ -1490,6 +1927,99 @@ fn filter_by<F>(table: &mut Self, flags: u32, cmp_fn: F) -> Result<(), MountInfo
         }
     }
 
+    /// Removes the given `element` from the table.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> MountInfoEntry {
+        log::debug!("MountInfo::remove removing entry from table");
+
+        let err_msg = format!("failed to find entry at index: {:?}", index);
+        let element: &MountInfoEntry = self
+            .get(index)
+            .ok_or(Err::<&MountInfoEntry, MountInfoError>(
+                MountInfoError::IndexOutOfBounds(err_msg),
+            ))
+            .unwrap();
+
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed() -> ! {
+            panic!("cannot remove table entry. Not found");
+        }
+
+        // increment reference counter to prevent mnt_table_remove from deallocating the underlying
+        // table entry
+        let borrowed = MountInfoEntry::borrow_ptr(element.inner);
+
+        let result = unsafe { libmount::mnt_table_remove_fs(self.inner, element.inner) };
+
+        match result {
+            0 => {
+                log::debug!("MountInfo::remove removed entry from table");
+
+                borrowed
+            }
+            code => {
+                let err_msg = "failed to remove entry from table".to_owned();
+                log::debug!(
+                    "MountInfo::remove {err_msg}. libmount::mnt_table_remove_fs returned error code: {code:?}"
+                );
+
+                // the element is not in the table, so we decrement its reference counter by
+                // dropping it to cancel out the increment performed by MountInfoEntry::borrow_ptr
+                drop(borrowed);
+                assert_failed()
+            }
+        }
+    }
+
+    /// Removes the entry at `index` from the table, and returns it.
+    ///
+    /// Unlike [`MountInfo::remove`], this method does not panic if `index` is out of bounds; it
+    /// returns an [`MountInfoError::IndexOutOfBounds`] instead.
+    pub fn try_remove(&mut self, index: usize) -> Result<MountInfoEntry, MountInfoError> {
+        log::debug!("MountInfo::try_remove removing entry at index: {:?}", index);
+
+        let inner = match self.get(index) {
+            Some(element) => element.inner,
+            None => {
+                let err_msg = format!("failed to find entry at index: {:?}", index);
+                log::debug!("MountInfo::try_remove {err_msg}");
+
+                return Err(MountInfoError::IndexOutOfBounds(err_msg));
+            }
+        };
+
+        // increment reference counter to prevent mnt_table_remove from deallocating the underlying
+        // table entry
+        let borrowed = MountInfoEntry::borrow_ptr(inner);
+
+        let result = unsafe { libmount::mnt_table_remove_fs(self.inner, inner) };
+
+        match result {
+            0 => {
+                log::debug!("MountInfo::try_remove removed entry from table");
+
+                Ok(borrowed)
+            }
+            code => {
+                let err_msg = format!("failed to remove entry at index: {:?}", index);
+                log::debug!(
+                    "MountInfo::try_remove {err_msg}. libmount::mnt_table_remove_fs returned error code: {code:?}"
+                );
+
+                // the element is not in the table, so we decrement its reference counter by
+                // dropping it to cancel out the increment performed by MountInfoEntry::borrow_ptr
+                drop(borrowed);
+
+                Err(MountInfoError::Action(err_msg))
+            }
+        }
+    }
+
     /// Removes the duplicate entries in this table keeping the first occurrence of an entry for
     /// which the `cmp` function returns [`Ordering::Equal`].
     ///
@@ -1517,28 +2047,76 @@ pub fn dedup_last_by<F>(&mut self, cmp: F) -> Result<(), MountInfoError>
         Self::filter_by(self, MNT_UNIQ_BACKWARD, cmp)
     }
 
+    #[doc(hidden)]
+    /// Parses the given file in the `/proc/<pid>/mountinfo` format, then appends the entries it
+    /// collected to the table.
+    pub(crate) fn import_file<T>(&mut self, file_path: T) -> Result<(), MountInfoError>
+    where
+        T: AsRef<Path>,
+    {
+        let file_path = file_path.as_ref();
+        let file_path_cstr = ffi_utils::as_ref_path_to_c_string(file_path).map_err(|e| {
+            let err_msg = format!("failed to convert path {:?} to `CString`. {}", file_path, e);
+            log::debug!("MountInfo::import_file {}", err_msg);
+
+            MountInfoError::Import(err_msg)
+        })?;
+        log::debug!(
+            "MountInfo::import_file importing table entries from file {:?}",
+            file_path
+        );
+
+        let result = self.import_with("mnt_table_parse_file", file_path, |table| unsafe {
+            libmount::mnt_table_parse_file(table, file_path_cstr.as_ptr())
+        });
+
+        match result {
+            Ok(()) => {
+                log::debug!(
+                    "MountInfo::import_file imported table entries from file {:?}",
+                    file_path
+                );
+
+                Ok(())
+            }
+            Err(e) => {
+                log::debug!(
+                    "MountInfo::import_file failed to import table entries from file {:?}. {}",
+                    file_path,
+                    e
+                );
+
+                Err(e)
+            }
+        }
+    }
+
     /// Parses the `/proc/self/mountinfo` and `/run/mount/utab` files, then appends the entries it
     /// collects to this `MountInfo`.
+    ///
+    /// **Note:** by default, lines with a syntax error are skipped. See
+    /// [`MountInfo::abort_on_syntax_error`].
     pub fn import_mountinfo(&mut self) -> Result<(), MountInfoError> {
         log::debug!("MountInfo::import_mountinfo import entries from /proc/self/mountinfo and /run/mount/utab");
 
-        unsafe {
-            match libmount::mnt_table_parse_mtab(self.inner, std::ptr::null()) {
-                0 => {
-                    log::debug!(
-                        "MountInfo::import_mountinfo imported entries from /proc/self/mountinfo and /run/mount/utab"
-                    );
+        let result = self.import_with(
+            "mnt_table_parse_mtab",
+            "/proc/self/mountinfo",
+            |table| unsafe { libmount::mnt_table_parse_mtab(table, std::ptr::null()) },
+        );
 
-                    Ok(())
-                }
-                code => {
-                    let err_msg =
-                        "failed to import entries from /proc/self/mountinfo and /run/mount/utab"
-                            .to_owned();
-                    log::debug!("MountInfo::import_mountinfo {}. libmount::mnt_table_parse_mtab returned error code: {:?}", err_msg, code);
+        match result {
+            Ok(()) => {
+                log::debug!(
+                    "MountInfo::import_mountinfo imported entries from /proc/self/mountinfo and /run/mount/utab"
+                );
 
-                    Err(MountInfoError::Import(err_msg))
-                }
+                Ok(())
+            }
+            Err(e) => {
+                log::debug!("MountInfo::import_mountinfo failed to import entries from /proc/self/mountinfo and /run/mount/utab. {}", e);
+
+                Err(e)
             }
         }
     }
@@ -1547,6 +2125,15 @@ pub fn import_mountinfo(&mut self) -> Result<(), MountInfoError> {
 
     //---- BEGIN predicates
 
+    /// Returns `true` if this `MountInfo` is set to stop importing table entries at the first line
+    /// with a syntax error.
+    pub fn is_aborting_on_syntax_error(&self) -> bool {
+        let state = self.abort_on_syntax_error;
+        log::debug!("MountInfo::is_aborting_on_syntax_error value: {:?}", state);
+
+        state
+    }
+
     /// Returns `true` if the table has length of 0.
     pub fn is_empty(&self) -> bool {
         let state = unsafe { libmount::mnt_table_is_empty(self.inner) == 1 };
@@ -1578,14 +2165,41 @@ pub fn is_mounted(&self, entry: &FsTabEntry) -> bool {
 }
 
 impl fmt::Display for MountInfo {
+    /// Renders the table in columns, like the
+    /// [`findmnt`](https://www.man7.org/linux/man-pages/man8/findmnt.8.html) command with its
+    /// `--list` option.
+    ///
+    /// Column widths are computed in characters, not in terminal cells; cells holding wide
+    /// characters might be misaligned.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut output: Vec<String> = vec![];
+        let header = ["TARGET", "SOURCE", "FSTYPE", "OPTIONS"].map(String::from);
+        let rows: Vec<[String; 4]> = std::iter::once(header).chain(self.rows()).collect();
 
-        for line in self.iter() {
-            output.push(line.to_string());
+        let mut widths = [0usize; 4];
+        for row in rows.iter() {
+            for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                *width = (*width).max(cell.chars().count());
+            }
         }
 
-        write!(f, "{}", output.join("\n"))
+        let lines: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                let (last, cells) = row.split_last().unwrap();
+                let mut line = String::new();
+
+                for (cell, width) in cells.iter().zip(widths.iter()) {
+                    line.push_str(cell);
+                    let padding = width - cell.chars().count() + 1;
+                    line.push_str(&" ".repeat(padding));
+                }
+                line.push_str(last);
+
+                line
+            })
+            .collect();
+
+        write!(f, "{}", lines.join("\n"))
     }
 }
 
@@ -1594,6 +2208,14 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 mod tests {
     use super::*;
     use pretty_assertions::{assert_eq, assert_ne};
+    use std::path::PathBuf;
+    use tempfile::Builder;
+
+    use crate::core::device::Pseudo;
+    use crate::core::fs::FileSystem;
+    use crate::mount::Mount;
+    use crate::mount::Unmount;
+    use crate::tables::fixtures;
 
     #[test]
     fn mount_info_can_import_mountinfo_file() -> crate::Result<()> {
@@ -1604,4 +2226,650 @@ fn mount_info_can_import_mountinfo_file() -> crate::Result<()> {
 
         Ok(())
     }
+
+    #[test]
+    fn mount_info_can_remove_an_element_from_the_middle_of_a_table() -> crate::Result<()> {
+        let mut mount_info = MountInfo::new()?;
+        mount_info.import_mountinfo()?;
+
+        let mut ids: Vec<_> = mount_info.iter().map(|e| e.mount_id()).collect();
+        assert!(ids.len() >= 2);
+
+        let middle = ids.len() / 2;
+        let item = mount_info.remove(middle);
+        let removed_id = ids.remove(middle);
+
+        let actual = item.mount_id();
+        let expected = removed_id;
+        assert_eq!(actual, expected);
+
+        let actual: Vec<_> = mount_info.iter().map(|e| e.mount_id()).collect();
+        let expected = ids;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_iterate_over_the_children_of_an_entry() -> crate::Result<()> {
+        let mut mount_info = MountInfo::new()?;
+        mount_info.import_mountinfo()?;
+
+        for parent in mount_info.iter() {
+            let parent_id = parent.mount_id();
+
+            let children: Vec<_> = mount_info.iter_children(parent).collect();
+
+            let actual = children.len();
+            let expected = mount_info
+                .iter()
+                .filter(|e| e.parent_id() == parent_id && e.mount_id() != parent_id)
+                .count();
+            assert_eq!(actual, expected);
+
+            for child in children {
+                let actual = child.parent_id();
+                let expected = parent_id;
+                assert_eq!(actual, expected);
+
+                assert_ne!(child.mount_id(), parent_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_yields_no_children_for_a_leaf_entry() -> crate::Result<()> {
+        let mut mount_info = MountInfo::new()?;
+        mount_info.import_mountinfo()?;
+
+        let leaf = mount_info
+            .iter()
+            .find(|e| !mount_info.iter().any(|c| c.parent_id() == e.mount_id()))
+            .unwrap();
+
+        let actual = mount_info.iter_children(leaf).next();
+        assert!(actual.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_walk_the_mount_tree_from_its_root() -> crate::Result<()> {
+        fn walk(table: &MountInfo, entry: &MountInfoEntry, depth: usize, lines: &mut Vec<String>) {
+            let target = entry.target().unwrap().display();
+            lines.push(format!("{:indent$}{}", "", target, indent = depth * 2));
+
+            for child in table.iter_children(entry) {
+                walk(table, child, depth + 1, lines);
+            }
+        }
+
+        let mut mount_info = MountInfo::new()?;
+        mount_info.import_mountinfo()?;
+
+        let root = mount_info.root().unwrap();
+        let mut lines = Vec::new();
+        walk(&mount_info, root, 0, &mut lines);
+
+        let actual = lines.first().map(String::as_str);
+        let expected = Some("/");
+        assert_eq!(actual, expected);
+
+        assert!(lines.len() <= mount_info.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_import_a_mountinfo_formatted_file() -> crate::Result<()> {
+        let mount_info = fixtures::mount_info()?;
+
+        let actual = mount_info.len();
+        let expected = 7;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn mount_info_can_serialize_an_entry() -> crate::Result<()> {
+        let mut mount_info = fixtures::mount_info()?;
+
+        // 24 21 0:5 / /dev rw,nosuid shared:4 - devtmpfs udev rw,mode=755
+        let entry = mount_info.find_target("/dev").unwrap();
+        let value = serde_json::to_value(entry).unwrap();
+
+        let actual = &value["mount_id"];
+        let expected = &serde_json::json!(24);
+        assert_eq!(actual, expected);
+
+        let actual = &value["parent_id"];
+        let expected = &serde_json::json!(21);
+        assert_eq!(actual, expected);
+
+        let actual = &value["device_id"];
+        let expected = &serde_json::json!("0:5");
+        assert_eq!(actual, expected);
+
+        let actual = &value["target"];
+        let expected = &serde_json::json!("/dev");
+        assert_eq!(actual, expected);
+
+        let actual = &value["source"];
+        let expected = &serde_json::json!("udev");
+        assert_eq!(actual, expected);
+
+        let actual = &value["file_system_type"];
+        let expected = &serde_json::json!("devtmpfs");
+        assert_eq!(actual, expected);
+
+        let actual = &value["fs_specific_options"];
+        let expected = &serde_json::json!("rw,mode=755");
+        assert_eq!(actual, expected);
+
+        let actual = &value["propagation_flags"];
+        let expected = &serde_json::json!(["shared"]);
+        assert_eq!(actual, expected);
+
+        for key in ["root", "fs_options", "fs_independent_options"] {
+            assert!(value.get(key).is_some(), "missing key: {key}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_entry_can_decode_its_device_numbers() -> crate::Result<()> {
+        let mut mount_info = fixtures::mount_info()?;
+
+        // 21 1 8:3 / / rw,relatime shared:1 - ext4 /dev/sda3 rw
+        let entry = mount_info.find_target("/").unwrap();
+
+        let actual = entry.device_numbers();
+        let expected = (8, 3);
+        assert_eq!(actual, expected);
+
+        let actual = entry.device_id_major_minor();
+        let expected = Some((8, 3));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_find_an_entry_by_device_number() -> crate::Result<()> {
+        // Bind mount of a directory on the root device.
+        let content = [
+            fixtures::MOUNTINFO,
+            "28 21 8:3 /srv /mnt rw,relatime - ext4 /dev/sda3 rw\n",
+        ]
+        .concat();
+        let mut mount_info = fixtures::mount_info_from(&content)?;
+
+        let actual = mount_info
+            .find_by_device_number(8, 3)
+            .and_then(|entry| entry.target());
+        let expected = Some(Path::new("/"));
+        assert_eq!(actual, expected);
+
+        let actual = mount_info
+            .find_back_by_device_number(8, 3)
+            .and_then(|entry| entry.target());
+        let expected = Some(Path::new("/mnt"));
+        assert_eq!(actual, expected);
+
+        let actual = mount_info
+            .find_by_device_number(0, 21)
+            .and_then(|entry| entry.target());
+        let expected = Some(Path::new("/proc"));
+        assert_eq!(actual, expected);
+
+        let actual = mount_info.find_by_device_number(8, 4);
+        assert!(actual.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_parse_the_file_system_options_of_an_entry() -> crate::Result<()> {
+        let mut mount_info = fixtures::mount_info()?;
+
+        let entry = mount_info.find_target("/dev/pts").unwrap();
+
+        let actual = entry.options();
+        let expected: Vec<MountOption> = vec!["rw".parse()?, "gid=5".parse()?, "mode=620".parse()?];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_iterator_knows_how_many_entries_are_left() -> crate::Result<()> {
+        let mount_info = fixtures::mount_info()?;
+
+        let mut iter = mount_info.iter();
+
+        let actual = iter.len();
+        let expected = mount_info.len();
+        assert_eq!(actual, expected);
+
+        iter.next();
+        let actual = iter.len();
+        let expected = mount_info.len() - 1;
+        assert_eq!(actual, expected);
+
+        let actual = iter.count();
+        let expected = mount_info.len() - 1;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_be_consumed_by_an_iterator() -> crate::Result<()> {
+        let mount_info = fixtures::mount_info()?;
+
+        let entries: Vec<MountInfoEntry> = mount_info.into_iter().collect();
+
+        let actual = entries.len();
+        let expected = 7;
+        assert_eq!(actual, expected);
+
+        let actual = entries[3].target();
+        let expected = Some(Path::new("/dev"));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_filter_entries_by_file_system_type() -> crate::Result<()> {
+        let mount_info = fixtures::mount_info()?;
+
+        let actual = mount_info.filter_by_fs_type(&[FileSystem::Ext4]).len();
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        let actual = mount_info
+            .filter_by_fs_type(&[FileSystem::Cgroup2, FileSystem::Proc])
+            .len();
+        let expected = 3;
+        assert_eq!(actual, expected);
+
+        let actual = mount_info.filter_by_fs_type(&[]).len();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        let actual = mount_info.filter_by_fs_pattern("noext4").len();
+        let expected = 6;
+        assert_eq!(actual, expected);
+
+        let actual = mount_info.filter_pseudo_fs().len();
+        let expected = 6;
+        assert_eq!(actual, expected);
+
+        let actual = mount_info.filter_network_fs().len();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        // The source table is left untouched.
+        let actual = mount_info.len();
+        let expected = 7;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_filter_network_file_systems() -> crate::Result<()> {
+        let content = [
+            fixtures::MOUNTINFO,
+            "28 21 0:40 / /mnt/nfs rw,relatime shared:8 - nfs4 server:/export rw,vers=4.2
+29 21 0:41 / /mnt/cifs rw,relatime shared:9 - cifs //server/share rw,vers=3.0
+30 21 0:42 / /mnt/tmp rw,relatime shared:10 - tmpfs none rw
+",
+        ]
+        .concat();
+        let mount_info = fixtures::mount_info_from(&content)?;
+
+        let network_fs = mount_info.filter_network_fs();
+
+        let actual: Vec<_> = network_fs.iter().map(|entry| entry.target()).collect();
+        let expected = vec![Some(Path::new("/mnt/nfs")), Some(Path::new("/mnt/cifs"))];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_find_the_deepest_mount_point_of_a_path() -> crate::Result<()> {
+        let mount_info = fixtures::mount_info()?;
+
+        let target_of = |path: &str| {
+            mount_info
+                .find_mount_point_of(path)
+                .and_then(|entry| entry.target().map(Path::to_path_buf))
+        };
+
+        let actual = target_of("/sys/fs/cgroup/unified/cgroup.procs");
+        let expected = Some(PathBuf::from("/sys/fs/cgroup/unified"));
+        assert_eq!(actual, expected);
+
+        let actual = target_of("/sys/fs/cgroup/cgroup.procs");
+        let expected = Some(PathBuf::from("/sys/fs/cgroup"));
+        assert_eq!(actual, expected);
+
+        let actual = target_of("/sys/kernel");
+        let expected = Some(PathBuf::from("/sys"));
+        assert_eq!(actual, expected);
+
+        let actual = target_of("/dev/pts");
+        let expected = Some(PathBuf::from("/dev/pts"));
+        assert_eq!(actual, expected);
+
+        let actual = target_of("/devices");
+        let expected = Some(PathBuf::from("/"));
+        assert_eq!(actual, expected);
+
+        // Falls back on the root file system.
+        let actual = target_of("relative/path");
+        let expected = Some(PathBuf::from("/"));
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_display_a_table_like_findmnt() -> crate::Result<()> {
+        let mount_info = fixtures::mount_info()?;
+
+        let output = mount_info.to_string();
+        let lines: Vec<&str> = output.lines().collect();
+
+        let actual = lines.len();
+        let expected = 8;
+        assert_eq!(actual, expected);
+
+        let actual: Vec<&str> = lines[0].split_whitespace().collect();
+        let expected = vec!["TARGET", "SOURCE", "FSTYPE", "OPTIONS"];
+        assert_eq!(actual, expected);
+
+        let actual: Vec<&str> = lines[5].split_whitespace().collect();
+        let expected = vec![
+            "/dev/pts",
+            "devpts",
+            "devpts",
+            "rw,nosuid,noexec,relatime,gid=5,mode=620",
+        ];
+        assert_eq!(actual, expected);
+
+        // Columns are aligned.
+        let actual = lines[5].find("devpts");
+        let expected = lines[0].find("SOURCE");
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_convert_a_table_to_tab_separated_values() -> crate::Result<()> {
+        let mount_info = fixtures::mount_info()?;
+
+        let output = mount_info.to_tsv();
+        let lines: Vec<&str> = output.lines().collect();
+
+        let actual = lines.len();
+        let expected = 7;
+        assert_eq!(actual, expected);
+
+        let actual = lines[0];
+        let expected = "/\t/dev/sda3\text4\trw,relatime";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    static MALFORMED_MOUNTINFO: &str = r#"21 1 8:3 / / rw,relatime shared:1 - ext4 /dev/sda3 rw
+22 21 0:20 / /sys
+26 21 8:4 / /home rw,relatime shared:6 - ext4 /dev/sda4 rw
+"#;
+
+    #[test]
+    fn mount_info_import_file_skips_lines_with_a_syntax_error_by_default() -> crate::Result<()> {
+        let file = fixtures::temp_file(MALFORMED_MOUNTINFO);
+
+        let mut mount_info = MountInfo::new()?;
+        assert!(!mount_info.is_aborting_on_syntax_error());
+
+        mount_info.import_file(file.path())?;
+
+        let actual = mount_info.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_import_file_reports_the_line_of_a_syntax_error() -> crate::Result<()> {
+        let file = fixtures::temp_file(MALFORMED_MOUNTINFO);
+
+        let mut mount_info = MountInfo::new()?;
+        mount_info.abort_on_syntax_error();
+        let err = mount_info.import_file(file.path()).unwrap_err();
+
+        assert!(matches!(err, MountInfoError::Parse { line: 2, .. }));
+
+        let err = crate::RsMountError::from(err);
+        assert!(matches!(err, crate::RsMountError::Parse { line: 2, .. }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_entry_can_decode_propagation_types() -> crate::Result<()> {
+        use crate::core::flags::PropagationMode;
+
+        let content = r#"21 1 8:3 / / rw,relatime shared:1 - ext4 /dev/sda3 rw
+30 21 0:30 / /mnt/shared rw,relatime shared:12 - tmpfs none rw
+31 21 0:31 / /mnt/slave rw,relatime master:12 - tmpfs none rw
+32 21 0:32 / /mnt/slave-shared rw,relatime shared:14 master:12 - tmpfs none rw
+33 21 0:33 / /mnt/private rw,relatime - tmpfs none rw
+34 21 0:34 / /mnt/unbindable rw,relatime unbindable - tmpfs none rw
+"#;
+        let mut mount_info = fixtures::mount_info_from(content)?;
+
+        let cases = [
+            ("/mnt/shared", vec![PropagationMode::Shared], Some(12), None),
+            ("/mnt/slave", vec![PropagationMode::Slave], None, Some(12)),
+            (
+                "/mnt/slave-shared",
+                vec![PropagationMode::Shared, PropagationMode::Slave],
+                Some(14),
+                Some(12),
+            ),
+            ("/mnt/private", vec![PropagationMode::Private], None, None),
+            (
+                "/mnt/unbindable",
+                vec![PropagationMode::Unbindable],
+                None,
+                None,
+            ),
+        ];
+
+        for (target, modes, peer_group, master_peer_group) in cases {
+            let entry = mount_info.find_target(target).unwrap();
+
+            let actual = entry.propagation();
+            let expected = HashSet::from_iter(modes);
+            assert_eq!(actual, expected, "target: {}", target);
+
+            let actual = entry.peer_group();
+            let expected = peer_group;
+            assert_eq!(actual, expected, "target: {}", target);
+
+            let actual = entry.master_peer_group();
+            let expected = master_peer_group;
+            assert_eq!(actual, expected, "target: {}", target);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_build_a_tree_of_mount_points() -> crate::Result<()> {
+        let mount_info = fixtures::mount_info()?;
+
+        let tree = mount_info.tree();
+
+        let actual = tree.len();
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        let root = &tree[0];
+
+        let actual = root.entry().target();
+        let expected = Some(Path::new("/"));
+        assert_eq!(actual, expected);
+
+        let actual = root.depth();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        let actual: Vec<_> = root
+            .children()
+            .iter()
+            .map(|node| node.entry().target().unwrap())
+            .collect();
+        let expected = vec![Path::new("/sys"), Path::new("/proc"), Path::new("/dev")];
+        assert_eq!(actual, expected);
+
+        let actual = root.descendants().count();
+        let expected = 7;
+        assert_eq!(actual, expected);
+
+        let nested = root
+            .descendants()
+            .find(|node| node.entry().target() == Some(Path::new("/sys/fs/cgroup/unified")))
+            .unwrap();
+
+        let actual = nested.depth();
+        let expected = 3;
+        assert_eq!(actual, expected);
+
+        assert!(nested.children().is_empty());
+
+        // Nodes outlive the table they were built from.
+        drop(mount_info);
+
+        let actual = nested.entry().mount_id();
+        let expected = Some(27);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_builds_an_empty_tree_from_an_empty_table() -> crate::Result<()> {
+        let mount_info = MountInfo::new()?;
+
+        let tree = mount_info.tree();
+        assert!(tree.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_find_overmounted_entries() -> crate::Result<()> {
+        let content = [
+            fixtures::MOUNTINFO,
+            "28 21 0:30 / /mnt rw,relatime shared:8 - tmpfs none rw
+29 28 0:31 / /mnt rw,relatime shared:9 - tmpfs none rw
+30 29 0:32 / /mnt rw,relatime shared:10 - tmpfs none rw
+",
+        ]
+        .concat();
+        let mount_info = fixtures::mount_info_from(&content)?;
+
+        let bottom = mount_info
+            .iter()
+            .find(|e| e.mount_id() == Some(28))
+            .unwrap();
+
+        let actual = mount_info.overmount(bottom).and_then(|e| e.mount_id());
+        let expected = Some(29);
+        assert_eq!(actual, expected);
+
+        let actual: Vec<_> = mount_info
+            .iter_overmounts(bottom)
+            .map(|e| e.mount_id())
+            .collect();
+        let expected = vec![Some(29), Some(30)];
+        assert_eq!(actual, expected);
+
+        let top = mount_info
+            .iter()
+            .find(|e| e.mount_id() == Some(30))
+            .unwrap();
+        let actual = mount_info.overmount(top);
+        assert!(actual.is_none());
+
+        let root = mount_info.root().unwrap();
+        let actual = mount_info.overmount(root);
+        assert!(actual.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_detect_a_tmpfs_mounted_over_another() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+
+            for _ in 0..2 {
+                let mut mount = Mount::builder()
+                    .source(Pseudo::None)
+                    .target(tmp_dir.path())
+                    .file_system(FileSystem::Tmpfs)
+                    .build()?;
+                mount.mount_device()?;
+            }
+
+            let mut mount_info = MountInfo::new()?;
+            mount_info.import_mountinfo()?;
+
+            let bottom = mount_info.find_target(tmp_dir.path()).unwrap().inner;
+            let bottom = MountInfoEntry::borrow_ptr(bottom);
+
+            let top = mount_info.overmount(&bottom).unwrap();
+
+            let actual = top.target();
+            let expected = Some(tmp_dir.path());
+            assert_eq!(actual, expected);
+
+            let actual = top.parent_id();
+            let expected = bottom.mount_id();
+            assert_eq!(actual, expected);
+
+            for _ in 0..2 {
+                let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+                unmount.unmount_device()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_info_can_not_remove_an_element_out_of_bounds() -> crate::Result<()> {
+        let mut mount_info = MountInfo::new()?;
+
+        let actual = mount_info.try_remove(0);
+        assert!(matches!(actual, Err(MountInfoError::IndexOutOfBounds(_))));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,59 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+
+// From this library
+use crate::core::entries::MountInfoEntry;
+
+/// A node in the tree of mount points built by [`MountInfo::tree`](crate::tables::MountInfo::tree).
+///
+/// Each node owns a [`MountInfoEntry`], and the nodes of the file systems mounted under it.
+#[derive(Debug)]
+pub struct MountNode {
+    entry: MountInfoEntry,
+    children: Vec<MountNode>,
+    depth: usize,
+}
+
+impl MountNode {
+    #[doc(hidden)]
+    /// Creates a new `MountNode`.
+    pub(crate) fn new(entry: MountInfoEntry, children: Vec<MountNode>, depth: usize) -> MountNode {
+        Self {
+            entry,
+            children,
+            depth,
+        }
+    }
+
+    /// Returns the [`MountInfoEntry`] held by this node.
+    pub fn entry(&self) -> &MountInfoEntry {
+        &self.entry
+    }
+
+    /// Returns the nodes of the file systems directly mounted under this node.
+    pub fn children(&self) -> &[MountNode] {
+        &self.children
+    }
+
+    /// Returns the distance between this node and the root of the tree (the root node has a depth
+    /// of `0`).
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns an iterator over this node, and all its descendants in depth-first order.
+    pub fn descendants(&self) -> impl Iterator<Item = &MountNode> {
+        let mut stack = vec![self];
+
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+
+            Some(node)
+        })
+    }
+}
@@ -32,18 +32,55 @@
 ///     Ok(())
 /// }
 /// ```
-#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct MountOption {
     name: String,
     value: Option<String>,
 }
 
 impl MountOption {
+    /// Creates a new `MountOption` named `name`, with an optional `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pretty_assertions::assert_eq;
+    /// use rsmount::tables::MountOption;
+    ///
+    /// fn main() -> rsmount::Result<()> {
+    ///     let option = MountOption::new("context", Some("system_u:object_r:tmp_t:s0:c127,c456"));
+    ///     assert_eq!(
+    ///         option.to_string(),
+    ///         r#"context="system_u:object_r:tmp_t:s0:c127,c456""#
+    ///     );
+    ///
+    ///     let option = MountOption::new("noatime", None::<String>);
+    ///     assert_eq!(option.to_string(), "noatime");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new<N, V>(name: N, value: Option<V>) -> MountOption
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        let name = name.into();
+        let value = value.map(Into::into);
+        log::debug!(
+            "MountOption::new creating a new `MountOption` instance with name: {:?} and value: {:?}",
+            name,
+            value
+        );
+
+        Self { name, value }
+    }
+
     #[doc(hidden)]
-    /// Creates a new `MountOption`.
-    pub(crate) fn new(name: &str) -> MountOption {
+    /// Creates a new `MountOption` without a value.
+    pub(crate) fn without_value(name: &str) -> MountOption {
         log::debug!(
-            "MountOption::new creating a new `MountOption` instance with name: {:?} and no value",
+            "MountOption::without_value creating a new `MountOption` instance with name: {:?} and no value",
             name,
         );
 
@@ -56,7 +93,7 @@ pub(crate) fn new(name: &str) -> MountOption {
     /// Creates a new `MountOption`.
     pub(crate) fn new_with_value(name: &str, value: &str) -> MountOption {
         log::debug!(
-            "MountOption::new creating a new `MountOption` instance with name: {:?} and value: {:?}",
+            "MountOption::new_with_value creating a new `MountOption` instance with name: {:?} and value: {:?}",
             name,
             value
         );
@@ -126,7 +163,7 @@ fn from_str(s: &str) -> Result<Self, Self::Err> {
             }
             // Mount option without value
             None => {
-                let option = Self::new(s);
+                let option = Self::without_value(s);
 
                 Ok(option)
             }
@@ -173,6 +210,66 @@ fn mount_option_can_not_parse_a_mount_option_string_with_an_unclosed_quote() {
         let _: MountOption = "ro='recursive".parse().unwrap();
     }
 
+    #[test]
+    fn mount_option_can_create_an_option_without_a_value() {
+        let option = MountOption::new("noatime", None::<&str>);
+
+        let actual = option.name();
+        let expected = "noatime";
+        assert_eq!(actual, expected);
+
+        let actual = option.value();
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        let actual = option.to_string();
+        let expected = "noatime";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn mount_option_can_create_an_option_with_an_empty_value() {
+        let option = MountOption::new("user", Some(""));
+
+        let actual = option.to_string();
+        let expected = "user=";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn mount_option_does_not_quote_plain_values() {
+        let option = MountOption::new("mode", Some("1777"));
+
+        let actual = option.to_string();
+        let expected = "mode=1777";
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn mount_option_quotes_values_with_commas() -> crate::Result<()> {
+        let option = MountOption::new("context", Some("system_u:object_r:tmp_t:s0:c127,c456"));
+
+        let actual = option.to_string();
+        let expected = r#"context="system_u:object_r:tmp_t:s0:c127,c456""#;
+        assert_eq!(actual, expected);
+
+        // Round trip
+        let actual: MountOption = option.to_string().parse()?;
+        let expected = option;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mount_option_quotes_values_with_whitespace() {
+        let option = MountOption::new("label", Some("my disk"));
+
+        let actual = option.to_string();
+        let expected = r#"label="my disk""#;
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn mount_option_can_parse_a_mount_option() -> crate::Result<()> {
         let option = "ro";
@@ -0,0 +1,42 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// From dependency library
+
+// From standard library
+use std::fmt;
+
+// From this library
+
+/// A defective line skipped while importing a file system description file.
+///
+/// Returned by [`FsTab::import_file_collecting_errors`](crate::tables::FsTab::import_file_collecting_errors).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    line: usize,
+    text: String,
+}
+
+impl ParseError {
+    #[doc(hidden)]
+    /// Creates a new `ParseError`.
+    pub(crate) fn new(line: usize, text: String) -> ParseError {
+        Self { line, text }
+    }
+
+    /// Returns the number of the defective line (starting at `1`).
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the content of the defective line.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: syntax error: {:?}", self.line, self.text)
+    }
+}
@@ -5,9 +5,14 @@
 
 // From standard library
 use std::cmp::Ordering;
+use std::ffi::CString;
 use std::fmt;
+use std::fs::File;
+use std::io;
+use std::io::{Read, Write};
 use std::mem::MaybeUninit;
 use std::ops::Index;
+use std::os::fd::FromRawFd;
 use std::path::Path;
 
 // From this library
@@ -20,6 +25,7 @@
 
 use crate::core::iter::Direction;
 use crate::core::iter::GenIterator;
+use crate::core::iter::SwapsIntoIter;
 use crate::core::iter::SwapsIter;
 
 use crate::owning_ref_from_ptr;
@@ -49,6 +55,8 @@
 pub struct Swaps {
     pub(crate) inner: *mut libmount::libmnt_table,
     pub(crate) gc: Vec<GcItem>,
+    has_parser_error_handler: bool,
+    abort_on_syntax_error: bool,
 }
 
 impl Swaps {
@@ -76,6 +84,22 @@ fn as_ref(&self) -> &Swaps {
     }
 }
 
+impl IntoIterator for Swaps {
+    type Item = SwapsEntry;
+    type IntoIter = SwapsIntoIter;
+
+    /// Creates a consuming iterator over the entries of this table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it fails to create a [`SwapsIntoIter`].
+    fn into_iter(self) -> Self::IntoIter {
+        log::debug!("Swaps::into_iter creating a new `SwapsIntoIter`");
+
+        SwapsIntoIter::new(self).unwrap()
+    }
+}
+
 impl Index<usize> for Swaps {
     type Output = SwapsEntry;
 
@@ -120,6 +144,8 @@ pub(crate) fn from_ptr(ptr: *mut libmount::libmnt_table) -> Swaps {
         Self {
             inner: ptr,
             gc: vec![],
+            has_parser_error_handler: false,
+            abort_on_syntax_error: false,
         }
     }
 
@@ -166,29 +192,212 @@ pub fn new() -> Result<Swaps, SwapsError> {
 
     //---- END constructors
 
+    /// Stops importing table entries at the first line with a syntax error, making import
+    /// methods return a [`SwapsError::Parse`] holding the line's number.
+    ///
+    /// **Note:** this setting has no effect if you set up a handler with
+    /// [`Swaps::set_parser_error_handler`].
+    pub fn abort_on_syntax_error(&mut self) {
+        log::debug!("Swaps::abort_on_syntax_error enabling abort on syntax error");
+
+        self.abort_on_syntax_error = true;
+    }
+
+    /// Skips lines with a syntax error when importing table entries (default).
+    pub fn skip_syntax_errors(&mut self) {
+        log::debug!("Swaps::skip_syntax_errors disabling abort on syntax error");
+
+        self.abort_on_syntax_error = false;
+    }
+
+    #[doc(hidden)]
+    /// Runs the `parse` function on this table, then converts its outcome to a `Result`.
+    ///
+    /// Unless the user set up a parser error handler, records the line numbers of syntax errors,
+    /// and reports the line the parser stopped at as a [`SwapsError::Parse`], quoting
+    /// the line when its file is readable.
+    fn import_with<T, F>(
+        &mut self,
+        function: &'static str,
+        source: T,
+        parse: F,
+    ) -> Result<(), SwapsError>
+    where
+        T: AsRef<Path>,
+        F: FnOnce(*mut libmount::libmnt_table) -> libc::c_int,
+    {
+        let source = source.as_ref();
+
+        let (result, error_lines) = if self.has_parser_error_handler {
+            (parse(self.inner), vec![])
+        } else {
+            ffi_utils::parse_recording_syntax_errors(self.inner, self.abort_on_syntax_error, parse)
+        };
+
+        match (result, error_lines.last()) {
+            (0, _) => {
+                if !error_lines.is_empty() {
+                    log::debug!(
+                        "Swaps::import_with skipped lines with a syntax error in {:?}: {:?}",
+                        source,
+                        error_lines
+                    );
+                }
+
+                Ok(())
+            }
+            (_, Some((line, file_name))) => {
+                let file_path = file_name.as_deref().unwrap_or(source);
+                let reason = match ffi_utils::read_line(file_path, *line) {
+                    Some(text) => format!("malformed entry {:?}", text.trim()),
+                    None => format!("syntax error in {:?}", file_path),
+                };
+
+                Err(SwapsError::Parse {
+                    line: *line,
+                    reason,
+                })
+            }
+            (code, None) => Err(SwapsError::Ffi { function, code }),
+        }
+    }
+
     /// Parses `/proc/swaps`, then appends the data it collected to the table.
+    ///
+    /// **Note:** by default, lines with a syntax error are skipped. See
+    /// [`Swaps::abort_on_syntax_error`].
     pub fn import_proc_swaps(&mut self) -> Result<(), SwapsError> {
         log::debug!("Swaps::import_proc_swaps importing entries from /proc/swaps");
 
-        let result = unsafe { libmount::mnt_table_parse_swaps(self.inner, std::ptr::null()) };
+        let result = self.import_with("mnt_table_parse_swaps", "/proc/swaps", |table| unsafe {
+            libmount::mnt_table_parse_swaps(table, std::ptr::null())
+        });
 
         match result {
-            0 => {
+            Ok(()) => {
                 log::debug!("Swaps::import_proc_swaps imported entries from /proc/swaps");
 
                 Ok(())
             }
-            code => {
-                let err_msg = "failed to import entries from /proc/swaps".to_owned();
-                log::debug!("Swaps::import_proc_swaps {}. libmount::mnt_table_parse_swaps returned error code: {:?}", err_msg, code);
+            Err(e) => {
+                log::debug!(
+                    "Swaps::import_proc_swaps failed to import entries from /proc/swaps. {}",
+                    e
+                );
 
-                Err(SwapsError::Import(err_msg))
+                Err(e)
             }
         }
     }
 
+    #[doc(hidden)]
+    /// Parses a file in the same format as `/proc/swaps`, then appends the data it collected to
+    /// the table.
+    pub(crate) fn import_file<T>(&mut self, file_path: T) -> Result<(), SwapsError>
+    where
+        T: AsRef<Path>,
+    {
+        let file_path = file_path.as_ref();
+        let file_path_cstr = ffi_utils::as_ref_path_to_c_string(file_path).map_err(|e| {
+            let err_msg = format!("failed to convert path {:?} to `CString`. {}", file_path, e);
+            log::debug!("Swaps::import_file {}", err_msg);
+
+            SwapsError::Import(err_msg)
+        })?;
+        log::debug!(
+            "Swaps::import_file importing entries from file {:?}",
+            file_path
+        );
+
+        let result = self.import_with("mnt_table_parse_swaps", file_path, |table| unsafe {
+            libmount::mnt_table_parse_swaps(table, file_path_cstr.as_ptr())
+        });
+
+        match result {
+            Ok(()) => {
+                log::debug!(
+                    "Swaps::import_file imported entries from file {:?}",
+                    file_path
+                );
+
+                Ok(())
+            }
+            Err(e) => {
+                log::debug!(
+                    "Swaps::import_file failed to import entries from file {:?}. {}",
+                    file_path,
+                    e
+                );
+
+                Err(e)
+            }
+        }
+    }
+
+    /// Parses swaps-formatted data (e.g. a copy of `/proc/swaps`) from the given `reader`, then
+    /// appends the entries it collected to the table.
+    ///
+    /// By default, malformed lines are skipped. See [`Swaps::abort_on_syntax_error`], and
+    /// [`Swaps::set_parser_error_handler`].
+    pub fn import_from_stream<R>(&mut self, mut reader: R) -> Result<(), SwapsError>
+    where
+        R: Read,
+    {
+        log::debug!("Swaps::import_from_stream importing entries from stream");
+
+        let mut content = Vec::new();
+        reader.read_to_end(&mut content).map_err(|e| {
+            let err_msg = format!("failed to read stream. {e}");
+            log::debug!("Swaps::import_from_stream {err_msg}");
+
+            SwapsError::Import(err_msg)
+        })?;
+
+        // `libmount` only parses swaps-formatted data from a named file, so we copy the stream's
+        // content to an anonymous in-memory file accessible through `/proc/self/fd`.
+        let name = CString::new("rsmount-swaps").unwrap();
+        let fd = unsafe { libc::memfd_create(name.as_ptr(), libc::MFD_CLOEXEC) };
+
+        if fd < 0 {
+            let err_msg = format!(
+                "failed to create in-memory file. {}",
+                io::Error::last_os_error()
+            );
+            log::debug!("Swaps::import_from_stream {err_msg}. libc::memfd_create returned error code: {fd:?}");
+
+            return Err(SwapsError::Import(err_msg));
+        }
+
+        // The file is closed when it goes out of scope.
+        let mut file = unsafe { File::from_raw_fd(fd) };
+        file.write_all(&content).map_err(|e| {
+            let err_msg = format!("failed to copy stream to in-memory file. {e}");
+            log::debug!("Swaps::import_from_stream {err_msg}");
+
+            SwapsError::Import(err_msg)
+        })?;
+
+        self.import_file(format!("/proc/self/fd/{fd}"))
+    }
+
     //---- BEGIN getters
 
+    /// Returns the total size of the swap spaces in this table (in kibibytes).
+    pub fn total_size(&self) -> u64 {
+        let total = self.iter().map(|entry| entry.size() as u64).sum();
+        log::debug!("Swaps::total_size value: {:?}", total);
+
+        total
+    }
+
+    /// Returns the total size of the swap space used in this table (in kibibytes).
+    pub fn total_used(&self) -> u64 {
+        let total = self.iter().map(|entry| entry.size_used() as u64).sum();
+        log::debug!("Swaps::total_used value: {:?}", total);
+
+        total
+    }
+
     /// Returns a reference to the [`Cache`] instance associated with this `Swaps`.
     pub fn cache(&self) -> Option<&Cache> {
         log::debug!("Swaps::cache getting associated path and tag cache");
@@ -270,10 +479,11 @@ pub fn position(&self, entry: &SwapsEntry) -> Option<usize> {
             index if index > 0 => {
                 log::debug!(
                     "Swaps::position mount table contains entry at index: {:?}",
-                    index
+                    index - 1
                 );
 
-                Some(index as usize)
+                // libmount::mnt_table_find_fs returns a 1-based index
+                Some((index - 1) as usize)
             }
             code => {
                 log::debug!( "Swaps::position no matching entry in table: libmount::mnt_table_find_fs returned error code: {code:?}");
@@ -697,6 +907,7 @@ pub fn set_parser_error_handler<F>(&mut self, err_handler: F) -> Result<(), Swap
                 match result {
                     0 => {
                         log::debug!("Swaps::set_parser_error_handler set up parser error handler");
+                        self.has_parser_error_handler = true;
                         // FIXME the callback function is long lived. If the function is called
                         // several times, we risk a substantial memory leak until the end of the program,
                         // since `user_data` is never released between calls.
@@ -733,11 +944,6 @@ pub fn set_parser_error_handler<F>(&mut self, err_handler: F) -> Result<(), Swap
     pub fn set_cache(&mut self, cache: Cache) -> Result<(), SwapsError> {
         log::debug!("Swaps::set_cache setting up a cache of paths and tags");
 
-        // Increment cache's reference counter to avoid a premature deallocation leading to a SIGSEV.
-        unsafe {
-            libmount::mnt_ref_cache(cache.inner);
-        }
-
         let result = unsafe { libmount::mnt_table_set_cache(self.inner, cache.inner) };
         match result {
             0 => {
@@ -840,6 +1046,99 @@ fn filter_by<F>(table: &mut Self, flags: u32, cmp_fn: F) -> Result<(), SwapsErro
         }
     }
 
+    /// Removes the given `element` from the table.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> SwapsEntry {
+        log::debug!("Swaps::remove removing entry from table");
+
+        let err_msg = format!("failed to find entry at index: {:?}", index);
+        let element: &SwapsEntry = self
+            .get(index)
+            .ok_or(Err::<&SwapsEntry, SwapsError>(
+                SwapsError::IndexOutOfBounds(err_msg),
+            ))
+            .unwrap();
+
+        #[cold]
+        #[inline(never)]
+        #[track_caller]
+        fn assert_failed() -> ! {
+            panic!("cannot remove table entry. Not found");
+        }
+
+        // increment reference counter to prevent mnt_table_remove from deallocating the underlying
+        // table entry
+        let borrowed = SwapsEntry::borrow_ptr(element.inner);
+
+        let result = unsafe { libmount::mnt_table_remove_fs(self.inner, element.inner) };
+
+        match result {
+            0 => {
+                log::debug!("Swaps::remove removed entry from table");
+
+                borrowed
+            }
+            code => {
+                let err_msg = "failed to remove entry from table".to_owned();
+                log::debug!(
+                    "Swaps::remove {err_msg}. libmount::mnt_table_remove_fs returned error code: {code:?}"
+                );
+
+                // the element is not in the table, so we decrement its reference counter by
+                // dropping it to cancel out the increment performed by SwapsEntry::borrow_ptr
+                drop(borrowed);
+                assert_failed()
+            }
+        }
+    }
+
+    /// Removes the entry at `index` from the table, and returns it.
+    ///
+    /// Unlike [`Swaps::remove`], this method does not panic if `index` is out of bounds; it
+    /// returns an [`SwapsError::IndexOutOfBounds`] instead.
+    pub fn try_remove(&mut self, index: usize) -> Result<SwapsEntry, SwapsError> {
+        log::debug!("Swaps::try_remove removing entry at index: {:?}", index);
+
+        let inner = match self.get(index) {
+            Some(element) => element.inner,
+            None => {
+                let err_msg = format!("failed to find entry at index: {:?}", index);
+                log::debug!("Swaps::try_remove {err_msg}");
+
+                return Err(SwapsError::IndexOutOfBounds(err_msg));
+            }
+        };
+
+        // increment reference counter to prevent mnt_table_remove from deallocating the underlying
+        // table entry
+        let borrowed = SwapsEntry::borrow_ptr(inner);
+
+        let result = unsafe { libmount::mnt_table_remove_fs(self.inner, inner) };
+
+        match result {
+            0 => {
+                log::debug!("Swaps::try_remove removed entry from table");
+
+                Ok(borrowed)
+            }
+            code => {
+                let err_msg = format!("failed to remove entry at index: {:?}", index);
+                log::debug!(
+                    "Swaps::try_remove {err_msg}. libmount::mnt_table_remove_fs returned error code: {code:?}"
+                );
+
+                // the element is not in the table, so we decrement its reference counter by
+                // dropping it to cancel out the increment performed by SwapsEntry::borrow_ptr
+                drop(borrowed);
+
+                Err(SwapsError::Action(err_msg))
+            }
+        }
+    }
+
     /// Removes the duplicate entries in this table keeping the first occurrence of an
     /// entry for which the `cmp` function returns [`Ordering::Equal`].
     ///
@@ -871,6 +1170,15 @@ pub fn dedup_last_by<F>(&mut self, cmp: F) -> Result<(), SwapsError>
 
     //---- BEGIN predicates
 
+    /// Returns `true` if this `Swaps` is set to stop importing table entries at the first line
+    /// with a syntax error.
+    pub fn is_aborting_on_syntax_error(&self) -> bool {
+        let state = self.abort_on_syntax_error;
+        log::debug!("Swaps::is_aborting_on_syntax_error value: {:?}", state);
+
+        state
+    }
+
     /// Returns `true` if the table has length of 0.
     pub fn is_empty(&self) -> bool {
         let state = unsafe { libmount::mnt_table_is_empty(self.inner) == 1 };
@@ -899,6 +1207,11 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 mod tests {
     use super::*;
     use pretty_assertions::{assert_eq, assert_ne};
+    use std::io::Cursor;
+    use std::io::Write;
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+    use std::sync::Arc;
+    use tempfile::NamedTempFile;
 
     #[test]
     fn swaps_can_import_proc_swaps() -> crate::Result<()> {
@@ -908,4 +1221,200 @@ fn swaps_can_import_proc_swaps() -> crate::Result<()> {
 
         Ok(())
     }
+
+    static SWAPS: &str = "Filename\t\t\t\tType\t\tSize\t\tUsed\t\tPriority
+/dev/sda2                               partition\t2097148\t\t524288\t\t-2
+/swapfile                               file\t\t1048572\t\t0\t\t-3
+";
+
+    #[test]
+    fn swaps_can_compute_totals_of_an_empty_table() -> crate::Result<()> {
+        let swaps = Swaps::new()?;
+
+        let actual = swaps.total_size();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        let actual = swaps.total_used();
+        let expected = 0;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_can_compute_totals() -> crate::Result<()> {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(SWAPS.as_bytes()).unwrap();
+
+        let mut swaps = Swaps::new()?;
+        swaps.import_file(file.path())?;
+
+        let actual = swaps.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        let actual = swaps.total_size();
+        let expected = 2097148 + 1048572;
+        assert_eq!(actual, expected);
+
+        let actual = swaps.total_used();
+        let expected = 524288;
+        assert_eq!(actual, expected);
+
+        let actual = swaps[0].usage_ratio();
+        let expected = 524288.0 / 2097148.0;
+        assert_eq!(actual, expected);
+
+        let actual = swaps[1].usage_ratio();
+        let expected = 0.0;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_can_import_entries_from_a_stream() -> crate::Result<()> {
+        let mut swaps = Swaps::new()?;
+        swaps.import_from_stream(Cursor::new(SWAPS))?;
+
+        let actual = swaps.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        let actual = swaps[0].source_path();
+        let expected = Some(Path::new("/dev/sda2"));
+        assert_eq!(actual, expected);
+
+        let actual = swaps[0].priority();
+        let expected = -2;
+        assert_eq!(actual, expected);
+
+        let actual = swaps[1].priority();
+        let expected = -3;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_can_classify_swap_partitions_and_swap_files() -> crate::Result<()> {
+        use crate::core::entries::SwapType;
+
+        let mut swaps = Swaps::new()?;
+        swaps.import_from_stream(Cursor::new(SWAPS))?;
+
+        let actual = swaps[0].swap_type();
+        let expected = Some(SwapType::Partition);
+        assert_eq!(actual, expected);
+
+        assert!(swaps[0].is_partition());
+        assert!(!swaps[0].is_file());
+
+        let actual = swaps[1].swap_type();
+        let expected = Some(SwapType::File);
+        assert_eq!(actual, expected);
+
+        assert!(swaps[1].is_file());
+        assert!(!swaps[1].is_partition());
+
+        let actual: Vec<_> = swaps
+            .iter()
+            .filter(|entry| entry.is_file())
+            .filter_map(|entry| entry.source_path())
+            .collect();
+        let expected = vec![Path::new("/swapfile")];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_keeps_the_name_of_an_unlisted_swap_type() -> crate::Result<()> {
+        use crate::core::entries::SwapType;
+
+        let content =
+            format!("{SWAPS}/dev/zram0                              zram\t\t8388604\t\t0\t\t100\n");
+
+        let mut swaps = Swaps::new()?;
+        swaps.import_from_stream(Cursor::new(content))?;
+
+        let actual = swaps[2].swap_type();
+        let expected = Some(SwapType::Other("zram".to_owned()));
+        assert_eq!(actual, expected);
+
+        assert!(!swaps[2].is_file());
+        assert!(!swaps[2].is_partition());
+
+        let actual = swaps[2].to_string();
+        let expected = "/dev/zram0\t\t\t\tzram\t\t8388604\t\t0\t\t100";
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_reports_malformed_lines_from_a_stream() -> crate::Result<()> {
+        let content = format!("{SWAPS}/dev/sdb1 partition not-a-size\n");
+
+        let errors = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&errors);
+
+        let mut swaps = Swaps::new()?;
+        swaps.set_parser_error_handler(move |_file_name, _line| {
+            counter.fetch_add(1, AtomicOrdering::SeqCst);
+
+            ParserFlow::Ignore
+        })?;
+        swaps.import_from_stream(Cursor::new(content))?;
+
+        let actual = errors.load(AtomicOrdering::SeqCst);
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        let actual = swaps.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_skips_malformed_lines_from_a_stream_by_default() -> crate::Result<()> {
+        let content = format!("{SWAPS}/dev/sdb1 partition not-a-size\n");
+
+        let mut swaps = Swaps::new()?;
+        swaps.import_from_stream(Cursor::new(content))?;
+
+        let actual = swaps.len();
+        let expected = 2;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_can_abort_on_the_first_malformed_line_from_a_stream() -> crate::Result<()> {
+        let content = format!("{SWAPS}/dev/sdb1 partition not-a-size\n");
+
+        let mut swaps = Swaps::new()?;
+        swaps.abort_on_syntax_error();
+        let err = swaps.import_from_stream(Cursor::new(content)).unwrap_err();
+
+        assert!(matches!(err, SwapsError::Parse { line: 4, .. }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn swaps_can_not_remove_an_element_out_of_bounds() -> crate::Result<()> {
+        let mut proc_swaps = Swaps::new()?;
+        proc_swaps.import_proc_swaps()?;
+
+        let index = proc_swaps.len();
+        let actual = proc_swaps.try_remove(index);
+        assert!(matches!(actual, Err(SwapsError::IndexOutOfBounds(_))));
+
+        Ok(())
+    }
 }
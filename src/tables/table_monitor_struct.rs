@@ -5,12 +5,14 @@
 
 // From standard library
 use std::mem::MaybeUninit;
-use std::os::fd::BorrowedFd;
+use std::os::fd::{AsRawFd, BorrowedFd, RawFd};
 use std::path::Path;
+use std::time::Duration;
 
 // From this library
 use crate::core::errors::TableMonitorError;
 use crate::core::fs::{FileChange, MonitorKind, MonitorStatus};
+use crate::core::iter::TableMonitorIter;
 use crate::ffi_utils;
 
 /// Mount table monitor.
@@ -229,10 +231,24 @@ pub fn event_notifier_delete(&mut self) -> Result<(), TableMonitorError> {
         }
     }
 
-    /// s for the next mount table file changes. If a change is detected, use
+    /// Waits for the next mount table file changes. If a change is detected, use
     /// [`TableMonitor::next_file_change`] to get additional details about any modification.
-    pub fn wait_for_next_change(&mut self, time_out: i32) -> MonitorStatus {
-        log::debug!("TableMonitor::wait_for_next_change waiting for the next file change");
+    ///
+    /// This method blocks until a change occurs, or the `time_out` duration elapses. Set
+    /// `time_out` to `None` to wait indefinitely.
+    ///
+    /// **Note:** the `time_out` duration has a millisecond resolution, and is capped at
+    /// `i32::MAX` milliseconds (about 24 days).
+    pub fn wait_for_next_change(&mut self, time_out: Option<Duration>) -> MonitorStatus {
+        log::debug!(
+            "TableMonitor::wait_for_next_change waiting for the next file change (time out: {:?})",
+            time_out
+        );
+
+        // A negative value tells `libmount` to wait indefinitely.
+        let time_out = time_out
+            .map(|duration| duration.as_millis().min(i32::MAX as u128) as i32)
+            .unwrap_or(-1);
 
         let result = unsafe { libmount::mnt_monitor_wait(self.inner, time_out) };
 
@@ -294,6 +310,15 @@ pub fn next_file_change(&mut self) -> Result<Option<FileChange>, TableMonitorErr
         }
     }
 
+    /// Returns an iterator over all pending file changes. Use it to process every queued change
+    /// after being notified by [`TableMonitor::wait_for_next_change`], or after the file
+    /// descriptor returned by [`TableMonitor::event_notifier_create`] becomes readable.
+    pub fn iter_changes(&mut self) -> TableMonitorIter {
+        log::debug!("TableMonitor::iter_changes creating a new `TableMonitorIter` instance");
+
+        TableMonitorIter::new(self)
+    }
+
     /// Clears this `TableMonitor`'s event buffer. Unless you called the
     /// [`TableMonitor::next_file_change`] method, which will automatically handle clean-up
     /// operations, you MUST call this function after an event occurs to be able to receive the
@@ -319,6 +344,19 @@ pub fn discard_last_event(&mut self) -> Result<(), TableMonitorError> {
     }
 }
 
+impl AsRawFd for TableMonitor {
+    /// Returns the file descriptor used by this `TableMonitor` to watch mount table files, or a
+    /// negative value if it could not be created.
+    ///
+    /// **Note:** the file descriptor is owned by this `TableMonitor`, do not close it directly;
+    /// use [`TableMonitor::event_notifier_delete`] instead.
+    fn as_raw_fd(&self) -> RawFd {
+        log::debug!("TableMonitor::as_raw_fd getting file descriptor");
+
+        unsafe { libmount::mnt_monitor_get_fd(self.inner) }
+    }
+}
+
 impl Drop for TableMonitor {
     fn drop(&mut self) {
         log::debug!("TableMonitor::drop deallocating `TableMonitor` instance");
@@ -326,3 +364,138 @@ fn drop(&mut self) {
         unsafe { libmount::mnt_unref_monitor(self.inner) }
     }
 }
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use tempfile::Builder;
+
+    use std::path::PathBuf;
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::core::device::Pseudo;
+    use crate::core::fs::FileSystem;
+    use crate::mount::Mount;
+    use crate::mount::Unmount;
+
+    #[test]
+    fn table_monitor_can_detect_a_new_mount() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+
+            let mut monitor = TableMonitor::new()?;
+            monitor.watch_kernel()?;
+            monitor.event_notifier_create()?;
+
+            let target = tmp_dir.path().to_path_buf();
+            let handle = thread::spawn(move || -> crate::Result<()> {
+                thread::sleep(Duration::from_millis(100));
+
+                let mut mount = Mount::builder()
+                    .source(Pseudo::None)
+                    .target(target)
+                    .file_system(FileSystem::Tmpfs)
+                    .build()?;
+
+                mount.mount_device()?;
+
+                Ok(())
+            });
+
+            let actual = monitor.wait_for_next_change(Some(Duration::from_secs(2)));
+            let expected = MonitorStatus::ChangeDetected;
+            assert_eq!(actual, expected);
+
+            handle.join().unwrap()?;
+
+            let change = monitor.next_file_change()?.unwrap();
+
+            let actual = change.file_name();
+            let expected = PathBuf::from("/proc/self/mountinfo");
+            assert_eq!(actual, expected);
+
+            let actual = change.monitor_kind();
+            let expected = Some(&MonitorKind::Kernel);
+            assert_eq!(actual, expected);
+
+            let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+            unmount.unmount_device()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn table_monitor_times_out_when_no_change_occurs() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let mut monitor = TableMonitor::new()?;
+            monitor.watch_kernel()?;
+
+            let actual = monitor.wait_for_next_change(Some(Duration::from_millis(100)));
+            let expected = MonitorStatus::TimeOut;
+            assert_eq!(actual, expected);
+
+            monitor.event_notifier_delete()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn table_monitor_can_iterate_over_queued_changes() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let tmp_dirs: Vec<_> = (0..2)
+                .map(|_| Builder::new().prefix("rsmount-test-").tempdir().unwrap())
+                .collect();
+
+            let mut monitor = TableMonitor::new()?;
+            monitor.watch_kernel()?;
+
+            let actual = monitor.as_raw_fd() >= 0;
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            // Queue changes before processing them.
+            for tmp_dir in tmp_dirs.iter() {
+                let mut mount = Mount::builder()
+                    .source(Pseudo::None)
+                    .target(tmp_dir.path())
+                    .file_system(FileSystem::Tmpfs)
+                    .build()?;
+
+                mount.mount_device()?;
+            }
+
+            let actual = monitor.wait_for_next_change(Some(Duration::from_secs(2)));
+            let expected = MonitorStatus::ChangeDetected;
+            assert_eq!(actual, expected);
+
+            let changes: Vec<_> = monitor.iter_changes().collect();
+
+            let actual = changes.is_empty();
+            let expected = false;
+            assert_eq!(actual, expected);
+
+            for change in changes {
+                let actual = change.monitor_kind();
+                let expected = Some(&MonitorKind::Kernel);
+                assert_eq!(actual, expected);
+            }
+
+            // All pending changes were drained.
+            let actual = monitor.next_file_change()?.is_none();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            for tmp_dir in tmp_dirs.iter() {
+                let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+                unmount.unmount_device()?;
+            }
+        }
+
+        Ok(())
+    }
+}
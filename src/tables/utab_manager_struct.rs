@@ -87,14 +87,22 @@ fn set_force_read_only(
         }
     }
 
-    /// Forces this `UtabManager` to operate in read-only mode.
+    /// Forces this `UtabManager` to operate in read-only mode, i.e. adds
+    /// [`MountFlag::ReadOnly`] to the mount flags of the entry to update.
+    ///
+    /// **Note:** this method modifies the entry set by [`UtabManager::set_mount_table_entry`],
+    /// it MUST be called after setting an entry, and will return an error otherwise.
     pub fn enable_read_only(&mut self) -> Result<(), UtabManagerError> {
         log::debug!("UtabManager::enable_read_only enabling read only mode");
 
         Self::set_force_read_only(self.ptr, true)
     }
 
-    /// Disables read-only mode.
+    /// Disables read-only mode, i.e. removes [`MountFlag::ReadOnly`] from the mount flags of the
+    /// entry to update.
+    ///
+    /// **Note:** this method modifies the entry set by [`UtabManager::set_mount_table_entry`],
+    /// it MUST be called after setting an entry, and will return an error otherwise.
     pub fn disable_read_only(&mut self) -> Result<(), UtabManagerError> {
         log::debug!("UtabManager::disable_read_only disabling read only mode");
 
@@ -274,7 +282,7 @@ pub fn mount_flags(&self) -> Option<Vec<MountFlag>> {
             bits => {
                 log::debug!("UtabManager::mount_flags got mount flags");
                 let flags: Vec<_> = all::<MountFlag>()
-                    .filter(|&flag| bits & (flag as u64) != 0)
+                    .filter(|&flag| bits & (flag as u64) == flag as u64)
                     .collect();
 
                 Some(flags)
@@ -305,3 +313,95 @@ fn drop(&mut self) {
         unsafe { libmount::mnt_free_update(self.ptr) }
     }
 }
+
+#[cfg(test)]
+#[allow(unused_imports)]
+mod tests {
+    use super::*;
+    use pretty_assertions::{assert_eq, assert_ne};
+
+    use crate::tables::UTab;
+
+    #[test]
+    fn utab_manager_is_not_ready_for_update_by_default() -> crate::Result<()> {
+        let manager = UtabManager::new()?;
+
+        let actual = manager.is_ready_for_update();
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn utab_manager_can_record_an_entry_in_utab() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let target = "/mnt/rsmount-test-utab-manager";
+            let entry = UTabEntry::builder()
+                .source("/dev/vda")
+                .target(target)
+                .mount_options("x-rsmount.test")
+                .build()?;
+
+            let mut manager = UtabManager::new()?;
+            manager.set_mount_table_entry(entry, vec![])?;
+
+            let actual = manager.is_ready_for_update();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            let actual = manager.mount_table_file_name().is_some();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            manager.update_mount_table()?;
+
+            let mut utab = UTab::new()?;
+            utab.import_utab()?;
+
+            let actual = utab.find_target(target).is_some();
+            let expected = true;
+            assert_eq!(actual, expected);
+
+            // Remove the test entry.
+            let mut manager = UtabManager::new()?;
+            manager.set_umount_target(target, vec![])?;
+            manager.update_mount_table()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn utab_manager_can_not_force_read_only_mode_without_an_entry() -> crate::Result<()> {
+        let mut manager = UtabManager::new()?;
+
+        let actual = manager.enable_read_only();
+        assert!(actual.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn utab_manager_can_force_read_only_mode() -> crate::Result<()> {
+        if inside_vm::inside_vm() {
+            let entry = UTabEntry::builder()
+                .source("/dev/vda")
+                .target("/mnt/rsmount-test-utab-manager")
+                .mount_options("rw,x-rsmount.test")
+                .build()?;
+
+            let mut manager = UtabManager::new()?;
+            manager.set_mount_table_entry(entry, vec![MountFlag::NoSuid])?;
+            manager.enable_read_only()?;
+
+            let mut actual = manager.mount_flags().unwrap();
+            actual.sort_by_key(|&flag| flag as u64);
+            let mut expected = vec![MountFlag::ReadOnly, MountFlag::NoSuid];
+            expected.sort_by_key(|&flag| flag as u64);
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+}
@@ -21,6 +21,7 @@
 use crate::core::errors::UTabIterError;
 use crate::core::iter::Direction;
 use crate::core::iter::GenIterator;
+use crate::core::iter::UTabIntoIter;
 use crate::core::iter::UTabIter;
 use crate::core::iter::UTabIterMut;
 use crate::ffi_utils;
@@ -53,6 +54,8 @@
 pub struct UTab {
     pub(crate) inner: *mut libmount::libmnt_table,
     pub(crate) gc: Vec<GcItem>,
+    has_parser_error_handler: bool,
+    abort_on_syntax_error: bool,
 }
 
 impl Drop for UTab {
@@ -71,6 +74,22 @@ fn as_ref(&self) -> &UTab {
     }
 }
 
+impl IntoIterator for UTab {
+    type Item = UTabEntry;
+    type IntoIter = UTabIntoIter;
+
+    /// Creates a consuming iterator over the entries of this table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it fails to create a [`UTabIntoIter`].
+    fn into_iter(self) -> Self::IntoIter {
+        log::debug!("UTab::into_iter creating a new `UTabIntoIter`");
+
+        UTabIntoIter::new(self).unwrap()
+    }
+}
+
 impl Index<usize> for UTab {
     type Output = UTabEntry;
 
@@ -136,6 +155,8 @@ pub(crate) fn from_ptr(ptr: *mut libmount::libmnt_table) -> UTab {
         Self {
             inner: ptr,
             gc: vec![],
+            has_parser_error_handler: false,
+            abort_on_syntax_error: false,
         }
     }
 
@@ -180,16 +201,82 @@ pub fn new() -> Result<UTab, UTabError> {
 
     //---- END constructors
 
+    /// Stops importing table entries at the first line with a syntax error, making import
+    /// methods return a [`UTabError::Parse`] holding the line's number.
+    ///
+    /// **Note:** this setting has no effect if you set up a handler with
+    /// [`UTab::set_parser_error_handler`].
+    pub fn abort_on_syntax_error(&mut self) {
+        log::debug!("UTab::abort_on_syntax_error enabling abort on syntax error");
+
+        self.abort_on_syntax_error = true;
+    }
+
+    /// Skips lines with a syntax error when importing table entries (default).
+    pub fn skip_syntax_errors(&mut self) {
+        log::debug!("UTab::skip_syntax_errors disabling abort on syntax error");
+
+        self.abort_on_syntax_error = false;
+    }
+
+    #[doc(hidden)]
+    /// Runs the `parse` function on this table, then converts its outcome to a `Result`.
+    ///
+    /// Unless the user set up a parser error handler, records the line numbers of syntax errors,
+    /// and reports the line the parser stopped at as a [`UTabError::Parse`], quoting
+    /// the line when its file is readable.
+    fn import_with<T, F>(
+        &mut self,
+        function: &'static str,
+        source: T,
+        parse: F,
+    ) -> Result<(), UTabError>
+    where
+        T: AsRef<Path>,
+        F: FnOnce(*mut libmount::libmnt_table) -> libc::c_int,
+    {
+        let source = source.as_ref();
+
+        let (result, error_lines) = if self.has_parser_error_handler {
+            (parse(self.inner), vec![])
+        } else {
+            ffi_utils::parse_recording_syntax_errors(self.inner, self.abort_on_syntax_error, parse)
+        };
+
+        match (result, error_lines.last()) {
+            (0, _) => {
+                if !error_lines.is_empty() {
+                    log::debug!(
+                        "UTab::import_with skipped lines with a syntax error in {:?}: {:?}",
+                        source,
+                        error_lines
+                    );
+                }
+
+                Ok(())
+            }
+            (_, Some((line, file_name))) => {
+                let file_path = file_name.as_deref().unwrap_or(source);
+                let reason = match ffi_utils::read_line(file_path, *line) {
+                    Some(text) => format!("malformed entry {:?}", text.trim()),
+                    None => format!("syntax error in {:?}", file_path),
+                };
+
+                Err(UTabError::Parse {
+                    line: *line,
+                    reason,
+                })
+            }
+            (code, None) => Err(UTabError::Ffi { function, code }),
+        }
+    }
+
     /// Parses the given file, then appends the entries it collected to the table.
     ///
     /// **Note:**
     /// - by default, comment lines are ignored during import. If you want them included, call
     ///   [`UTab::import_with_comments`] **before** invoking this method.
-    /// - the parser ignores lines with syntax errors. It will report defective lines to the caller
-    ///   through an error callback function.
-    ///
-    // FIXME Defective lines are reported to the caller by the errcb() function (see mnt_table_set_parser_errcb()).
-    // can not currently wrap the function `mnt_table_set_parser_errcb`
+    /// - by default, lines with a syntax error are skipped. See [`UTab::abort_on_syntax_error`].
     fn import_file<T>(&mut self, file_path: T) -> Result<(), UTabError>
     where
         T: AsRef<Path>,
@@ -201,10 +288,12 @@ fn import_file<T>(&mut self, file_path: T) -> Result<(), UTabError>
             file_path
         );
 
-        let result = unsafe { libmount::mnt_table_parse_file(self.inner, file_path_cstr.as_ptr()) };
+        let result = self.import_with("mnt_table_parse_file", file_path, |table| unsafe {
+            libmount::mnt_table_parse_file(table, file_path_cstr.as_ptr())
+        });
 
         match result {
-            0 => {
+            Ok(()) => {
                 log::debug!(
                     "UTab::import_file imported table entries from file {:?}",
                     file_path
@@ -212,11 +301,14 @@ fn import_file<T>(&mut self, file_path: T) -> Result<(), UTabError>
 
                 Ok(())
             }
-            code => {
-                let err_msg = format!("failed to import table entries from file {:?}", file_path);
-                log::debug!("UTab::import_file {}. libmount::mnt_table_parse_file returned error code: {:?}", err_msg, code);
+            Err(e) => {
+                log::debug!(
+                    "UTab::import_file failed to import table entries from file {:?}. {}",
+                    file_path,
+                    e
+                );
 
-                Err(UTabError::Import(err_msg))
+                Err(e)
             }
         }
     }
@@ -309,10 +401,11 @@ pub fn position(&self, entry: &UTabEntry) -> Option<usize> {
             index if index > 0 => {
                 log::debug!(
                     "UTab::position mount table contains entry at index: {:?}",
-                    index
+                    index - 1
                 );
 
-                Some(index as usize)
+                // libmount::mnt_table_find_fs returns a 1-based index
+                Some((index - 1) as usize)
             }
             code => {
                 log::debug!( "UTab::position no matching entry in table: libmount::mnt_table_find_fs returned error code: {code:?}");
@@ -1086,6 +1179,7 @@ pub fn set_parser_error_handler<F>(&mut self, err_handler: F) -> Result<(), UTab
                 match result {
                     0 => {
                         log::debug!("UTab::set_parser_error_handler set up parser error handler");
+                        self.has_parser_error_handler = true;
                         // FIXME the callback function is long lived. If the function is called
                         // several times, we risk a substantial memory leak until the end of the program,
                         // since `user_data` is never released between calls.
@@ -1122,11 +1216,6 @@ pub fn set_parser_error_handler<F>(&mut self, err_handler: F) -> Result<(), UTab
     pub fn set_cache(&mut self, cache: Cache) -> Result<(), UTabError> {
         log::debug!("UTab::set_cache setting up a cache of paths and tags");
 
-        // Increment cache's reference counter to avoid a premature deallocation leading to a SIGSEV.
-        unsafe {
-            libmount::mnt_ref_cache(cache.inner);
-        }
-
         let result = unsafe { libmount::mnt_table_set_cache(self.inner, cache.inner) };
         match result {
             0 => {
@@ -1541,6 +1630,50 @@ fn assert_failed() -> ! {
         }
     }
 
+    /// Removes the entry at `index` from the table, and returns it.
+    ///
+    /// Unlike [`UTab::remove`], this method does not panic if `index` is out of bounds; it
+    /// returns an [`UTabError::IndexOutOfBounds`] instead.
+    pub fn try_remove(&mut self, index: usize) -> Result<UTabEntry, UTabError> {
+        log::debug!("UTab::try_remove removing entry at index: {:?}", index);
+
+        let inner = match self.get(index) {
+            Some(element) => element.inner,
+            None => {
+                let err_msg = format!("failed to find entry at index: {:?}", index);
+                log::debug!("UTab::try_remove {err_msg}");
+
+                return Err(UTabError::IndexOutOfBounds(err_msg));
+            }
+        };
+
+        // increment reference counter to prevent mnt_table_remove from deallocating the underlying
+        // table entry
+        let borrowed = UTabEntry::borrow_ptr(inner);
+
+        let result = unsafe { libmount::mnt_table_remove_fs(self.inner, inner) };
+
+        match result {
+            0 => {
+                log::debug!("UTab::try_remove removed entry from table");
+
+                Ok(borrowed)
+            }
+            code => {
+                let err_msg = format!("failed to remove entry at index: {:?}", index);
+                log::debug!(
+                    "UTab::try_remove {err_msg}. libmount::mnt_table_remove_fs returned error code: {code:?}"
+                );
+
+                // the element is not in the table, so we decrement its reference counter by
+                // dropping it to cancel out the increment performed by UTabEntry::borrow_ptr
+                drop(borrowed);
+
+                Err(UTabError::Action(err_msg))
+            }
+        }
+    }
+
     /// Removes all table entries.
     pub fn clear(&mut self) -> Result<(), UTabError> {
         log::debug!("UTab::clear removing all table entries");
@@ -1627,6 +1760,15 @@ pub fn write_stream(&mut self, file_stream: &mut File) -> io::Result<()> {
 
     //---- BEGIN predicates
 
+    /// Returns `true` if this `UTab` is set to stop importing table entries at the first line
+    /// with a syntax error.
+    pub fn is_aborting_on_syntax_error(&self) -> bool {
+        let state = self.abort_on_syntax_error;
+        log::debug!("UTab::is_aborting_on_syntax_error value: {:?}", state);
+
+        state
+    }
+
     /// Returns `true` if this `UTab` contains a element matching **exactly** the given `element`.
     pub fn contains(&self, element: &UTabEntry) -> bool {
         let state = unsafe { libmount::mnt_table_find_fs(self.inner, element.inner) > 0 };
@@ -1662,8 +1804,32 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 #[allow(unused_imports)]
 mod tests {
     use super::*;
+    use crate::core::errors::UTabEntryError;
+    use crate::tables::fixtures;
+    use crate::tables::MountInfo;
     use pretty_assertions::{assert_eq, assert_ne};
 
+    static UTAB: &str = r#"SRC=/dev/sda3 TARGET=/ ROOT=/ OPTS=x-initrd.mount
+SRC=/dev/sda3 TARGET=/mnt ROOT=/srv BINDSRC=/srv OPTS=x-systemd.automount
+"#;
+
+    fn sample_tables() -> crate::Result<(UTab, MountInfo)> {
+        let utab_file = fixtures::temp_file(UTAB);
+
+        let mut utab = UTab::new()?;
+        utab.import_file(utab_file.path())?;
+
+        // Bind mount of `/srv` on `/mnt`.
+        let content = [
+            fixtures::MOUNTINFO,
+            "28 21 8:3 /srv /mnt rw,nosuid,relatime shared:1 - ext4 /dev/sda3 rw\n",
+        ]
+        .concat();
+        let mount_info = fixtures::mount_info_from(&content)?;
+
+        Ok((utab, mount_info))
+    }
+
     #[test]
     fn utab_can_import_run_mount_utab() -> crate::Result<()> {
         let mut utab = UTab::new()?;
@@ -1672,4 +1838,109 @@ fn utab_can_import_run_mount_utab() -> crate::Result<()> {
 
         Ok(())
     }
+
+    #[test]
+    fn utab_can_remove_an_element_from_the_middle_of_a_table() -> crate::Result<()> {
+        let entry1 = UTabEntry::builder()
+            .source("/dev/vda1")
+            .target("/")
+            .mount_options("rw,relatime")
+            .build()?;
+
+        let entry2 = UTabEntry::builder()
+            .source("/dev/vda2")
+            .target("/home")
+            .mount_options("rw,relatime")
+            .build()?;
+
+        let entry3 = UTabEntry::builder()
+            .source("/dev/vda3")
+            .target("/var")
+            .mount_options("rw,relatime")
+            .build()?;
+
+        let mut utab = UTab::new()?;
+        utab.push(entry1);
+        utab.push(entry2);
+        utab.push(entry3);
+
+        let item = utab.try_remove(1)?;
+
+        let actual = item.target().unwrap();
+        let expected = Path::new("/home");
+        assert_eq!(actual, expected);
+
+        let actual: Vec<_> = utab.iter().map(|e| e.target().unwrap()).collect();
+        let expected = vec![Path::new("/"), Path::new("/var")];
+        assert_eq!(actual, expected);
+
+        let actual = utab.try_remove(2);
+        assert!(matches!(actual, Err(UTabError::IndexOutOfBounds(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn utab_entry_can_merge_userspace_options_into_a_mountinfo_entry() -> crate::Result<()> {
+        let (mut utab, mut mount_info) = sample_tables()?;
+
+        let utab_entry = utab.find_target("/").unwrap();
+        let entry = mount_info.find_target("/").unwrap();
+        let merged = utab_entry.merge_into(entry)?;
+
+        let options = merged.options();
+        for option in ["rw", "relatime", "x-initrd.mount"] {
+            let option: MountOption = option.parse()?;
+            assert!(options.contains(&option), "missing option: {:?}", option);
+        }
+
+        let actual = merged.bind_source();
+        let expected = None;
+        assert_eq!(actual, expected);
+
+        // The original entry is left untouched.
+        let actual = entry.options().contains(&"x-initrd.mount".parse()?);
+        let expected = false;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn utab_entry_can_merge_a_bind_mount_into_a_mountinfo_entry() -> crate::Result<()> {
+        let (mut utab, mut mount_info) = sample_tables()?;
+
+        let utab_entry = utab.find_target("/mnt").unwrap();
+        let entry = mount_info.find_target("/mnt").unwrap();
+        let merged = utab_entry.merge_into(entry)?;
+
+        let options = merged.options();
+        for option in ["nosuid", "relatime", "x-systemd.automount"] {
+            let option: MountOption = option.parse()?;
+            assert!(options.contains(&option), "missing option: {:?}", option);
+        }
+
+        let actual = merged.bind_source();
+        let expected = Some("/srv");
+        assert_eq!(actual, expected);
+
+        let actual = merged.root();
+        let expected = Some("/srv");
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn utab_entry_can_not_merge_into_an_entry_with_another_target() -> crate::Result<()> {
+        let (mut utab, mut mount_info) = sample_tables()?;
+
+        let utab_entry = utab.find_target("/").unwrap();
+        let entry = mount_info.find_target("/mnt").unwrap();
+
+        let actual = utab_entry.merge_into(entry);
+        assert!(matches!(actual, Err(UTabEntryError::Action(_))));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,112 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Mounts several entries of a table in parallel, with forked child processes.
+//!
+//! Forking inside libtest's multi-threaded harness is unsafe, so this test runs in its own binary
+//! (`harness = false`), on the main thread.
+//!
+//! Run with `cargo test --test parallel_mount`.
+
+// From dependency library
+use pretty_assertions::assert_eq;
+use tempfile::Builder;
+
+// From standard library
+
+// From this library
+use rsmount::device::Pseudo;
+use rsmount::entries::FsTabEntry;
+use rsmount::fs::FileSystem;
+use rsmount::mount::Mount;
+use rsmount::mount::StepResult;
+use rsmount::mount::Unmount;
+use rsmount::tables::FsTab;
+use rsmount::tables::MountInfo;
+
+fn mount_can_mount_entries_in_a_table_in_parallel() -> rsmount::Result<()> {
+    let tmp_dirs: Vec<_> = (0..3)
+        .map(|_| Builder::new().prefix("rsmount-test-").tempdir().unwrap())
+        .collect();
+
+    let mut fstab = FsTab::new()?;
+    for tmp_dir in tmp_dirs.iter() {
+        let entry = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target(tmp_dir.path())
+            .file_system_type(FileSystem::Tmpfs)
+            .mount_options("size=1m")
+            .build()?;
+
+        fstab.push(entry);
+    }
+
+    let mut mount = Mount::builder()
+        .override_fstab(fstab)
+        .parallel_mount()
+        .build()?;
+
+    let parent_pid = std::process::id();
+    for step in mount.seq_mount() {
+        if std::process::id() != parent_pid {
+            // Child process, report the outcome of its mount then exit.
+            let code = if matches!(step, StepResult::MountSuccess(_)) {
+                0
+            } else {
+                1
+            };
+
+            unsafe { libc::_exit(code) };
+        }
+    }
+
+    let actual = mount.is_parent_process();
+    let expected = true;
+    assert_eq!(actual, expected);
+
+    let status = mount.wait_on_children();
+
+    let actual = status.children();
+    let expected = 3;
+    assert_eq!(actual, expected);
+
+    let actual = status.errors();
+    let expected = 0;
+    assert_eq!(actual, expected);
+
+    let mut mountinfo = MountInfo::new()?;
+    mountinfo.import_mountinfo()?;
+
+    for tmp_dir in tmp_dirs.iter() {
+        let entry = mountinfo.find_target(tmp_dir.path());
+        assert!(entry.is_some());
+
+        let mut unmount = Unmount::builder().target(tmp_dir.path()).build()?;
+        unmount.unmount_device()?;
+    }
+
+    Ok(())
+}
+
+fn main() -> rsmount::Result<()> {
+    const TEST_NAME: &str = "mount_can_mount_entries_in_a_table_in_parallel";
+
+    // Answer `cargo nextest`'s request to list the tests in this binary.
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--list") {
+        if !args.iter().any(|arg| arg == "--ignored") {
+            println!("{TEST_NAME}: test");
+        }
+
+        return Ok(());
+    }
+
+    if inside_vm::inside_vm() {
+        mount_can_mount_entries_in_a_table_in_parallel()?;
+        println!("test {TEST_NAME} ... ok");
+    } else {
+        println!("test {TEST_NAME} ... ignored, not running in a VM");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks [`utils::read_fstab`] against the file set in the `LIBMOUNT_FSTAB` environment variable.
+//!
+//! Environment variables are shared by all threads of a process; these tests run in their own
+//! binary, where no other test reads `fstab`, and take turns holding `ENV_LOCK` while
+//! `LIBMOUNT_FSTAB` is set.
+
+// From dependency library
+use pretty_assertions::assert_eq;
+
+// From standard library
+use std::path::Path;
+use std::sync::Mutex;
+
+// From this library
+use rsmount::utils;
+use rsmount::RsMountError;
+
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+#[test]
+fn read_fstab_reads_the_file_set_in_libmount_fstab() -> rsmount::Result<()> {
+    let _lock = ENV_LOCK.lock().unwrap();
+
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let path = tmp_dir.path().join("fstab");
+    std::fs::write(
+        &path,
+        "# <file system> <mount point> <type> <options> <dump> <pass>\n\
+         UUID=dd476616-1ce4-415e-9dbd-8c2fa8f42f0f / ext4 rw,relatime 0 1\n\
+         tmpfs /tmp tmpfs nosuid,nodev 0 0\n",
+    )
+    .unwrap();
+    std::env::set_var("LIBMOUNT_FSTAB", &path);
+
+    let fstab = utils::read_fstab()?;
+
+    let actual = fstab.len();
+    let expected = 2;
+    assert_eq!(actual, expected);
+
+    let actual: Vec<_> = fstab.iter().map(|entry| entry.target()).collect();
+    let expected = vec![Some(Path::new("/")), Some(Path::new("/tmp"))];
+    assert_eq!(actual, expected);
+
+    std::env::remove_var("LIBMOUNT_FSTAB");
+
+    Ok(())
+}
+
+#[test]
+fn read_fstab_reports_an_unreadable_fstab_file_as_an_io_error() {
+    let _lock = ENV_LOCK.lock().unwrap();
+
+    let tmp_dir = tempfile::tempdir().unwrap();
+    std::env::set_var("LIBMOUNT_FSTAB", tmp_dir.path().join("missing"));
+
+    let actual = utils::read_fstab();
+    assert!(matches!(actual, Err(RsMountError::Io(_))));
+
+    std::env::remove_var("LIBMOUNT_FSTAB");
+}
@@ -0,0 +1,127 @@
+// Copyright (c) 2023 Nick Piaddo
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Checks the messages a [`Mount`] in verbose mode emits through the `log` crate.
+//!
+//! The `log` crate has a single global logger; these tests run in their own binary, where no
+//! other test sets a logger or changes the maximum log level. Each test only looks at the records
+//! mentioning its own temporary directory.
+
+// From dependency library
+use pretty_assertions::assert_eq;
+use tempfile::Builder;
+
+// From standard library
+use std::sync::Mutex;
+use std::sync::Once;
+
+// From this library
+use rsmount::device::Pseudo;
+use rsmount::entries::FsTabEntry;
+use rsmount::fs::FileSystem;
+use rsmount::mount::Mount;
+use rsmount::tables::FsTab;
+
+static RECORDS: Mutex<Vec<(log::Level, String)>> = Mutex::new(Vec::new());
+
+struct CapturingLogger;
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Info
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            let message = record.args().to_string();
+            RECORDS.lock().unwrap().push((record.level(), message));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger;
+
+/// Installs the capturing logger, once for all tests.
+fn capture_logs() {
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| {
+        log::set_logger(&LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Info);
+    });
+}
+
+/// Returns `true` if an `info` record with the given `message` was emitted.
+fn has_info_record(message: &str) -> bool {
+    RECORDS
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(level, record)| *level == log::Level::Info && record == message)
+}
+
+#[test]
+fn mount_logs_the_outcome_of_a_mount_in_verbose_mode() -> rsmount::Result<()> {
+    if inside_vm::inside_vm() {
+        capture_logs();
+
+        let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+        let mut mount = Mount::builder()
+            .source(Pseudo::None)
+            .target(tmp_dir.path())
+            .file_system(FileSystem::Tmpfs)
+            .dry_run()
+            .verbose()
+            .build()?;
+
+        mount.mount_device()?;
+
+        let message = format!("none mounted on {}", tmp_dir.path().display());
+
+        let actual = has_info_record(&message);
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn mount_logs_each_step_of_a_sequential_mount_in_verbose_mode() -> rsmount::Result<()> {
+    if inside_vm::inside_vm() {
+        capture_logs();
+
+        let tmp_dir = Builder::new().prefix("rsmount-test-").tempdir().unwrap();
+
+        let mut fstab = FsTab::new()?;
+        let entry = FsTabEntry::builder()
+            .source(Pseudo::None)
+            .target(tmp_dir.path())
+            .file_system_type(FileSystem::Tmpfs)
+            .mount_options("size=1m")
+            .build()?;
+        fstab.push(entry);
+
+        let mut mount = Mount::builder()
+            .override_fstab(fstab)
+            .dry_run()
+            .verbose()
+            .build()?;
+
+        let steps = mount.seq_mount().count();
+
+        let actual = steps;
+        let expected = 1;
+        assert_eq!(actual, expected);
+
+        let message = format!("none mounted on {}", tmp_dir.path().display());
+
+        let actual = has_info_record(&message);
+        let expected = true;
+        assert_eq!(actual, expected);
+    }
+
+    Ok(())
+}